@@ -0,0 +1,245 @@
+//! Headless project analysis
+//!
+//! Loads a project file, checks every wood beam, and writes the results as
+//! a JSON array for scripting and CI. Warnings are listed on stderr per
+//! member, with a count of each warning code at the end.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use calc_core::calculations::{calculate_continuous, CalculationItem};
+use calc_core::errors::WarningSeverity;
+use calc_core::file_io::load_project;
+use calc_core::loads::DesignMethod;
+use serde_json::json;
+
+/// Options for the `analyze` command
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzeArgs {
+    /// Project file to analyze
+    pub project: PathBuf,
+    /// Design method; the project's setting when not given
+    pub method: Option<DesignMethod>,
+    /// Output file; stdout when not given
+    pub out: Option<PathBuf>,
+    /// Exit successfully even when a member fails
+    pub allow_fail: bool,
+    /// Exit with failure when any member has a Caution warning
+    pub strict: bool,
+}
+
+impl AnalyzeArgs {
+    /// Parse the arguments following `analyze`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut project = None;
+        let mut method = None;
+        let mut out = None;
+        let mut allow_fail = false;
+        let mut strict = false;
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--method" => {
+                    let value = iter.next().ok_or("--method needs a value")?;
+                    method = Some(match value.to_ascii_lowercase().as_str() {
+                        "asd" => DesignMethod::Asd,
+                        "lrfd" => DesignMethod::Lrfd,
+                        _ => return Err(format!("Unknown design method '{}'", value)),
+                    });
+                }
+                "--out" => {
+                    let value = iter.next().ok_or("--out needs a file path")?;
+                    out = Some(PathBuf::from(value));
+                }
+                "--allow-fail" => allow_fail = true,
+                "--strict" => strict = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+                path if project.is_none() => project = Some(PathBuf::from(path)),
+                extra => return Err(format!("Unexpected argument '{}'", extra)),
+            }
+        }
+
+        Ok(Self {
+            project: project.ok_or("Missing project file")?,
+            method,
+            out,
+            allow_fail,
+            strict,
+        })
+    }
+}
+
+/// Analyze every beam in the project and write the JSON results
+///
+/// Exits with 1 when a beam fails its checks or cannot be calculated (unless
+/// `allow_fail`) or, when `strict`, has a Caution warning; and with 2 when the
+/// project cannot be read or the output cannot be written.
+pub fn run(args: &AnalyzeArgs) -> ExitCode {
+    let project = match load_project(&args.project) {
+        Ok(project) => project,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+    let method = args.method.unwrap_or(project.settings.design_method);
+
+    let mut beams: Vec<_> = project
+        .items
+        .iter()
+        .filter_map(|(id, item)| match item {
+            CalculationItem::Beam(beam) => Some((id, beam)),
+            CalculationItem::Column(_)
+            | CalculationItem::SteelBeam(_)
+            | CalculationItem::SpreadFooting(_)
+            | CalculationItem::RetainingWall(_) => None,
+        })
+        .collect();
+    beams.sort_by(|a, b| a.1.label.cmp(&b.1.label).then(a.0.cmp(b.0)));
+
+    let mut all_pass = true;
+    let mut warning_counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut cautions = 0;
+    let entries: Vec<serde_json::Value> = beams
+        .into_iter()
+        .map(|(id, beam)| {
+            let beam = project.beam_for_calculation(beam);
+            match calculate_continuous(&beam, method) {
+                Ok(result) => {
+                    all_pass &= result.passes();
+                    for warning in &result.warnings {
+                        eprintln!("{}: [{:?}] {}", beam.label, warning.severity, warning);
+                        *warning_counts.entry(warning.code.clone()).or_default() += 1;
+                        if warning.severity >= WarningSeverity::Caution {
+                            cautions += 1;
+                        }
+                    }
+                    json!({
+                        "id": id,
+                        "label": beam.label,
+                        "passes": result.passes(),
+                        "result": result,
+                    })
+                }
+                Err(e) => {
+                    all_pass = false;
+                    json!({
+                        "id": id,
+                        "label": beam.label,
+                        "passes": false,
+                        "error": e,
+                    })
+                }
+            }
+        })
+        .collect();
+
+    let text = serde_json::to_string_pretty(&entries).unwrap_or_default();
+    match &args.out {
+        Some(path) => {
+            if let Err(e) = fs::write(path, text + "\n") {
+                eprintln!("Error: cannot write {}: {}", path.display(), e);
+                return ExitCode::from(2);
+            }
+        }
+        None => println!("{}", text),
+    }
+
+    if !warning_counts.is_empty() {
+        let total: usize = warning_counts.values().sum();
+        let by_code: Vec<String> = warning_counts
+            .iter()
+            .map(|(code, count)| format!("{} x{}", code, count))
+            .collect();
+        eprintln!("{} warning(s): {}", total, by_code.join(", "));
+    }
+
+    // Info warnings are notes, not review items, so they never fail --strict
+    let warnings_fail = args.strict && cautions > 0;
+    if (all_pass || args.allow_fail) && !warnings_fail {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_analyze_args() {
+        let parsed = AnalyzeArgs::parse(&args(&[
+            "job.stf",
+            "--method",
+            "LRFD",
+            "--out",
+            "r.json",
+            "--allow-fail",
+            "--strict",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.project, PathBuf::from("job.stf"));
+        assert_eq!(parsed.method, Some(DesignMethod::Lrfd));
+        assert_eq!(parsed.out, Some(PathBuf::from("r.json")));
+        assert!(parsed.allow_fail);
+        assert!(parsed.strict);
+
+        let defaults = AnalyzeArgs::parse(&args(&["job.stf"])).unwrap();
+        assert_eq!(defaults.method, None);
+        assert!(!defaults.allow_fail);
+        assert!(!defaults.strict);
+    }
+
+    #[test]
+    fn test_parse_analyze_args_errors() {
+        assert!(AnalyzeArgs::parse(&args(&[])).is_err());
+        assert!(AnalyzeArgs::parse(&args(&["job.stf", "--method"])).is_err());
+        assert!(AnalyzeArgs::parse(&args(&["job.stf", "--method", "wsd"])).is_err());
+        assert!(AnalyzeArgs::parse(&args(&["job.stf", "other.stf"])).is_err());
+        assert!(AnalyzeArgs::parse(&args(&["job.stf", "--verbose"])).is_err());
+    }
+
+    #[test]
+    fn test_analyze_project_file() {
+        use calc_core::file_io::save_project;
+        use calc_core::project::{templates, Project};
+
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        project.add_item(CalculationItem::Beam(templates::header()));
+        let mut overspanned = templates::floor_beam();
+        overspanned.label = "FB-2".to_string();
+        overspanned.spans[0].length_ft = 30.0;
+        project.add_item(CalculationItem::Beam(overspanned));
+
+        let dir = std::env::temp_dir();
+        let stem = format!("stratify_cli_analyze_{}", uuid::Uuid::new_v4());
+        let path = dir.join(format!("{}.stf", stem));
+        let out = dir.join(format!("{}.json", stem));
+        save_project(&project, &path).unwrap();
+
+        let mut args =
+            AnalyzeArgs::parse(&[path.display().to_string(), "--out".into(), out.display().to_string()]).unwrap();
+        assert_eq!(run(&args), ExitCode::FAILURE);
+        args.allow_fail = true;
+        assert_eq!(run(&args), ExitCode::SUCCESS);
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&fs::read_to_string(&out).unwrap()).unwrap();
+        let summary: Vec<(&str, bool)> = entries
+            .iter()
+            .map(|e| (e["label"].as_str().unwrap(), e["passes"].as_bool().unwrap()))
+            .collect();
+        assert_eq!(summary, [("FB-2", false), ("H-1", true)]);
+        assert!(entries[1]["result"]["span_results"].is_array());
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&out);
+    }
+}
@@ -0,0 +1,115 @@
+//! # Stratify Calculation Server
+//!
+//! JSON-RPC 2.0 over stdio for LLM tool use (MCP-style). Each request is one
+//! line of JSON on stdin; each response is one line on stdout.
+//!
+//! ## Methods
+//!
+//! - `list_tools` - Available calculations and their input schemas
+//! - `calculate_continuous` - Params `{ "input": ContinuousBeamInput, "method": "Asd" | "Lrfd" }`;
+//!   the result is the `ContinuousBeamResult`, and a failed calculation
+//!   returns the serialized `CalcError` as the error `data`
+//!
+//! ```text
+//! {"jsonrpc":"2.0","id":1,"method":"list_tools"}
+//! {"jsonrpc":"2.0","id":1,"result":{"tools":[{"name":"calculate_continuous",...}]}}
+//! ```
+
+use std::io::{self, BufRead, Write};
+
+use calc_core::calculations::{calculate_continuous, ContinuousBeamInput};
+use calc_core::loads::DesignMethod;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Server-defined: the calculation itself returned a `CalcError`
+const CALCULATION_ERROR: i64 = -32000;
+
+#[derive(Deserialize)]
+struct CalculateParams {
+    input: ContinuousBeamInput,
+    #[serde(default)]
+    method: DesignMethod,
+}
+
+fn main() {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = handle(&line);
+        if writeln!(stdout, "{}", response).and_then(|()| stdout.flush()).is_err() {
+            break;
+        }
+    }
+}
+
+/// Response to one request line
+fn handle(line: &str) -> Value {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return error(Value::Null, PARSE_ERROR, e.to_string(), None),
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+    match request.get("method").and_then(Value::as_str) {
+        Some("list_tools") => success(id, json!({ "tools": [calculate_continuous_tool()] })),
+        Some("calculate_continuous") => {
+            let params: CalculateParams = match serde_json::from_value(params) {
+                Ok(params) => params,
+                Err(e) => return error(id, INVALID_PARAMS, e.to_string(), None),
+            };
+            match calculate_continuous(&params.input, params.method) {
+                Ok(result) => success(id, json!(result)),
+                Err(e) => error(id, CALCULATION_ERROR, e.to_string(), Some(json!(e))),
+            }
+        }
+        Some(other) => error(id, METHOD_NOT_FOUND, format!("Unknown method '{}'", other), None),
+        None => error(id, INVALID_PARAMS, "Missing method".to_string(), None),
+    }
+}
+
+/// Tool description for `list_tools`
+fn calculate_continuous_tool() -> Value {
+    json!({
+        "name": "calculate_continuous",
+        "description": "Analyze a single- or multi-span wood beam under all code load \
+                        combinations and check bending, shear, and deflection per NDS.",
+        "input_schema": {
+            "type": "object",
+            "required": ["input"],
+            "properties": {
+                "input": {
+                    "type": "object",
+                    "description": "ContinuousBeamInput: spans, supports, load_case, adjustment_factors",
+                    "required": ["label", "spans", "supports", "load_case"]
+                },
+                "method": {
+                    "type": "string",
+                    "enum": ["Asd", "Lrfd"],
+                    "default": "Asd"
+                }
+            }
+        }
+    })
+}
+
+fn success(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error(id: Value, code: i64, message: String, data: Option<Value>) -> Value {
+    let mut error = json!({ "code": code, "message": message });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+    json!({ "jsonrpc": "2.0", "id": id, "error": error })
+}
@@ -0,0 +1,204 @@
+//! Batch PDF export
+//!
+//! Regenerates the project report for every `.stf` file in a directory.
+//! A file that cannot be loaded or rendered is reported and skipped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use calc_core::errors::{CalcError, CalcResult};
+use calc_core::file_io::load_project;
+use calc_core::pdf::render_project_pdf;
+
+/// Options for the `pdf-batch` command
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfBatchArgs {
+    /// Directory searched for project files
+    pub dir: PathBuf,
+    /// Directory the PDFs are written to
+    pub out: PathBuf,
+    /// Also search subdirectories, mirroring them under `out`
+    pub recursive: bool,
+}
+
+impl PdfBatchArgs {
+    /// Parse the arguments following `pdf-batch`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut dir = None;
+        let mut out = None;
+        let mut recursive = false;
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--out" => {
+                    let value = iter.next().ok_or("--out needs a directory")?;
+                    out = Some(PathBuf::from(value));
+                }
+                "--recursive" => recursive = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+                path if dir.is_none() => dir = Some(PathBuf::from(path)),
+                extra => return Err(format!("Unexpected argument '{}'", extra)),
+            }
+        }
+
+        Ok(Self {
+            dir: dir.ok_or("Missing project directory")?,
+            out: out.ok_or("Missing --out directory")?,
+            recursive,
+        })
+    }
+}
+
+/// Outcome of one project file
+#[derive(Debug)]
+pub struct BatchEntry {
+    /// Project file
+    pub source: PathBuf,
+    /// PDF written, or why the file was skipped
+    pub outcome: CalcResult<PathBuf>,
+}
+
+/// Render every project under `args.dir`, continuing past failures
+///
+/// Files are processed in path order. Errors listing the directory itself
+/// are returned; errors with individual files are recorded in their entry.
+pub fn render_all(args: &PdfBatchArgs) -> CalcResult<Vec<BatchEntry>> {
+    let mut sources = Vec::new();
+    find_projects(&args.dir, args.recursive, &mut sources)?;
+    sources.sort();
+
+    Ok(sources
+        .into_iter()
+        .map(|source| {
+            let relative = source.strip_prefix(&args.dir).unwrap_or(&source);
+            let target = args.out.join(relative).with_extension("pdf");
+            let outcome = render_one(&source, &target).map(|()| target);
+            BatchEntry { source, outcome }
+        })
+        .collect())
+}
+
+/// Run the batch and print a line per file plus a summary
+///
+/// Exits with 1 when any file failed and with 2 when the directory cannot be
+/// read.
+pub fn run(args: &PdfBatchArgs) -> ExitCode {
+    let entries = match render_all(args) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut failed = 0;
+    for entry in &entries {
+        match &entry.outcome {
+            Ok(target) => println!("OK    {} -> {}", entry.source.display(), target.display()),
+            Err(e) => {
+                failed += 1;
+                println!("FAIL  {}: {}", entry.source.display(), e);
+            }
+        }
+    }
+    println!();
+    println!("{} rendered, {} failed", entries.len() - failed, failed);
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn render_one(source: &Path, target: &Path) -> CalcResult<()> {
+    let project = load_project(source)?;
+    let pdf = render_project_pdf(&project)?;
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            CalcError::file_error("create", parent.display().to_string(), e.to_string())
+        })?;
+    }
+    fs::write(target, pdf)
+        .map_err(|e| CalcError::file_error("write", target.display().to_string(), e.to_string()))
+}
+
+fn find_projects(dir: &Path, recursive: bool, found: &mut Vec<PathBuf>) -> CalcResult<()> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| CalcError::file_error("read", dir.display().to_string(), e.to_string()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if recursive {
+                find_projects(&path, true, found)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext == "stf") {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calc_core::calculations::CalculationItem;
+    use calc_core::file_io::save_project;
+    use calc_core::project::{templates, Project};
+
+    #[test]
+    fn test_batch_continues_past_corrupt_file() {
+        // Unique root so concurrent test runs do not delete each other's files
+        let root =
+            std::env::temp_dir().join(format!("stratify_cli_pdf_batch_{}", uuid::Uuid::new_v4()));
+        let (dir, out) = (root.join("jobs"), root.join("pdf"));
+        fs::create_dir_all(dir.join("archive")).unwrap();
+
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        project.add_item(CalculationItem::Beam(templates::header()));
+        save_project(&project, &dir.join("a.stf")).unwrap();
+        save_project(&project, &dir.join("archive").join("b.stf")).unwrap();
+        fs::write(dir.join("corrupt.stf"), "{ not a project").unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let args = PdfBatchArgs::parse(&[
+            dir.display().to_string(),
+            "--out".into(),
+            out.display().to_string(),
+            "--recursive".into(),
+        ])
+        .unwrap();
+        let entries = render_all(&args).unwrap();
+
+        let names: Vec<_> = entries
+            .iter()
+            .map(|e| e.source.strip_prefix(&dir).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                PathBuf::from("a.stf"),
+                Path::new("archive").join("b.stf"),
+                PathBuf::from("corrupt.stf")
+            ]
+        );
+        assert!(entries[2].outcome.is_err());
+        for entry in &entries[..2] {
+            let pdf = fs::read(entry.outcome.as_ref().unwrap()).unwrap();
+            assert!(pdf.starts_with(b"%PDF"));
+        }
+        assert!(out.join("archive").join("b.pdf").exists());
+
+        // Without --recursive the subfolder is skipped
+        let flat = render_all(&PdfBatchArgs {
+            recursive: false,
+            ..args
+        })
+        .unwrap();
+        assert_eq!(flat.len(), 2);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,915 @@
+//! TUI state and key handling
+//!
+//! Kept free of terminal I/O so the editing logic can be tested directly.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use calc_core::calculations::{
+    calculate_continuous, CalculationItem, ContinuousBeamInput, ContinuousBeamResult,
+};
+use calc_core::errors::{CalcError, CalcResult};
+use calc_core::file_io::{load_project, save_project, FileLock};
+use calc_core::loads::{DesignMethod, DiscreteLoad, LoadDistribution, LoadType};
+use calc_core::materials::{
+    GlulamLayup, GlulamMaterial, GlulamStressClass, LvlGrade, LvlMaterial, Material, PslGrade,
+    PslMaterial, WoodGrade, WoodMaterial, WoodSpecies,
+};
+use calc_core::project::{templates, Project};
+use crossterm::event::KeyCode;
+use uuid::Uuid;
+
+/// Key bindings shown in the help overlay, as (keys, action)
+pub const KEY_BINDINGS: &[(&str, &str)] = &[
+    ("↑/↓, k/j", "Move the selection"),
+    ("Tab", "Switch between items and fields"),
+    ("Enter", "Edit the selected field / open the selected item"),
+    ("←/→, h/l", "Cycle material or load type"),
+    ("Esc", "Cancel an edit or close this help"),
+    ("d", "Cycle shear / moment / deflection diagram"),
+    ("m", "Toggle ASD / LRFD"),
+    ("a", "Add a floor beam"),
+    ("s", "Save the project"),
+    ("?", "Show or hide this help"),
+    ("q", "Quit (press twice with unsaved changes)"),
+];
+
+/// Pane that receives navigation keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Items,
+    Fields,
+}
+
+/// Diagram drawn under the fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagram {
+    Shear,
+    Moment,
+    Deflection,
+}
+
+impl Diagram {
+    /// Chart title and y-axis units
+    pub fn title(&self) -> (&'static str, &'static str) {
+        match self {
+            Diagram::Shear => ("Shear", "lb"),
+            Diagram::Moment => ("Moment", "ft-lb"),
+            Diagram::Deflection => ("Deflection", "in"),
+        }
+    }
+
+    /// Points of this diagram from a beam result
+    pub fn points<'a>(&self, result: &'a ContinuousBeamResult) -> &'a [(f64, f64)] {
+        match self {
+            Diagram::Shear => &result.shear_diagram,
+            Diagram::Moment => &result.moment_diagram,
+            Diagram::Deflection => &result.deflection_diagram,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Diagram::Shear => Diagram::Moment,
+            Diagram::Moment => Diagram::Deflection,
+            Diagram::Deflection => Diagram::Shear,
+        }
+    }
+}
+
+/// Editable field of a beam, indexed by span or load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    SpanLength(usize),
+    SpanWidth(usize),
+    SpanDepth(usize),
+    SpanMaterial(usize),
+    LoadType(usize),
+    LoadMagnitude(usize),
+    LoadStartMagnitude(usize),
+    LoadEndMagnitude(usize),
+    LoadPosition(usize),
+    LoadStart(usize),
+    LoadEnd(usize),
+}
+
+impl Field {
+    /// Every editable field of a beam, spans first
+    pub fn all(beam: &ContinuousBeamInput) -> Vec<Field> {
+        let mut fields = Vec::new();
+        for i in 0..beam.spans.len() {
+            fields.extend([
+                Field::SpanLength(i),
+                Field::SpanWidth(i),
+                Field::SpanDepth(i),
+                Field::SpanMaterial(i),
+            ]);
+        }
+        for (j, load) in beam.load_case.loads.iter().enumerate() {
+            fields.push(Field::LoadType(j));
+            match load.distribution {
+                LoadDistribution::UniformFull => fields.push(Field::LoadMagnitude(j)),
+                LoadDistribution::Point { .. }
+                | LoadDistribution::Moment { .. }
+                | LoadDistribution::HorizontalPoint { .. } => {
+                    fields.extend([Field::LoadMagnitude(j), Field::LoadPosition(j)])
+                }
+                LoadDistribution::UniformPartial { .. } => fields.extend([
+                    Field::LoadMagnitude(j),
+                    Field::LoadStart(j),
+                    Field::LoadEnd(j),
+                ]),
+                LoadDistribution::Trapezoidal { .. } => fields.extend([
+                    Field::LoadStartMagnitude(j),
+                    Field::LoadEndMagnitude(j),
+                    Field::LoadStart(j),
+                    Field::LoadEnd(j),
+                ]),
+            }
+        }
+        fields
+    }
+
+    /// Whether ←/→ cycle this field instead of typing a number
+    pub fn is_choice(&self) -> bool {
+        matches!(self, Field::SpanMaterial(_) | Field::LoadType(_))
+    }
+
+    /// Row label, e.g. "Span 1 length (ft)"
+    pub fn label(&self, beam: &ContinuousBeamInput) -> String {
+        let load_units = |j: usize| -> &'static str {
+            let load = &beam.load_case.loads[j];
+            match (&load.distribution, load.tributary_width_ft.is_some()) {
+                (LoadDistribution::Point { .. } | LoadDistribution::HorizontalPoint { .. }, _) => {
+                    "lb"
+                }
+                (LoadDistribution::Moment { .. }, _) => "ft-lb",
+                (_, true) => "psf",
+                (_, false) => "plf",
+            }
+        };
+        match *self {
+            Field::SpanLength(i) => format!("Span {} length (ft)", i + 1),
+            Field::SpanWidth(i) => format!("Span {} width (in)", i + 1),
+            Field::SpanDepth(i) => format!("Span {} depth (in)", i + 1),
+            Field::SpanMaterial(i) => format!("Span {} material", i + 1),
+            Field::LoadType(j) => {
+                format!(
+                    "Load {} type ({})",
+                    j + 1,
+                    beam.load_case.loads[j].distribution.display_name()
+                )
+            }
+            Field::LoadMagnitude(j) => format!("Load {} magnitude ({})", j + 1, load_units(j)),
+            Field::LoadStartMagnitude(j) => {
+                format!("Load {} start magnitude ({})", j + 1, load_units(j))
+            }
+            Field::LoadEndMagnitude(j) => {
+                format!("Load {} end magnitude ({})", j + 1, load_units(j))
+            }
+            Field::LoadPosition(j) => format!("Load {} position (ft)", j + 1),
+            Field::LoadStart(j) => format!("Load {} start (ft)", j + 1),
+            Field::LoadEnd(j) => format!("Load {} end (ft)", j + 1),
+        }
+    }
+
+    /// Current value as display text
+    pub fn value(&self, beam: &ContinuousBeamInput) -> String {
+        match *self {
+            Field::SpanMaterial(i) => beam.spans[i].material.display_name(),
+            Field::LoadType(j) => {
+                let load_type = beam.load_case.loads[j].load_type;
+                format!("{} - {}", load_type.code(), load_type.description())
+            }
+            _ => self
+                .number(beam)
+                .map(|v| format!("{}", v))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn number(&self, beam: &ContinuousBeamInput) -> Option<f64> {
+        match *self {
+            Field::SpanLength(i) => Some(beam.spans[i].length_ft),
+            Field::SpanWidth(i) => Some(beam.spans[i].width_in),
+            Field::SpanDepth(i) => Some(beam.spans[i].depth_in),
+            Field::LoadMagnitude(j) => Some(beam.load_case.loads[j].magnitude),
+            Field::LoadStartMagnitude(j) => match beam.load_case.loads[j].distribution {
+                LoadDistribution::Trapezoidal {
+                    start_magnitude, ..
+                } => Some(start_magnitude),
+                _ => None,
+            },
+            Field::LoadEndMagnitude(j) => match beam.load_case.loads[j].distribution {
+                LoadDistribution::Trapezoidal { end_magnitude, .. } => Some(end_magnitude),
+                _ => None,
+            },
+            Field::LoadPosition(j) => match beam.load_case.loads[j].distribution {
+                LoadDistribution::Point { position_ft }
+                | LoadDistribution::Moment { position_ft }
+                | LoadDistribution::HorizontalPoint { position_ft } => Some(position_ft),
+                _ => None,
+            },
+            Field::LoadStart(j) => match beam.load_case.loads[j].distribution {
+                LoadDistribution::UniformPartial { start_ft, .. }
+                | LoadDistribution::Trapezoidal { start_ft, .. } => Some(start_ft),
+                _ => None,
+            },
+            Field::LoadEnd(j) => match beam.load_case.loads[j].distribution {
+                LoadDistribution::UniformPartial { end_ft, .. }
+                | LoadDistribution::Trapezoidal { end_ft, .. } => Some(end_ft),
+                _ => None,
+            },
+            Field::SpanMaterial(_) | Field::LoadType(_) => None,
+        }
+    }
+
+    fn set_number(&self, beam: &mut ContinuousBeamInput, value: f64) {
+        match *self {
+            Field::SpanLength(i) => beam.spans[i].length_ft = value,
+            Field::SpanWidth(i) => beam.spans[i].width_in = value,
+            Field::SpanDepth(i) => {
+                // A tapered span keeps its taper
+                let span = &mut beam.spans[i];
+                if let Some(end) = span.depth_end_in.as_mut() {
+                    *end += value - span.depth_in;
+                }
+                span.depth_in = value;
+            }
+            Field::LoadMagnitude(j) => beam.load_case.loads[j].magnitude = value,
+            Field::LoadStartMagnitude(j) => {
+                // `magnitude` mirrors the start magnitude of a trapezoid
+                let load = &mut beam.load_case.loads[j];
+                if let LoadDistribution::Trapezoidal {
+                    start_magnitude, ..
+                } = &mut load.distribution
+                {
+                    *start_magnitude = value;
+                    load.magnitude = value;
+                }
+            }
+            Field::LoadEndMagnitude(j) => {
+                if let LoadDistribution::Trapezoidal { end_magnitude, .. } =
+                    &mut beam.load_case.loads[j].distribution
+                {
+                    *end_magnitude = value;
+                }
+            }
+            Field::LoadPosition(j) => set_position(&mut beam.load_case.loads[j], |p, _| *p = value),
+            Field::LoadStart(j) => set_position(&mut beam.load_case.loads[j], |s, _| *s = value),
+            Field::LoadEnd(j) => set_position(&mut beam.load_case.loads[j], |_, e| *e = value),
+            Field::SpanMaterial(_) | Field::LoadType(_) => {}
+        }
+    }
+
+    fn cycle(&self, beam: &mut ContinuousBeamInput, step: isize) {
+        match *self {
+            Field::SpanMaterial(i) => {
+                let choices = material_choices();
+                let current = choices.iter().position(|m| *m == beam.spans[i].material);
+                beam.spans[i].material = choices[step_index(current, step, choices.len())].clone();
+            }
+            Field::LoadType(j) => {
+                let load = &mut beam.load_case.loads[j];
+                let current = LoadType::ALL.iter().position(|t| *t == load.load_type);
+                load.load_type = LoadType::ALL[step_index(current, step, LoadType::ALL.len())];
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Apply `set` to a load's position (point loads) or start/end (ranged loads)
+fn set_position(load: &mut DiscreteLoad, set: impl FnOnce(&mut f64, &mut f64)) {
+    match &mut load.distribution {
+        LoadDistribution::Point { position_ft }
+        | LoadDistribution::Moment { position_ft }
+        | LoadDistribution::HorizontalPoint { position_ft } => {
+            let mut unused = 0.0;
+            set(position_ft, &mut unused)
+        }
+        LoadDistribution::UniformPartial { start_ft, end_ft }
+        | LoadDistribution::Trapezoidal {
+            start_ft, end_ft, ..
+        } => set(start_ft, end_ft),
+        LoadDistribution::UniformFull => {}
+    }
+}
+
+fn step_index(current: Option<usize>, step: isize, len: usize) -> usize {
+    match current {
+        Some(i) => (i as isize + step).rem_euclid(len as isize) as usize,
+        None => 0,
+    }
+}
+
+/// Materials offered when cycling a span's material
+pub fn material_choices() -> Vec<Material> {
+    let mut choices = Vec::new();
+    for species in WoodSpecies::ALL {
+        for grade in WoodGrade::ALL {
+            choices.push(Material::SawnLumber(WoodMaterial::new(species, grade)));
+        }
+    }
+    for class in GlulamStressClass::ALL {
+        for layup in GlulamLayup::ALL {
+            choices.push(Material::Glulam(GlulamMaterial::new(class, layup)));
+        }
+    }
+    choices.extend(LvlGrade::ALL.map(|g| Material::Lvl(LvlMaterial::new(g))));
+    choices.extend(PslGrade::ALL.map(|g| Material::Psl(PslMaterial::new(g))));
+    choices
+}
+
+/// State of the interactive editor
+pub struct App {
+    /// Project being edited
+    pub project: Project,
+    /// File the project is saved to
+    pub path: PathBuf,
+    /// Item UUIDs in display order (by label)
+    pub items: Vec<Uuid>,
+    /// Index into `items`
+    pub selected: usize,
+    /// Index into the selected beam's fields
+    pub field: usize,
+    pub focus: Focus,
+    /// Text being typed into a numeric field
+    pub editing: Option<String>,
+    pub diagram: Diagram,
+    /// Result of the selected beam, recalculated after each edit
+    pub result: Option<CalcResult<ContinuousBeamResult>>,
+    /// Pass/fail of every item; `None` when it cannot be calculated
+    pub item_passes: HashMap<Uuid, Option<bool>>,
+    pub modified: bool,
+    /// Lock on `path`, held from open (or first save) until the editor exits
+    file_lock: Option<FileLock>,
+    /// Who holds the lock when another user has the file open (read-only)
+    pub lock_holder: Option<String>,
+    /// One-line message for the status bar
+    pub status: String,
+    pub show_help: bool,
+    pub should_quit: bool,
+    confirm_quit: bool,
+}
+
+impl App {
+    /// Create the editor for a project saved at `path`
+    pub fn new(project: Project, path: impl Into<PathBuf>) -> Self {
+        let mut app = Self {
+            project,
+            path: path.into(),
+            items: Vec::new(),
+            selected: 0,
+            field: 0,
+            focus: Focus::Items,
+            editing: None,
+            diagram: Diagram::Moment,
+            result: None,
+            item_passes: HashMap::new(),
+            modified: false,
+            file_lock: None,
+            lock_holder: None,
+            status: "Press ? for help".to_string(),
+            show_help: false,
+            should_quit: false,
+            confirm_quit: false,
+        };
+        app.refresh_items();
+        app.recalculate_all();
+        app
+    }
+
+    /// Open a project file, or start an empty project if it does not exist
+    ///
+    /// Takes the file lock like the GUI. When another user holds it the
+    /// project opens read-only.
+    pub fn open(path: &Path) -> CalcResult<Self> {
+        if !path.exists() {
+            let mut app = Self::new(Project::new("", "", ""), path);
+            app.status = format!("New project {} - press a to add a beam", path.display());
+            return Ok(app);
+        }
+        let project = load_project(path)?;
+        let mut app = Self::new(project, path);
+        match FileLock::acquire(path, whoami::username()) {
+            Ok(lock) => app.file_lock = Some(lock),
+            Err(CalcError::FileLocked { locked_by, .. }) => {
+                app.status = format!("Read-only: {} has this file open", locked_by);
+                app.lock_holder = Some(locked_by);
+            }
+            // Retried on save, which refuses to write without the lock
+            Err(e) => app.status = format!("Warning: cannot lock {}: {}", path.display(), e),
+        }
+        Ok(app)
+    }
+
+    /// Whether edits are allowed, setting the status when they are not
+    fn can_edit(&mut self) -> bool {
+        match &self.lock_holder {
+            Some(holder) => {
+                self.status = format!("Read-only: {} has this file open", holder);
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// UUID of the selected item
+    pub fn selected_id(&self) -> Option<Uuid> {
+        self.items.get(self.selected).copied()
+    }
+
+    /// Selected item, if it is a wood beam
+    pub fn selected_beam(&self) -> Option<&ContinuousBeamInput> {
+        match self.project.get_item(&self.selected_id()?)? {
+            CalculationItem::Beam(beam) => Some(beam),
+            _ => None,
+        }
+    }
+
+    /// Editable fields of the selected beam
+    pub fn fields(&self) -> Vec<Field> {
+        self.selected_beam().map(Field::all).unwrap_or_default()
+    }
+
+    /// Handle one key press
+    pub fn handle_key(&mut self, key: KeyCode) {
+        if self.editing.is_some() {
+            self.handle_edit_key(key);
+            return;
+        }
+        if self.show_help {
+            if matches!(key, KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q')) {
+                self.show_help = false;
+            }
+            return;
+        }
+
+        let quitting = matches!(key, KeyCode::Char('q'));
+        match key {
+            KeyCode::Char('q') => {
+                if !self.modified || self.confirm_quit {
+                    self.should_quit = true;
+                } else {
+                    self.status =
+                        "Unsaved changes - press q again to quit, or s to save".to_string();
+                }
+            }
+            KeyCode::Char('?') => self.show_help = true,
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Items if self.selected_beam().is_some() => Focus::Fields,
+                    _ => Focus::Items,
+                }
+            }
+            KeyCode::Esc => self.focus = Focus::Items,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Left | KeyCode::Char('h') => self.cycle_field(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.cycle_field(1),
+            KeyCode::Enter => match self.focus {
+                Focus::Items if self.selected_beam().is_some() => self.focus = Focus::Fields,
+                Focus::Items => self.status = "Only wood beams can be edited here".to_string(),
+                Focus::Fields => self.start_edit(),
+            },
+            KeyCode::Char('d') => self.diagram = self.diagram.next(),
+            KeyCode::Char('m') if self.can_edit() => {
+                let method = match self.project.settings.design_method {
+                    DesignMethod::Asd => DesignMethod::Lrfd,
+                    DesignMethod::Lrfd => DesignMethod::Asd,
+                };
+                self.project.settings.design_method = method;
+                self.mark_modified();
+                self.recalculate_all();
+                self.status = format!("Design method: {}", method.display_name());
+            }
+            KeyCode::Char('a') if self.can_edit() => self.add_beam(),
+            KeyCode::Char('s') if self.can_edit() => self.save(),
+            _ => {}
+        }
+        self.confirm_quit = quitting && self.modified;
+    }
+
+    fn handle_edit_key(&mut self, key: KeyCode) {
+        let Some(buffer) = self.editing.as_mut() else {
+            return;
+        };
+        match key {
+            KeyCode::Char(c) if c.is_ascii_digit() || matches!(c, '.' | '-' | 'e' | 'E') => {
+                buffer.push(c)
+            }
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Esc => {
+                self.editing = None;
+                self.status = "Edit cancelled".to_string();
+            }
+            KeyCode::Enter => {
+                let text = self.editing.take().unwrap_or_default();
+                match text.trim().parse::<f64>() {
+                    Ok(value) if value.is_finite() => {
+                        self.apply(|field, beam| field.set_number(beam, value))
+                    }
+                    _ => self.status = format!("'{}' is not a number", text),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn move_selection(&mut self, step: isize) {
+        match self.focus {
+            Focus::Items => {
+                if self.items.is_empty() {
+                    return;
+                }
+                self.selected = step_index(Some(self.selected), step, self.items.len());
+                self.field = 0;
+                self.recalculate_selected();
+            }
+            Focus::Fields => {
+                let count = self.fields().len();
+                if count > 0 {
+                    self.field = step_index(Some(self.field), step, count);
+                }
+            }
+        }
+    }
+
+    fn start_edit(&mut self) {
+        let Some(field) = self.fields().get(self.field).copied() else {
+            return;
+        };
+        if field.is_choice() {
+            self.cycle_field(1);
+            return;
+        }
+        if !self.can_edit() {
+            return;
+        }
+        let current = self
+            .selected_beam()
+            .map(|beam| field.value(beam))
+            .unwrap_or_default();
+        self.editing = Some(current);
+        self.status = "Type a value, Enter to apply, Esc to cancel".to_string();
+    }
+
+    fn cycle_field(&mut self, step: isize) {
+        if self.focus != Focus::Fields {
+            return;
+        }
+        match self.fields().get(self.field) {
+            Some(field) if field.is_choice() => self.apply(|field, beam| field.cycle(beam, step)),
+            _ => {}
+        }
+    }
+
+    /// Apply an edit to the selected field of the selected beam
+    fn apply(&mut self, edit: impl FnOnce(&Field, &mut ContinuousBeamInput)) {
+        if !self.can_edit() {
+            return;
+        }
+        let Some(field) = self.fields().get(self.field).copied() else {
+            return;
+        };
+        let Some(id) = self.selected_id() else {
+            return;
+        };
+        if let Some(CalculationItem::Beam(beam)) = self.project.get_item_mut(&id) {
+            edit(&field, beam);
+            self.mark_modified();
+            self.recalculate_selected();
+            self.status = match &self.result {
+                Some(Err(e)) => format!("Error: {}", e),
+                _ => String::new(),
+            };
+        }
+    }
+
+    fn add_beam(&mut self) {
+        let mut beam = templates::floor_beam();
+        beam.label = format!("B-{}", self.project.item_count() + 1);
+        let label = beam.label.clone();
+        let id = self.project.add_item(CalculationItem::Beam(beam));
+        self.mark_modified();
+        self.refresh_items();
+        self.selected = self.items.iter().position(|i| *i == id).unwrap_or(0);
+        self.field = 0;
+        self.recalculate_selected();
+        self.status = format!("Added {}", label);
+    }
+
+    fn save(&mut self) {
+        if self.file_lock.is_none() {
+            match FileLock::acquire(&self.path, whoami::username()) {
+                Ok(lock) => self.file_lock = Some(lock),
+                Err(e) => {
+                    self.status = format!("Error: cannot save (lock failed): {}", e);
+                    return;
+                }
+            }
+        }
+        match save_project(&self.project, &self.path) {
+            Ok(()) => {
+                self.modified = false;
+                self.status = format!("Saved {}", self.path.display());
+            }
+            Err(e) => self.status = format!("Error: cannot save {}: {}", self.path.display(), e),
+        }
+    }
+
+    fn mark_modified(&mut self) {
+        self.modified = true;
+        self.project.touch();
+    }
+
+    fn refresh_items(&mut self) {
+        let mut items: Vec<(&Uuid, &CalculationItem)> = self.project.items.iter().collect();
+        items.sort_by(|a, b| a.1.label().cmp(b.1.label()).then(a.0.cmp(b.0)));
+        self.items = items.into_iter().map(|(id, _)| *id).collect();
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+    }
+
+    fn recalculate_all(&mut self) {
+        let method = self.project.settings.design_method;
+        self.item_passes = self
+            .project
+            .items
+            .iter()
+            .map(|(id, item)| {
+                let passes = match item {
+                    CalculationItem::Beam(beam) => {
+                        calculate_continuous(&self.project.beam_for_calculation(beam), method)
+                            .map(|r| r.passes())
+                    }
+                    other => self
+                        .project
+                        .item_for_calculation(other)
+                        .calculate(method)
+                        .map(|r| r.passes()),
+                };
+                (*id, passes.ok())
+            })
+            .collect();
+        self.recalculate_selected();
+    }
+
+    fn recalculate_selected(&mut self) {
+        let method = self.project.settings.design_method;
+        self.result = self
+            .selected_beam()
+            .map(|beam| calculate_continuous(&self.project.beam_for_calculation(beam), method));
+        if let Some(id) = self.selected_id() {
+            let passes = match (&self.result, self.project.get_item(&id)) {
+                (Some(result), _) => result.as_ref().ok().map(|r| r.passes()),
+                (None, Some(item)) => self
+                    .project
+                    .item_for_calculation(item)
+                    .calculate(method)
+                    .ok()
+                    .map(|r| r.passes()),
+                (None, None) => None,
+            };
+            self.item_passes.insert(id, passes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with_floor_beam() -> App {
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        App::new(
+            project,
+            std::env::temp_dir().join("stratify_cli_tui_unused.stf"),
+        )
+    }
+
+    /// Unique temp path so concurrent test runs do not share files
+    fn temp_project_path() -> PathBuf {
+        std::env::temp_dir().join(format!("stratify_cli_tui_{}.stf", Uuid::new_v4()))
+    }
+
+    fn press(app: &mut App, keys: &[KeyCode]) {
+        for key in keys {
+            app.handle_key(*key);
+        }
+    }
+
+    fn moment(app: &App) -> f64 {
+        app.result
+            .as_ref()
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .max_positive_moment_ftlb
+    }
+
+    #[test]
+    fn test_edit_span_length_recalculates() {
+        let mut app = app_with_floor_beam();
+        let before = moment(&app);
+        assert_eq!(app.fields()[0], Field::SpanLength(0));
+
+        press(&mut app, &[KeyCode::Tab, KeyCode::Enter]);
+        assert_eq!(app.editing.as_deref(), Some("14"));
+        press(
+            &mut app,
+            &[
+                KeyCode::Backspace,
+                KeyCode::Backspace,
+                KeyCode::Char('1'),
+                KeyCode::Char('6'),
+                KeyCode::Enter,
+            ],
+        );
+
+        assert_eq!(app.selected_beam().unwrap().spans[0].length_ft, 16.0);
+        assert!(app.modified);
+        // Uniform load: M grows with L²
+        assert!((moment(&app) / before - (16.0_f64 / 14.0).powi(2)).abs() < 0.02);
+
+        // Non-numbers are rejected without touching the beam
+        press(
+            &mut app,
+            &[
+                KeyCode::Enter,
+                KeyCode::Char('-'),
+                KeyCode::Char('-'),
+                KeyCode::Enter,
+            ],
+        );
+        assert_eq!(app.selected_beam().unwrap().spans[0].length_ft, 16.0);
+        assert!(app.status.contains("not a number"));
+    }
+
+    #[test]
+    fn test_cycle_material_and_load_type() {
+        let mut app = app_with_floor_beam();
+        let fields = app.fields();
+        let material = fields
+            .iter()
+            .position(|f| *f == Field::SpanMaterial(0))
+            .unwrap();
+        let load_type = fields
+            .iter()
+            .position(|f| *f == Field::LoadType(1))
+            .unwrap();
+
+        app.focus = Focus::Fields;
+        app.field = material;
+        let original = app.selected_beam().unwrap().spans[0].material.clone();
+        press(&mut app, &[KeyCode::Right]);
+        assert_ne!(app.selected_beam().unwrap().spans[0].material, original);
+        press(&mut app, &[KeyCode::Left]);
+        assert_eq!(app.selected_beam().unwrap().spans[0].material, original);
+
+        app.field = load_type;
+        press(&mut app, &[KeyCode::Right]);
+        assert_eq!(
+            app.selected_beam().unwrap().load_case.loads[1].load_type,
+            LoadType::LiveRoof
+        );
+    }
+
+    #[test]
+    fn test_edit_trapezoid_magnitudes() {
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let mut beam = templates::floor_beam();
+        beam.load_case.loads = vec![DiscreteLoad::trapezoidal(
+            LoadType::Snow,
+            0.0,
+            100.0,
+            0.0,
+            10.0,
+        )];
+        project.add_item(CalculationItem::Beam(beam));
+        let mut app = App::new(
+            project,
+            std::env::temp_dir().join("stratify_cli_tui_unused.stf"),
+        );
+        let fields = app.fields();
+        let start = fields
+            .iter()
+            .position(|f| *f == Field::LoadStartMagnitude(0))
+            .unwrap();
+        let end = fields
+            .iter()
+            .position(|f| *f == Field::LoadEndMagnitude(0))
+            .unwrap();
+
+        app.focus = Focus::Fields;
+        app.field = start;
+        press(
+            &mut app,
+            &[
+                KeyCode::Enter,
+                KeyCode::Backspace,
+                KeyCode::Char('5'),
+                KeyCode::Char('0'),
+                KeyCode::Enter,
+            ],
+        );
+        app.field = end;
+        press(
+            &mut app,
+            &[KeyCode::Enter, KeyCode::Char('0'), KeyCode::Enter],
+        ); // 100 -> 1000
+
+        let load = &app.selected_beam().unwrap().load_case.loads[0];
+        assert_eq!(load.magnitude, 50.0);
+        assert!(matches!(
+            load.distribution,
+            LoadDistribution::Trapezoidal {
+                start_magnitude: 50.0,
+                end_magnitude: 1000.0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_quit_asks_before_discarding_changes() {
+        let mut app = app_with_floor_beam();
+        press(&mut app, &[KeyCode::Char('a'), KeyCode::Char('q')]);
+        assert!(!app.should_quit);
+        press(&mut app, &[KeyCode::Char('q')]);
+        assert!(app.should_quit);
+
+        // Another key in between resets the confirmation
+        let mut app = app_with_floor_beam();
+        press(
+            &mut app,
+            &[
+                KeyCode::Char('a'),
+                KeyCode::Char('q'),
+                KeyCode::Char('d'),
+                KeyCode::Char('q'),
+            ],
+        );
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_save_and_reopen() {
+        let path = temp_project_path();
+
+        let mut app = App::open(&path).unwrap();
+        assert!(app.items.is_empty());
+        press(
+            &mut app,
+            &[KeyCode::Char('a'), KeyCode::Char('m'), KeyCode::Char('s')],
+        );
+        assert!(!app.modified);
+        assert_eq!(app.item_passes.values().filter(|p| p.is_some()).count(), 1);
+        drop(app);
+
+        let reopened = App::open(&path).unwrap();
+        assert_eq!(reopened.items.len(), 1);
+        assert_eq!(reopened.project.settings.design_method, DesignMethod::Lrfd);
+        assert!(matches!(reopened.result, Some(Ok(_))));
+        assert!(reopened.lock_holder.is_none());
+
+        drop(reopened);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_locked_file_opens_read_only() {
+        let path = temp_project_path();
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        save_project(&project, &path).unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+
+        let other = FileLock::acquire(&path, "someone-else").unwrap();
+        let mut app = App::open(&path).unwrap();
+        assert!(app
+            .lock_holder
+            .as_deref()
+            .unwrap()
+            .starts_with("someone-else"));
+
+        // Neither edits nor saves go through
+        press(&mut app, &[KeyCode::Char('a'), KeyCode::Char('s')]);
+        assert_eq!(app.items.len(), 1);
+        assert!(!app.modified);
+        assert!(app.status.starts_with("Read-only: someone-else"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), saved);
+
+        // Once released, the next open takes the lock
+        drop(other);
+        drop(app);
+        let app = App::open(&path).unwrap();
+        assert!(app.lock_holder.is_none());
+
+        drop(app);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,115 @@
+//! Interactive terminal editor
+//!
+//! Lists the items in a project file, edits the spans, loads, and materials
+//! of wood beams with live pass/fail, and plots the shear, moment, and
+//! deflection diagrams. Press `?` inside the editor for the key bindings.
+
+mod app;
+mod ui;
+
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crossterm::event::{self, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+
+use app::App;
+
+/// Options for the `tui` command
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuiArgs {
+    /// Project file to edit; created on first save if it does not exist
+    pub project: PathBuf,
+}
+
+impl TuiArgs {
+    /// Parse the arguments following `tui`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        match args {
+            [] => Err("Missing project file".to_string()),
+            [flag, ..] if flag.starts_with("--") => Err(format!("Unknown option '{}'", flag)),
+            [path] => Ok(Self {
+                project: PathBuf::from(path),
+            }),
+            [_, extra, ..] => Err(format!("Unexpected argument '{}'", extra)),
+        }
+    }
+}
+
+/// Run the editor until the user quits
+///
+/// Exits with 2 when the project cannot be read and 1 on a terminal error.
+pub fn run(args: &TuiArgs) -> ExitCode {
+    let mut app = match App::open(&args.project) {
+        Ok(app) => app,
+        Err(e) => {
+            eprintln!("Error: cannot read {}: {}", args.project.display(), e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut terminal = ratatui::init();
+    let outcome = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: terminal: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.handle_key(key.code);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calc_core::calculations::CalculationItem;
+    use calc_core::project::{templates, Project};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_tui_args() {
+        assert_eq!(TuiArgs::parse(&args(&["job.stf"])).unwrap().project, PathBuf::from("job.stf"));
+        assert!(TuiArgs::parse(&args(&[])).is_err());
+        assert!(TuiArgs::parse(&args(&["--method"])).is_err());
+        assert!(TuiArgs::parse(&args(&["a.stf", "b.stf"])).is_err());
+    }
+
+    #[test]
+    fn test_draw_beam_and_help() {
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        let mut app = App::new(project, "job.stf");
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+
+        terminal.draw(|frame| ui::draw(frame, &app)).unwrap();
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains("FB-1"));
+        assert!(screen.contains("PASS"));
+        assert!(screen.contains("Span 1 length (ft)"));
+
+        app.handle_key(crossterm::event::KeyCode::Char('?'));
+        terminal.draw(|frame| ui::draw(frame, &app)).unwrap();
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains("Cycle shear / moment / deflection diagram"));
+    }
+}
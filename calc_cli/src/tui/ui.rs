@@ -0,0 +1,232 @@
+//! TUI rendering
+//!
+//! Items and results on the left; the selected beam's fields and a braille
+//! plot of its shear, moment, or deflection diagram on the right.
+
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Axis, Block, Chart, Clear, Dataset, GraphType, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    Wrap,
+};
+use ratatui::Frame;
+
+use super::app::{App, Focus, KEY_BINDINGS};
+
+/// Draw the whole screen
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [left, right] = Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(body);
+    let [items_area, results_area] =
+        Layout::vertical([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(left);
+    let [fields_area, chart_area] =
+        Layout::vertical([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(right);
+
+    let title = format!(
+        " Stratify - {}{}{}  [{}]",
+        app.path.display(),
+        if app.modified { " *" } else { "" },
+        if app.lock_holder.is_some() { " [Read-Only]" } else { "" },
+        app.project.settings.design_method.code()
+    );
+    frame.render_widget(Paragraph::new(title).bold().reversed(), header);
+
+    draw_items(frame, app, items_area);
+    draw_results(frame, app, results_area);
+    draw_fields(frame, app, fields_area);
+    draw_diagram(frame, app, chart_area);
+
+    let status = if app.status.is_empty() { "? help  q quit" } else { app.status.as_str() };
+    frame.render_widget(Paragraph::new(status).dim(), footer);
+
+    if app.show_help {
+        draw_help(frame);
+    }
+}
+
+fn pane(title: &str, focused: bool) -> Block<'static> {
+    let block = Block::bordered().title(format!(" {} ", title));
+    if focused {
+        block.border_style(Style::default().fg(Color::Cyan))
+    } else {
+        block
+    }
+}
+
+fn pass_span(passes: Option<bool>) -> Span<'static> {
+    match passes {
+        Some(true) => Span::styled("OK  ", Style::default().fg(Color::Green)),
+        Some(false) => Span::styled("NG  ", Style::default().fg(Color::Red)),
+        None => Span::styled("ERR ", Style::default().fg(Color::Yellow)),
+    }
+}
+
+fn draw_items(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .items
+        .iter()
+        .filter_map(|id| {
+            let item = app.project.get_item(id)?;
+            let passes = app.item_passes.get(id).copied().flatten();
+            Some(ListItem::new(Line::from(vec![
+                pass_span(passes),
+                Span::raw(item.label().to_string()),
+                Span::raw(format!("  {}", item.calc_type())).dim(),
+            ])))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(pane("Items", app.focus == Focus::Items))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected((!app.items.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_results(frame: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = match &app.result {
+        Some(Ok(result)) => {
+            let verdict = if result.passes() {
+                Span::styled("PASS", Style::default().fg(Color::Green).bold())
+            } else {
+                Span::styled("FAIL", Style::default().fg(Color::Red).bold())
+            };
+            let mut lines = vec![
+                Line::from(vec![
+                    verdict,
+                    Span::raw(format!(
+                        "  {:.2} {} (span {})",
+                        result.governing_unity,
+                        result.governing_condition.display_name(),
+                        result.governing_span + 1
+                    )),
+                ]),
+                Line::from(format!("Combination: {}", result.governing_combination)),
+                Line::from(format!("Max +M: {:.0} ft-lb", result.max_positive_moment_ftlb)),
+                Line::from(format!("Max -M: {:.0} ft-lb", result.max_negative_moment_ftlb)),
+                Line::from(format!("Max V:  {:.0} lb", result.max_shear_lb)),
+                Line::from(format!("Max Δ:  {:.3} in", result.max_deflection_in)),
+            ];
+            for span in &result.span_results {
+                lines.push(Line::from(format!(
+                    "Span {}: fb {:.2}  fv {:.2}  Δ {:.2}",
+                    span.span_index + 1,
+                    span.bending_unity,
+                    span.shear_unity,
+                    span.deflection_unity
+                )));
+            }
+            lines
+        }
+        Some(Err(e)) => vec![Line::styled(format!("Error: {}", e), Style::default().fg(Color::Red))],
+        None => match app.selected_id().and_then(|id| app.project.get_item(&id)) {
+            Some(item) => vec![
+                Line::from(vec![
+                    pass_span(app.selected_id().and_then(|id| app.item_passes.get(&id).copied().flatten())),
+                    Span::raw(item.label().to_string()),
+                ]),
+                Line::from(format!("{} items are not editable here", item.calc_type())).dim(),
+            ],
+            None => vec![Line::from("No items - press a to add a beam").dim()],
+        },
+    };
+    frame.render_widget(
+        Paragraph::new(lines).block(pane("Results", false)).wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+fn draw_fields(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(beam) = app.selected_beam() else {
+        frame.render_widget(pane("Fields", false), area);
+        return;
+    };
+    let rows: Vec<Row> = app
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let value = match &app.editing {
+                Some(buffer) if i == app.field => Span::styled(format!("{}▏", buffer), Style::default().fg(Color::Yellow)),
+                _ if field.is_choice() => Span::raw(format!("◂ {} ▸", field.value(beam))),
+                _ => Span::raw(field.value(beam)),
+            };
+            Row::new(vec![Line::from(field.label(beam)), Line::from(value)])
+        })
+        .collect();
+
+    let focused = app.focus == Focus::Fields;
+    let table = Table::new(rows, [Constraint::Length(28), Constraint::Min(10)])
+        .block(pane(&format!("{} fields", beam.label), focused))
+        .row_highlight_style(if focused {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default()
+        });
+    let mut state = TableState::default().with_selected(Some(app.field));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_diagram(frame: &mut Frame, app: &App, area: Rect) {
+    let (name, units) = app.diagram.title();
+    let block = pane(&format!("{} ({}) - d to cycle", name, units), false);
+    let Some(Ok(result)) = &app.result else {
+        frame.render_widget(block, area);
+        return;
+    };
+    let points = app.diagram.points(result);
+    if points.is_empty() {
+        frame.render_widget(block, area);
+        return;
+    }
+
+    let x_max = points.iter().map(|p| p.0).fold(0.0, f64::max);
+    let y_min = points.iter().map(|p| p.1).fold(0.0, f64::min);
+    let y_max = points.iter().map(|p| p.1).fold(0.0, f64::max);
+    let pad = ((y_max - y_min) * 0.1).max(1e-6);
+    let baseline = [(0.0, 0.0), (x_max, 0.0)];
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().dark_gray())
+            .data(&baseline),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(points),
+    ];
+    let number = |v: f64| if v.abs() >= 100.0 { format!("{:.0}", v) } else { format!("{:.2}", v) };
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .bounds([0.0, x_max])
+                .labels(["0".to_string(), format!("{:.1}", x_max / 2.0), format!("{:.1} ft", x_max)]),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([y_min - pad, y_max + pad])
+                .labels([number(y_min), "0".to_string(), number(y_max)]),
+        );
+    frame.render_widget(chart, area);
+}
+
+fn draw_help(frame: &mut Frame) {
+    let height = KEY_BINDINGS.len() as u16 + 2;
+    let [area] = Layout::vertical([Constraint::Length(height)]).flex(Flex::Center).areas(frame.area());
+    let [area] = Layout::horizontal([Constraint::Length(62)]).flex(Flex::Center).areas(area);
+
+    let rows = KEY_BINDINGS
+        .iter()
+        .map(|(keys, action)| Row::new(vec![Line::from(*keys).bold(), Line::from(*action)]));
+    let table = Table::new(rows, [Constraint::Length(12), Constraint::Min(20)])
+        .block(Block::bordered().title(" Keys - Esc to close "));
+    frame.render_widget(Clear, area);
+    frame.render_widget(table, area);
+}
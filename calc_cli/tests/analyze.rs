@@ -0,0 +1,84 @@
+//! Exit codes of the `calc_cli analyze` command
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use calc_core::calculations::{calculate_continuous, CalculationItem, ContinuousBeamInput};
+use calc_core::errors::WarningSeverity;
+use calc_core::file_io::save_project;
+use calc_core::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+use calc_core::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
+use calc_core::project::Project;
+use uuid::Uuid;
+
+/// Unique temp path so concurrent test runs do not share files
+fn temp_path(extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("stratify_cli_strict_{}.{}", Uuid::new_v4(), extension))
+}
+
+fn analyze(project: &Path, out: &Path, strict: bool) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_calc_cli"));
+    command.arg("analyze").arg(project).arg("--out").arg(out);
+    if strict {
+        command.arg("--strict");
+    }
+    command.output().unwrap()
+}
+
+fn save_beam(beam: ContinuousBeamInput) -> PathBuf {
+    let mut project = Project::new("Engineer", "25-001", "Client");
+    project.add_item(CalculationItem::Beam(beam));
+    let path = temp_path("stf");
+    save_project(&project, &path).unwrap();
+    path
+}
+
+#[test]
+fn test_strict_fails_on_warnings() {
+    // A 4x4 over 10 ft passes lightly loaded but is slender: L/d = 34.3 > 30
+    let loads = EnhancedLoadCase::new("Roof").with_load(DiscreteLoad::uniform(LoadType::Dead, 10.0));
+    let beam = ContinuousBeamInput::simple_span("B-1", 10.0, 3.5, 3.5, Material::default(), loads);
+    let result = calculate_continuous(&beam, DesignMethod::Asd).unwrap();
+    assert!(result.passes());
+    assert!(result.warnings.iter().any(|w| w.code == "HIGH_SPAN_TO_DEPTH"));
+
+    let path = save_beam(beam);
+    let out = temp_path("json");
+
+    let lenient = analyze(&path, &out, false);
+    assert!(lenient.status.success());
+    let stderr = String::from_utf8(lenient.stderr).unwrap();
+    assert!(stderr.contains("B-1: [Caution] HIGH_SPAN_TO_DEPTH"), "{}", stderr);
+    assert!(stderr.contains("HIGH_SPAN_TO_DEPTH x1"), "{}", stderr);
+
+    let strict = analyze(&path, &out, true);
+    assert_eq!(strict.status.code(), Some(1));
+
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&out);
+}
+
+#[test]
+fn test_strict_ignores_info_warnings() {
+    // A typical joist with bearing at one end only: BEARING_NOT_CHECKED is Info
+    let loads = EnhancedLoadCase::new("Floor")
+        .with_load(DiscreteLoad::uniform(LoadType::Live, 40.0))
+        .without_self_weight();
+    let material = Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2));
+    let beam = ContinuousBeamInput::simple_span("J-1", 12.0, 1.5, 9.25, material, loads).with_bearing_length(0, 3.5);
+    let result = calculate_continuous(&beam, DesignMethod::Asd).unwrap();
+    assert!(result.passes());
+    assert!(!result.warnings.is_empty());
+    assert!(result.warnings.iter().all(|w| w.severity == WarningSeverity::Info));
+
+    let path = save_beam(beam);
+    let out = temp_path("json");
+
+    let strict = analyze(&path, &out, true);
+    let stderr = String::from_utf8(strict.stderr).unwrap();
+    assert!(stderr.contains("J-1: [Info] BEARING_NOT_CHECKED"), "{}", stderr);
+    assert!(strict.status.success(), "{}", stderr);
+
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&out);
+}
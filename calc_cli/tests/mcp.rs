@@ -0,0 +1,64 @@
+//! Round trip through the `calc_mcp` server binary
+
+#![cfg(feature = "mcp")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use calc_core::loads::DesignMethod;
+use calc_core::project::templates;
+use serde_json::{json, Value};
+
+fn exchange(requests: &[Value]) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_calc_mcp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    {
+        let stdin = child.stdin.as_mut().unwrap();
+        for request in requests {
+            writeln!(stdin, "{}", request).unwrap();
+        }
+    }
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_list_tools_and_calculate() {
+    let beam = templates::header();
+    let expected = calc_core::calculations::calculate_continuous(&beam, DesignMethod::Asd).unwrap();
+
+    let mut invalid = beam.clone();
+    invalid.spans[0].length_ft = -1.0;
+
+    let responses = exchange(&[
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "list_tools" }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "calculate_continuous",
+                "params": { "input": beam, "method": "Asd" } }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "calculate_continuous", "params": { "input": invalid } }),
+        json!({ "jsonrpc": "2.0", "id": 4, "method": "calculate_column" }),
+    ]);
+    assert_eq!(responses.len(), 4);
+
+    let tools = responses[0]["result"]["tools"].as_array().unwrap();
+    assert_eq!(tools[0]["name"], "calculate_continuous");
+    assert!(tools[0]["input_schema"]["properties"]["input"].is_object());
+
+    let result = &responses[1]["result"];
+    assert_eq!(responses[1]["id"], 2);
+    assert_eq!(result["governing_unity"], json!(expected.governing_unity));
+    assert_eq!(result["reactions"], json!(expected.reactions));
+
+    let error = &responses[2]["error"];
+    assert_eq!(error["data"]["type"], "InvalidInput");
+    assert_eq!(error["data"]["details"]["field"], "spans[0]");
+
+    assert_eq!(responses[3]["error"]["code"], -32601);
+}
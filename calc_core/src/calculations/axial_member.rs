@@ -0,0 +1,352 @@
+//! # Axial Member Calculation
+//!
+//! Checks a wood member carrying only axial force, such as a truss chord or
+//! web, in tension or compression depending on the sign of the force.
+//!
+//! ## Tension (NDS 3.8)
+//!
+//! ```text
+//! ft = T / A_net ≤ Ft' = Ft × C_D × C_M × C_t × C_F × C_i
+//! ```
+//!
+//! The net area deducts the bolt holes of [`SectionDeductions`] at the
+//! critical section (NDS 3.1.2).
+//!
+//! ## Compression
+//!
+//! Delegated to the [`column`](super::column) stability check on the gross
+//! section.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::axial_member::{calculate, AxialAction, AxialMemberInput};
+//! use calc_core::materials::{WoodGrade, WoodMaterial, WoodSpecies};
+//!
+//! let web = AxialMemberInput::new(
+//!     "W-1",
+//!     4000.0,
+//!     6.0,
+//!     1.5,
+//!     5.5,
+//!     WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2),
+//! );
+//! let result = calculate(&web).unwrap();
+//! assert_eq!(result.action, AxialAction::Tension);
+//! assert!(result.passes());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::column::{self, ColumnEndCondition, ColumnInput, ColumnResult};
+use crate::errors::{CalcError, CalcResult};
+use crate::materials::WoodMaterial;
+use crate::nds_factors::{AdjustmentFactors, SizeFactor};
+use crate::section_deductions::SectionDeductions;
+
+/// Whether an axial member is pulled or pushed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AxialAction {
+    /// Axial force ≥ 0
+    Tension,
+    /// Axial force < 0
+    Compression,
+}
+
+/// Input parameters for a wood axial member.
+///
+/// ## JSON Example
+///
+/// ```json
+/// {
+///   "label": "W-1",
+///   "axial_force_lb": -3000.0,
+///   "length_ft": 6.0,
+///   "width_in": 1.5,
+///   "depth_in": 3.5,
+///   "material": { "species": "DF-L", "grade": "No.2" },
+///   "k_factor": 1.0
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AxialMemberInput {
+    /// User label for this member (e.g., "W-3", "Bottom chord")
+    pub label: String,
+
+    /// Axial force (lb) - positive tension, negative compression
+    pub axial_force_lb: f64,
+
+    /// Length between panel points (ft), the unbraced length in compression
+    pub length_ft: f64,
+
+    /// Actual member width in inches (e.g., 1.5 for a 2x)
+    pub width_in: f64,
+
+    /// Actual member depth in inches
+    pub depth_in: f64,
+
+    /// Wood material (species and grade)
+    pub material: WoodMaterial,
+
+    /// Effective length factor K for compression
+    ///
+    /// Used for any axis without an explicit end condition.
+    pub k_factor: f64,
+
+    /// End condition for buckling in the depth direction (Ke overrides `k_factor`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_condition_depth: Option<ColumnEndCondition>,
+
+    /// End condition for buckling in the width direction (Ke overrides `k_factor`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_condition_width: Option<ColumnEndCondition>,
+
+    /// NDS adjustment factors; compression uses only the load duration
+    #[serde(default)]
+    pub adjustment_factors: AdjustmentFactors,
+
+    /// Bolt holes at the critical section, deducted for tension
+    ///
+    /// Each of the `hole_count` holes of `hole_diameter_in` is bored through
+    /// the width.
+    #[serde(default)]
+    pub section_deductions: SectionDeductions,
+}
+
+impl AxialMemberInput {
+    /// Create a pin-ended member with default adjustment factors and no holes
+    pub fn new(
+        label: impl Into<String>,
+        axial_force_lb: f64,
+        length_ft: f64,
+        width_in: f64,
+        depth_in: f64,
+        material: WoodMaterial,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            axial_force_lb,
+            length_ft,
+            width_in,
+            depth_in,
+            material,
+            k_factor: 1.0,
+            end_condition_depth: None,
+            end_condition_width: None,
+            adjustment_factors: AdjustmentFactors::default(),
+            section_deductions: SectionDeductions::default(),
+        }
+    }
+
+    /// Deduct `count` bolt holes of `diameter_in` at the critical section (builder pattern)
+    pub fn with_holes(mut self, diameter_in: f64, count: u8) -> Self {
+        self.section_deductions = self.section_deductions.with_holes(diameter_in, count);
+        self
+    }
+
+    /// Tension or compression, from the sign of the axial force
+    pub fn action(&self) -> AxialAction {
+        if self.axial_force_lb < 0.0 {
+            AxialAction::Compression
+        } else {
+            AxialAction::Tension
+        }
+    }
+
+    /// Gross cross-sectional area A = bd (in²)
+    pub fn area_in2(&self) -> f64 {
+        self.width_in * self.depth_in
+    }
+
+    /// Net area through the holes A_net = A - n·D·b (in²)
+    pub fn net_area_in2(&self) -> f64 {
+        let deductions = &self.section_deductions;
+        if deductions.has_holes() {
+            self.area_in2() - f64::from(deductions.hole_count) * deductions.hole_diameter_in * self.width_in
+        } else {
+            self.area_in2()
+        }
+    }
+
+    /// Validate input parameters.
+    pub fn validate(&self) -> CalcResult<()> {
+        if self.length_ft <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "length_ft",
+                self.length_ft.to_string(),
+                "Length must be positive",
+            ));
+        }
+        if self.width_in <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "width_in",
+                self.width_in.to_string(),
+                "Width must be positive",
+            ));
+        }
+        if self.depth_in <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "depth_in",
+                self.depth_in.to_string(),
+                "Depth must be positive",
+            ));
+        }
+        if self.net_area_in2() <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "section_deductions",
+                self.section_deductions.hole_count.to_string(),
+                "Holes remove the whole net section",
+            ));
+        }
+        Ok(())
+    }
+
+    /// The compression check as a column of the same length and section
+    fn column_input(&self) -> ColumnInput {
+        ColumnInput {
+            label: self.label.clone(),
+            height_ft: self.length_ft,
+            axial_load_lb: -self.axial_force_lb,
+            material: self.material.clone(),
+            width_in: self.width_in,
+            depth_in: self.depth_in,
+            k_factor: self.k_factor,
+            end_condition_depth: self.end_condition_depth,
+            end_condition_width: self.end_condition_width,
+            applied_moment_ftlb: None,
+            eccentricity_in: None,
+            load_duration: self.adjustment_factors.load_duration,
+        }
+    }
+}
+
+/// Results from an axial member calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AxialMemberResult {
+    /// Which check governs, from the sign of the force
+    pub action: AxialAction,
+
+    /// Net area resisting tension (in²)
+    pub net_area_in2: f64,
+
+    /// Tension stress ft = T / A_net (psi), zero in compression
+    pub actual_ft_psi: f64,
+
+    /// Allowable tension stress Ft' (psi)
+    pub allowable_ft_psi: f64,
+
+    /// Compression check, for a member in compression
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<ColumnResult>,
+
+    /// Governing unity ratio: ft / Ft' in tension, fc / Fc' in compression
+    pub unity: f64,
+}
+
+impl AxialMemberResult {
+    /// Check if the member passes (unity ≤ 1.0)
+    pub fn passes(&self) -> bool {
+        self.unity <= 1.0
+    }
+}
+
+/// Calculate axial member capacity.
+///
+/// # Returns
+///
+/// * `Ok(AxialMemberResult)` - Tension or compression check per the force sign
+/// * `Err(CalcError)` - If inputs are invalid
+pub fn calculate(input: &AxialMemberInput) -> CalcResult<AxialMemberResult> {
+    input.validate()?;
+
+    let props = input.material.properties();
+    let c_f = SizeFactor::new(input.depth_in, input.width_in).factor_ft();
+    let allowable_ft_psi = input.adjustment_factors.adjusted_ft(props.ft_psi, c_f);
+    let net_area_in2 = input.net_area_in2();
+
+    match input.action() {
+        AxialAction::Tension => {
+            let actual_ft_psi = input.axial_force_lb / net_area_in2;
+            Ok(AxialMemberResult {
+                action: AxialAction::Tension,
+                net_area_in2,
+                actual_ft_psi,
+                allowable_ft_psi,
+                compression: None,
+                unity: actual_ft_psi / allowable_ft_psi,
+            })
+        }
+        AxialAction::Compression => {
+            let compression = column::calculate(&input.column_input())?;
+            Ok(AxialMemberResult {
+                action: AxialAction::Compression,
+                net_area_in2,
+                actual_ft_psi: 0.0,
+                allowable_ft_psi,
+                unity: compression.axial_unity,
+                compression: Some(compression),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::materials::{WoodGrade, WoodSpecies};
+    use crate::nds_factors::LoadDuration;
+
+    fn test_web(axial_force_lb: f64) -> AxialMemberInput {
+        AxialMemberInput::new(
+            "W-1",
+            axial_force_lb,
+            6.0,
+            1.5,
+            5.5,
+            WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2),
+        )
+    }
+
+    #[test]
+    fn test_tension_on_net_section() {
+        // 2x6 DF-L No.2 with one 11/16" bolt hole:
+        // A_net = 8.25 - 0.6875 × 1.5 = 7.219 in², Ft' = 575 × 1.3 = 747.5 psi
+        let web = test_web(4000.0).with_holes(0.6875, 1);
+        let result = calculate(&web).unwrap();
+
+        assert_eq!(result.action, AxialAction::Tension);
+        assert!((result.net_area_in2 - 7.21875).abs() < 1e-9);
+        assert!((result.allowable_ft_psi - 747.5).abs() < 1e-9);
+        assert!((result.actual_ft_psi - 4000.0 / 7.21875).abs() < 1e-9);
+        assert!((result.unity - 554.11 / 747.5).abs() < 1e-4);
+        assert!(result.compression.is_none());
+        assert!(result.passes());
+
+        // C_D = 1.15 for snow raises Ft'
+        let mut snow = web.clone();
+        snow.adjustment_factors.load_duration = LoadDuration::Snow;
+        assert!((calculate(&snow).unwrap().allowable_ft_psi - 747.5 * 1.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compression_uses_column_check() {
+        let web = test_web(-3000.0).with_holes(0.6875, 1);
+        let result = calculate(&web).unwrap();
+        let column = column::calculate(&web.column_input()).unwrap();
+
+        assert_eq!(result.action, AxialAction::Compression);
+        assert_eq!(result.actual_ft_psi, 0.0);
+        assert_eq!(result.unity, column.axial_unity);
+        // Compression is checked on the gross section
+        let compression = result.compression.unwrap();
+        assert!((compression.actual_fc_psi - 3000.0 / 8.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_holes_through_whole_section_rejected() {
+        let web = test_web(1000.0).with_holes(0.75, 8);
+        assert!(calculate(&web).is_err());
+    }
+}
@@ -16,7 +16,7 @@
 //! ```rust
 //! use calc_core::calculations::beam::{BeamInput, calculate};
 //! use calc_core::materials::{Material, WoodSpecies, WoodGrade, WoodMaterial};
-//! use calc_core::loads::{EnhancedLoadCase, DiscreteLoad, LoadType, DesignMethod};
+//! use calc_core::loads::{CodeEdition, EnhancedLoadCase, DiscreteLoad, LoadType, DesignMethod};
 //! use calc_core::nds_factors::AdjustmentFactors;
 //!
 //! // Define beam input with multiple discrete loads
@@ -36,6 +36,7 @@
 //!     width_in: 1.5,  // 2x nominal
 //!     depth_in: 9.25, // 10 nominal
 //!     adjustment_factors: AdjustmentFactors::default(),
+//!     code_edition: CodeEdition::default(),
 //! };
 //!
 //! let result = calculate(&input, DesignMethod::Asd).unwrap();
@@ -49,11 +50,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{CalcError, CalcResult};
-use crate::loads::{DesignMethod, EnhancedLoadCase, LoadDistribution, LoadType};
+use crate::loads::{CodeEdition, DesignMethod, EnhancedLoadCase, LoadType};
 use crate::materials::Material;
 use crate::nds_factors::{AdjustmentFactors, AdjustmentSummary, BeamStability, SizeFactor};
 
 use super::beam_analysis::{BeamAnalysis, SingleLoad};
+use super::continuous_beam::{ContinuousBeamInput, GoverningCondition};
 
 /// Input parameters for a simply-supported beam.
 ///
@@ -137,11 +139,11 @@ pub struct BeamInput {
     /// Defaults to normal duration, dry service, normal temperature.
     #[serde(default)]
     pub adjustment_factors: AdjustmentFactors,
-}
 
-/// Typical wood density for self-weight calculation (pcf)
-/// Using 35 pcf as a reasonable average for softwood lumber
-const WOOD_DENSITY_PCF: f64 = 35.0;
+    /// ASCE 7 edition the load combinations follow
+    #[serde(default)]
+    pub code_edition: CodeEdition,
+}
 
 impl BeamInput {
     /// Validate input parameters.
@@ -194,16 +196,17 @@ impl BeamInput {
 
     /// Calculate beam self-weight in pounds per linear foot (plf)
     ///
-    /// Uses typical softwood density of 35 pcf.
+    /// Uses the material density from its specific gravity.
     /// Area (in²) * density (pcf) / 144 (in²/ft²) = plf
     pub fn self_weight_plf(&self) -> f64 {
         let area_in2 = self.area_in2();
-        area_in2 * WOOD_DENSITY_PCF / 144.0
+        area_in2 * self.material.density_pcf() / 144.0
     }
 
     /// Get governing factored uniform load in plf for design
     ///
-    /// Applies ASCE 7 load combinations to all uniform loads in the load case.
+    /// Applies the ASCE 7 load combinations of `code_edition` to all uniform
+    /// loads in the load case.
     /// Optionally includes beam self-weight as additional dead load.
     ///
     /// Note: Point loads and partial loads are not included in this simplified
@@ -211,7 +214,7 @@ impl BeamInput {
     /// use the full analysis methods.
     pub fn governing_uniform_plf(&self, method: DesignMethod) -> f64 {
         // Get the governing factored load from the load case
-        let mut governing = self.load_case.governing_uniform_plf(method);
+        let mut governing = self.load_case.governing_uniform_plf(method, self.code_edition);
 
         // Add self-weight if enabled (as unfactored dead load, then apply factor)
         if self.load_case.include_self_weight {
@@ -246,6 +249,26 @@ impl BeamInput {
     }
 }
 
+/// Convert to a single pinned-pinned span of the continuous beam solver.
+///
+/// Load case, material, section, adjustment factors and code edition carry
+/// over as-is, so `calculate_continuous` on the result reproduces [`calculate`].
+impl From<BeamInput> for ContinuousBeamInput {
+    fn from(beam: BeamInput) -> Self {
+        let mut input = ContinuousBeamInput::simple_span(
+            beam.label,
+            beam.span_ft,
+            beam.width_in,
+            beam.depth_in,
+            beam.material,
+            beam.load_case,
+        );
+        input.adjustment_factors = beam.adjustment_factors;
+        input.code_edition = beam.code_edition;
+        input
+    }
+}
+
 /// Results from beam calculation.
 ///
 /// All results include both raw values and unity checks for easy pass/fail determination.
@@ -404,14 +427,8 @@ impl BeamResult {
     }
 
     /// Get a description of what governs the design
-    pub fn governing_condition(&self) -> &'static str {
-        if self.bending_unity >= self.shear_unity && self.bending_unity >= self.deflection_unity {
-            "Bending"
-        } else if self.shear_unity >= self.deflection_unity {
-            "Shear"
-        } else {
-            "Deflection"
-        }
+    pub fn governing_condition(&self) -> GoverningCondition {
+        GoverningCondition::from_unities(self.bending_unity, self.shear_unity, self.deflection_unity)
     }
 }
 
@@ -434,7 +451,7 @@ impl BeamResult {
 /// ```rust
 /// use calc_core::calculations::beam::{BeamInput, calculate};
 /// use calc_core::materials::{Material, WoodSpecies, WoodGrade, WoodMaterial};
-/// use calc_core::loads::{EnhancedLoadCase, DiscreteLoad, LoadType, DesignMethod};
+/// use calc_core::loads::{CodeEdition, EnhancedLoadCase, DiscreteLoad, LoadType, DesignMethod};
 /// use calc_core::nds_factors::AdjustmentFactors;
 ///
 /// let load_case = EnhancedLoadCase::new("Floor")
@@ -452,6 +469,7 @@ impl BeamResult {
 ///     width_in: 1.5,
 ///     depth_in: 9.25,
 ///     adjustment_factors: AdjustmentFactors::default(),
+///     code_edition: CodeEdition::default(),
 /// };
 ///
 /// let result = calculate(&input, DesignMethod::Asd).expect("Calculation should succeed");
@@ -489,35 +507,14 @@ pub fn calculate(input: &BeamInput, method: DesignMethod) -> CalcResult<BeamResu
 
     // Convert each DiscreteLoad to SingleLoad
     for discrete_load in &input.load_case.loads {
-        let magnitude = discrete_load.effective_magnitude();
-        let load_type = discrete_load.load_type;
-
-        let single = match &discrete_load.distribution {
-            LoadDistribution::Point { position_ft } => {
-                SingleLoad::point(magnitude, *position_ft)
-            }
-            LoadDistribution::UniformFull => {
-                SingleLoad::uniform_full(magnitude)
-            }
-            LoadDistribution::UniformPartial { start_ft, end_ft } => {
-                SingleLoad::uniform_partial(magnitude, *start_ft, *end_ft)
-            }
-            LoadDistribution::Moment { position_ft } => {
-                SingleLoad::moment(magnitude, *position_ft)
-            }
-            LoadDistribution::Trapezoidal { start_ft, end_ft, start_magnitude, end_magnitude } => {
-                // Approximate trapezoidal as partial uniform with average magnitude
-                let avg_mag = (start_magnitude + end_magnitude) / 2.0;
-                SingleLoad::uniform_partial(avg_mag, *start_ft, *end_ft)
-            }
-        };
-
-        loads_by_type.push((load_type, single));
+        if let Some(single) = SingleLoad::from_discrete(discrete_load) {
+            loads_by_type.push((discrete_load.load_type, single));
+        }
     }
 
     // === Run Analysis for Each Load Combination ===
     // Track both max (for strength design) and min (for uplift/anchor design)
-    let combinations = method.combinations();
+    let combinations = method.combinations(input.code_edition);
     let mut governing_moment = 0.0f64;
     let mut governing_combo_name = String::new();
     let mut governing_analysis: Option<super::beam_analysis::AnalysisResults> = None;
@@ -541,24 +538,10 @@ pub fn calculate(input: &BeamInput, method: DesignMethod) -> CalcResult<BeamResu
                 continue; // Skip loads with zero factor
             }
 
-            // Create factored copy of the load
-            let factored_load = match single_load {
-                SingleLoad::Point { magnitude_lb, position_ft } => {
-                    SingleLoad::point(magnitude_lb * factor, *position_ft)
-                }
-                SingleLoad::UniformFull { magnitude_plf } => {
-                    total_factored_plf += magnitude_plf * factor;
-                    SingleLoad::uniform_full(magnitude_plf * factor)
-                }
-                SingleLoad::UniformPartial { magnitude_plf, start_ft, end_ft } => {
-                    SingleLoad::uniform_partial(magnitude_plf * factor, *start_ft, *end_ft)
-                }
-                SingleLoad::Moment { magnitude_ftlb, position_ft } => {
-                    SingleLoad::moment(magnitude_ftlb * factor, *position_ft)
-                }
-            };
-
-            analysis.add_load(factored_load);
+            if let SingleLoad::UniformFull { magnitude_plf } = single_load {
+                total_factored_plf += magnitude_plf * factor;
+            }
+            analysis.add_load(single_load.scaled(factor));
         }
 
         // Skip empty analyses
@@ -746,6 +729,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         }
     }
 
@@ -818,6 +802,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
         assert!(result.passes());
@@ -840,6 +825,7 @@ mod tests {
             width_in: 5.125,
             depth_in: 16.5,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
         // Should have higher allowable Fb than sawn lumber
@@ -860,6 +846,7 @@ mod tests {
             width_in: 1.75,
             depth_in: 11.875,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
         // LVL should have higher E than sawn lumber
@@ -904,8 +891,9 @@ mod tests {
         let beam = test_beam();
         let self_wt = beam.self_weight_plf();
         // 1.5" x 9.25" = 13.875 in²
-        // 13.875 * 35 pcf / 144 = 3.37 plf
-        assert!((self_wt - 3.37).abs() < 0.1);
+        // DF-L (G = 0.50) at 19% moisture: 34.2 pcf
+        // 13.875 * 34.2 pcf / 144 = 3.30 plf
+        assert!((self_wt - 3.30).abs() < 0.01);
     }
 
     #[test]
@@ -929,6 +917,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let beam_with_sw = BeamInput {
@@ -942,6 +931,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result_no_sw = calculate(&beam_no_sw, DesignMethod::Asd).unwrap();
@@ -963,6 +953,47 @@ mod tests {
         assert!((result_lrfd.design_load_plf - 220.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_converts_to_continuous_beam() {
+        use crate::calculations::continuous_beam::{calculate_continuous, SupportType};
+
+        let beam = test_beam();
+        let simple = calculate(&beam, DesignMethod::Asd).unwrap();
+        let input = ContinuousBeamInput::from(beam.clone());
+        assert_eq!(input.label, beam.label);
+        assert_eq!(input.supports, vec![SupportType::Pinned, SupportType::Pinned]);
+        assert_eq!(input.spans[0].length_ft, beam.span_ft);
+
+        let continuous = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+        let close = |a: f64, b: f64| (a - b).abs() <= 0.01 * b.abs();
+        assert!(close(continuous.max_positive_moment_ftlb, simple.max_moment_ftlb));
+        assert!(close(continuous.max_shear_lb, simple.max_shear_lb));
+        assert!(close(continuous.max_deflection_in, simple.max_deflection_in));
+        let span = &continuous.span_results[0];
+        assert!(close(span.bending_unity, simple.bending_unity));
+        assert!(close(span.shear_unity, simple.shear_unity));
+        assert!(simple.governing_combination.starts_with(&continuous.governing_combination));
+
+        // Snow-governed 7-16 beam: the edition must survive the conversion
+        // for 1.0S (ASD) and 1.6S (LRFD) to carry over
+        let mut beam = test_beam();
+        beam.code_edition = CodeEdition::Asce7_16;
+        beam.load_case = EnhancedLoadCase::new("Roof")
+            .without_self_weight()
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 30.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Snow, 150.0));
+        let input = ContinuousBeamInput::from(beam.clone());
+        assert_eq!(input.code_edition, CodeEdition::Asce7_16);
+        for method in [DesignMethod::Asd, DesignMethod::Lrfd] {
+            let simple = calculate(&beam, method).unwrap();
+            let continuous = calculate_continuous(&input, method).unwrap();
+            assert!(close(continuous.max_positive_moment_ftlb, simple.max_moment_ftlb));
+            assert!(close(continuous.max_shear_lb, simple.max_shear_lb));
+            assert!(close(continuous.span_results[0].bending_unity, simple.bending_unity));
+            assert!(simple.governing_combination.starts_with(&continuous.governing_combination));
+        }
+    }
+
     #[test]
     fn test_governing_combination_reported() {
         let beam = test_beam();
@@ -992,6 +1023,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
@@ -1032,6 +1064,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
@@ -1080,6 +1113,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
@@ -1139,6 +1173,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
@@ -1197,6 +1232,7 @@ mod tests {
             width_in: 1.5,
             depth_in: 9.25,
             adjustment_factors: AdjustmentFactors::default(),
+            code_edition: CodeEdition::default(),
         };
 
         let result = calculate(&beam, DesignMethod::Asd).unwrap();
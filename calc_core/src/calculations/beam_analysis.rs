@@ -36,6 +36,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::equations::beam::{partial_load_segments, PARTIAL_LOAD_SEGMENTS};
+use crate::loads::{DiscreteLoad, LoadDistribution};
+
 /// A single load applied to the beam
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SingleLoad {
@@ -91,6 +94,46 @@ impl SingleLoad {
         }
     }
 
+    /// Convert a load case load, with its effective magnitude
+    ///
+    /// Trapezoidal loads are approximated as a partial uniform load of their
+    /// average magnitude. Horizontal loads cause no bending and give `None`.
+    pub fn from_discrete(load: &DiscreteLoad) -> Option<Self> {
+        let magnitude = load.effective_magnitude();
+        Some(match &load.distribution {
+            LoadDistribution::Point { position_ft } => SingleLoad::point(magnitude, *position_ft),
+            LoadDistribution::UniformFull => SingleLoad::uniform_full(magnitude),
+            LoadDistribution::UniformPartial { start_ft, end_ft } => {
+                SingleLoad::uniform_partial(magnitude, *start_ft, *end_ft)
+            }
+            LoadDistribution::Moment { position_ft } => SingleLoad::moment(magnitude, *position_ft),
+            LoadDistribution::Trapezoidal {
+                start_ft,
+                end_ft,
+                start_magnitude,
+                end_magnitude,
+            } => SingleLoad::uniform_partial((start_magnitude + end_magnitude) / 2.0, *start_ft, *end_ft),
+            LoadDistribution::HorizontalPoint { .. } => return None,
+        })
+    }
+
+    /// Copy of this load with its magnitude multiplied by `factor`
+    pub fn scaled(&self, factor: f64) -> Self {
+        match self {
+            SingleLoad::Point { magnitude_lb, position_ft } => SingleLoad::point(magnitude_lb * factor, *position_ft),
+            SingleLoad::UniformFull { magnitude_plf } => SingleLoad::uniform_full(magnitude_plf * factor),
+            SingleLoad::UniformPartial {
+                magnitude_plf,
+                start_ft,
+                end_ft,
+            } => SingleLoad::uniform_partial(magnitude_plf * factor, *start_ft, *end_ft),
+            SingleLoad::Moment {
+                magnitude_ftlb,
+                position_ft,
+            } => SingleLoad::moment(magnitude_ftlb * factor, *position_ft),
+        }
+    }
+
     /// Calculate left reaction (R1) for this load
     pub fn reaction_left(&self, span_ft: f64) -> f64 {
         match self {
@@ -355,20 +398,11 @@ impl SingleLoad {
         } = self
         {
             // Approximate by treating as multiple point loads
-            let num_segments = 20;
-            let segment_length = (end_ft - start_ft) / num_segments as f64;
-            let segment_load = magnitude_plf * segment_length; // lb per segment
-
-            let mut total_deflection = 0.0;
-            for i in 0..num_segments {
-                let pos = start_ft + (i as f64 + 0.5) * segment_length;
-                let point_load = SingleLoad::Point {
-                    magnitude_lb: segment_load,
-                    position_ft: pos,
-                };
-                total_deflection += point_load.deflection_at(x_ft, span_ft, e_psi, i_in4);
-            }
-            total_deflection
+            partial_load_segments(*magnitude_plf, *start_ft, *end_ft, PARTIAL_LOAD_SEGMENTS)
+                .map(|(magnitude_lb, position_ft)| {
+                    SingleLoad::Point { magnitude_lb, position_ft }.deflection_at(x_ft, span_ft, e_psi, i_in4)
+                })
+                .sum()
         } else {
             0.0
         }
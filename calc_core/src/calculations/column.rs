@@ -1,17 +1,19 @@
 //! # Column Calculation
 //!
-//! Analyzes axial compression members (columns) per NDS.
+//! Analyzes axial compression members (columns) per NDS, with optional
+//! bending about the depth axis checked by the NDS 3.9.2 interaction equation.
 //!
-//! ## Current Status: Placeholder
+//! ## Current Status
 //!
-//! This module provides the data structures for column calculations.
-//! Full calculation logic will be implemented in a future phase.
+//! The column stability factor Cp is still a simplified placeholder; full
+//! NDS 3.7.1 Cp will be implemented in a future phase.
 //!
-//! ## Assumptions (when implemented)
+//! ## Assumptions
 //!
-//! - Pin-pin boundary conditions (K = 1.0)
+//! - End conditions per axis via [`ColumnEndCondition`] (pin-pin by default);
+//!   the axis with the larger le/d governs Cp, see [`ColumnAxis`]
 //! - Sawn lumber rectangular section
-//! - Axial load only (combined loading to be added later)
+//! - Bending about the depth axis only, compression edge braced (C_L = 1.0)
 //!
 //! ## Example
 //!
@@ -27,6 +29,11 @@
 //!     width_in: 3.5,
 //!     depth_in: 3.5,
 //!     k_factor: 1.0,
+//!     end_condition_depth: None,
+//!     end_condition_width: None,
+//!     applied_moment_ftlb: None,
+//!     eccentricity_in: None,
+//!     load_duration: Default::default(),
 //! };
 //! ```
 
@@ -34,6 +41,76 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{CalcError, CalcResult};
 use crate::materials::WoodMaterial;
+use crate::nds_factors::{LoadDuration, SizeFactor};
+
+/// Column end restraint for buckling about one axis.
+///
+/// Recommended design Ke values per NDS Appendix G (Table G1), which
+/// exceed the theoretical values to account for imperfect fixity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ColumnEndCondition {
+    /// Both ends pinned (theoretical K = 1.0)
+    #[default]
+    PinnedPinned,
+    /// Base fixed, top free to translate and rotate (flagpole, theoretical K = 2.0)
+    FixedFree,
+    /// One end fixed, other pinned (theoretical K = 0.7)
+    FixedPinned,
+    /// Both ends fixed (theoretical K = 0.5)
+    FixedFixed,
+}
+
+impl ColumnEndCondition {
+    /// All end conditions, for UI selection
+    pub const ALL: [ColumnEndCondition; 4] = [
+        ColumnEndCondition::PinnedPinned,
+        ColumnEndCondition::FixedFree,
+        ColumnEndCondition::FixedPinned,
+        ColumnEndCondition::FixedFixed,
+    ];
+
+    /// Recommended design buckling length coefficient Ke
+    pub fn ke(&self) -> f64 {
+        match self {
+            ColumnEndCondition::PinnedPinned => 1.0,
+            ColumnEndCondition::FixedFree => 2.1,
+            ColumnEndCondition::FixedPinned => 0.8,
+            ColumnEndCondition::FixedFixed => 0.65,
+        }
+    }
+
+    /// Human-readable name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ColumnEndCondition::PinnedPinned => "Pinned-Pinned",
+            ColumnEndCondition::FixedFree => "Fixed-Free",
+            ColumnEndCondition::FixedPinned => "Fixed-Pinned",
+            ColumnEndCondition::FixedFixed => "Fixed-Fixed",
+        }
+    }
+}
+
+/// Direction a column buckles in, named for the section dimension it bends across
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ColumnAxis {
+    /// Buckling across the depth (le/d with d = `depth_in`)
+    #[default]
+    Depth,
+    /// Buckling across the width (le/d with d = `width_in`)
+    Width,
+}
+
+impl ColumnAxis {
+    /// Human-readable name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ColumnAxis::Depth => "Depth",
+            ColumnAxis::Width => "Width",
+        }
+    }
+}
 
 /// Input parameters for a wood column.
 ///
@@ -54,6 +131,7 @@ use crate::materials::WoodMaterial;
 /// }
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ColumnInput {
     /// User label for this column (e.g., "C-1", "Interior Column")
     pub label: String,
@@ -74,7 +152,31 @@ pub struct ColumnInput {
     pub depth_in: f64,
 
     /// Effective length factor K (typically 1.0 for pin-pin)
+    ///
+    /// Used for any axis without an explicit end condition.
     pub k_factor: f64,
+
+    /// End condition for buckling in the depth direction (Ke overrides `k_factor`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_condition_depth: Option<ColumnEndCondition>,
+
+    /// End condition for buckling in the width direction (Ke overrides `k_factor`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_condition_width: Option<ColumnEndCondition>,
+
+    /// Applied moment about the depth axis (ft-lb), e.g. from wind on a stud
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_moment_ftlb: Option<f64>,
+
+    /// Eccentricity of the axial load in the depth direction (in)
+    ///
+    /// Adds P·e to the applied moment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eccentricity_in: Option<f64>,
+
+    /// Load duration for C_D on Fc and Fb
+    #[serde(default)]
+    pub load_duration: LoadDuration,
 }
 
 impl ColumnInput {
@@ -135,10 +237,57 @@ impl ColumnInput {
         self.width_in.min(self.depth_in)
     }
 
-    /// Calculate slenderness ratio le/d
+    /// Buckling length coefficient Ke for `axis`
+    ///
+    /// The end condition's recommended value, or `k_factor` without one.
+    pub fn ke(&self, axis: ColumnAxis) -> f64 {
+        let end_condition = match axis {
+            ColumnAxis::Depth => self.end_condition_depth,
+            ColumnAxis::Width => self.end_condition_width,
+        };
+        end_condition.map_or(self.k_factor, |c| c.ke())
+    }
+
+    /// Effective length le = Ke * L (in) for buckling in the depth direction
+    pub fn effective_length_depth_in(&self) -> f64 {
+        self.height_ft * 12.0 * self.ke(ColumnAxis::Depth)
+    }
+
+    /// Effective length le = Ke * L (in) for buckling in the width direction
+    pub fn effective_length_width_in(&self) -> f64 {
+        self.height_ft * 12.0 * self.ke(ColumnAxis::Width)
+    }
+
+    /// Section modulus about the depth axis S = bd²/6 (in³)
+    pub fn section_modulus_in3(&self) -> f64 {
+        self.width_in * self.depth_in * self.depth_in / 6.0
+    }
+
+    /// Total moment about the depth axis (in-lb): applied moment plus P·e
+    pub fn moment_inlb(&self) -> f64 {
+        self.applied_moment_ftlb.unwrap_or(0.0) * 12.0 + self.axial_load_lb * self.eccentricity_in.unwrap_or(0.0)
+    }
+
+    /// Slenderness ratio le/d for buckling about `axis`
+    pub fn slenderness_ratio_about(&self, axis: ColumnAxis) -> f64 {
+        match axis {
+            ColumnAxis::Depth => self.effective_length_depth_in() / self.depth_in,
+            ColumnAxis::Width => self.effective_length_width_in() / self.width_in,
+        }
+    }
+
+    /// Axis with the larger slenderness ratio (depth on a tie)
+    pub fn governing_axis(&self) -> ColumnAxis {
+        if self.slenderness_ratio_about(ColumnAxis::Width) > self.slenderness_ratio_about(ColumnAxis::Depth) {
+            ColumnAxis::Width
+        } else {
+            ColumnAxis::Depth
+        }
+    }
+
+    /// Calculate governing slenderness ratio le/d (larger of the two axes)
     pub fn slenderness_ratio(&self) -> f64 {
-        let le_in = self.height_ft * 12.0 * self.k_factor;
-        le_in / self.min_dimension_in()
+        self.slenderness_ratio_about(self.governing_axis())
     }
 }
 
@@ -151,6 +300,10 @@ impl ColumnInput {
 ///   "actual_fc_psi": 408.2,
 ///   "allowable_fc_psi": 1350.0,
 ///   "axial_unity": 0.30,
+///   "actual_fb_psi": 0.0,
+///   "allowable_fb_psi": 1350.0,
+///   "bending_unity": 0.0,
+///   "combined_unity": 0.09,
 ///   "slenderness_ratio": 34.3,
 ///   "cp_factor": 0.85
 /// }
@@ -166,9 +319,37 @@ pub struct ColumnResult {
     /// Axial unity check: actual_fc / allowable_fc
     pub axial_unity: f64,
 
+    /// Bending stress fb1 = M/S about the depth axis (psi)
+    #[serde(default)]
+    pub actual_fb_psi: f64,
+
+    /// Allowable bending stress Fb1' = Fb × C_D × C_F (psi)
+    #[serde(default)]
+    pub allowable_fb_psi: f64,
+
+    /// Bending unity check: actual_fb / allowable_fb
+    #[serde(default)]
+    pub bending_unity: f64,
+
+    /// Euler buckling stress about the depth axis FcE1 = 0.822 Emin / (le1/d1)² (psi)
+    #[serde(default)]
+    pub fce1_psi: f64,
+
+    /// NDS Eq. 3.9-3: (fc/Fc')² + fb1 / [Fb1' (1 - fc/FcE1)]
+    #[serde(default)]
+    pub combined_unity: f64,
+
     /// Slenderness ratio le/d
     pub slenderness_ratio: f64,
 
+    /// Axis whose slenderness ratio governs Cp
+    #[serde(default)]
+    pub governing_axis: ColumnAxis,
+
+    /// Buckling length coefficient Ke about the governing axis
+    #[serde(default)]
+    pub ke: f64,
+
     /// Column stability factor Cp (NDS 3.7)
     pub cp_factor: f64,
 
@@ -180,16 +361,16 @@ pub struct ColumnResult {
 }
 
 impl ColumnResult {
-    /// Check if the column passes (unity ≤ 1.0)
+    /// Check if the column passes (axial and combined unity ≤ 1.0)
     pub fn passes(&self) -> bool {
-        self.axial_unity <= 1.0
+        self.axial_unity <= 1.0 && self.combined_unity <= 1.0
     }
 }
 
 /// Calculate column capacity.
 ///
-/// **Note**: This is a simplified placeholder implementation.
-/// Full NDS column design with Cp factor calculation will be added later.
+/// Axial compression with optional bending about the depth axis, combined
+/// per NDS 3.9.2. The Cp factor is still a simplified placeholder.
 ///
 /// # Arguments
 ///
@@ -198,13 +379,15 @@ impl ColumnResult {
 /// # Returns
 ///
 /// * `Ok(ColumnResult)` - Calculation results
-/// * `Err(CalcError)` - If inputs are invalid
+/// * `Err(CalcError)` - If inputs are invalid, or if a column with bending
+///   reaches FcE1, where NDS Eq. 3.9-3 is undefined
 pub fn calculate(input: &ColumnInput) -> CalcResult<ColumnResult> {
     input.validate()?;
 
     let props = input.material.properties();
     let area = input.area_in2();
-    let slenderness = input.slenderness_ratio();
+    let governing_axis = input.governing_axis();
+    let slenderness = input.slenderness_ratio_about(governing_axis);
 
     // Actual compression stress
     let actual_fc_psi = input.axial_load_lb / area;
@@ -220,16 +403,46 @@ pub fn calculate(input: &ColumnInput) -> CalcResult<ColumnResult> {
     };
 
     // Allowable stress with Cp factor applied
-    let allowable_fc_psi = props.fc_psi * cp_factor;
+    let c_d = input.load_duration.factor();
+    let allowable_fc_psi = props.fc_psi * c_d * cp_factor;
 
     // Unity check
     let axial_unity = actual_fc_psi / allowable_fc_psi;
 
+    // Bending about the depth axis (NDS 3.9.2)
+    let actual_fb_psi = input.moment_inlb().abs() / input.section_modulus_in3();
+    let allowable_fb_psi = props.fb_psi * c_d * SizeFactor::new(input.depth_in, input.width_in).factor_fb();
+    let bending_unity = actual_fb_psi / allowable_fb_psi;
+
+    let slenderness_depth = input.effective_length_depth_in() / input.depth_in;
+    let fce1_psi = 0.822 * props.e_min_psi / (slenderness_depth * slenderness_depth);
+    let amplification = 1.0 - actual_fc_psi / fce1_psi;
+    let combined_unity = if bending_unity == 0.0 {
+        axial_unity * axial_unity
+    } else if amplification <= 0.0 {
+        return Err(CalcError::calculation_failed(
+            "Column",
+            format!(
+                "fc = {:.0} psi exceeds FcE1 = {:.0} psi; NDS 3.9.2 requires fc < FcE1 under bending",
+                actual_fc_psi, fce1_psi
+            ),
+        ));
+    } else {
+        axial_unity * axial_unity + bending_unity / amplification
+    };
+
     Ok(ColumnResult {
         actual_fc_psi,
         allowable_fc_psi,
         axial_unity,
+        actual_fb_psi,
+        allowable_fb_psi,
+        bending_unity,
+        fce1_psi,
+        combined_unity,
         slenderness_ratio: slenderness,
+        governing_axis,
+        ke: input.ke(governing_axis),
         cp_factor,
         fc_reference_psi: props.fc_psi,
         area_in2: area,
@@ -250,6 +463,11 @@ mod tests {
             width_in: 3.5,
             depth_in: 3.5,
             k_factor: 1.0,
+            end_condition_depth: None,
+            end_condition_width: None,
+            applied_moment_ftlb: None,
+            eccentricity_in: None,
+            load_duration: LoadDuration::Normal,
         }
     }
 
@@ -294,4 +512,107 @@ mod tests {
         assert_eq!(col.height_ft, roundtrip.height_ft);
         assert_eq!(col.axial_load_lb, roundtrip.axial_load_lb);
     }
+
+    #[test]
+    fn test_fixed_free_effective_length() {
+        let mut col = test_column();
+        col.end_condition_depth = Some(ColumnEndCondition::FixedFree);
+        col.end_condition_width = Some(ColumnEndCondition::FixedFree);
+
+        // le = 2.1 * 120 in = 252 in
+        assert!((col.effective_length_depth_in() - 2.1 * 120.0).abs() < 1e-9);
+        assert!((col.effective_length_width_in() - 2.1 * 120.0).abs() < 1e-9);
+        assert!((col.slenderness_ratio() - 252.0 / 3.5).abs() < 1e-9);
+
+        // Bracing the weak direction only leaves the strong axis governing
+        col.width_in = 1.5;
+        col.depth_in = 5.5;
+        col.end_condition_width = Some(ColumnEndCondition::FixedFixed);
+        let expected = (252.0 / 5.5_f64).max(0.65 * 120.0 / 1.5);
+        assert!((col.slenderness_ratio() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fixed_free_capacity() {
+        // 4x4 at 6.9 ft: le/d = 23.7 pin-pin, 49.7 fixed-free (Ke = 2.1),
+        // both inside the simplified Cp's range
+        let mut col = test_column();
+        col.height_ft = 6.9;
+        let pinned = calculate(&col).unwrap();
+        assert_eq!(pinned.governing_axis, ColumnAxis::Depth);
+        assert_eq!(pinned.ke, 1.0);
+
+        col.end_condition_depth = Some(ColumnEndCondition::FixedFree);
+        col.end_condition_width = Some(ColumnEndCondition::FixedFree);
+        let flagpole = calculate(&col).unwrap();
+        assert_eq!(flagpole.ke, 2.1);
+        let ratio = flagpole.allowable_fc_psi / pinned.allowable_fc_psi;
+        assert!((0.5..0.7).contains(&ratio), "{}", ratio);
+
+        // Fixed-free about the depth only: the depth axis governs
+        col.end_condition_width = None;
+        col.width_in = 5.5;
+        let result = calculate(&col).unwrap();
+        assert_eq!(result.governing_axis, ColumnAxis::Depth);
+        assert!((result.slenderness_ratio - 2.1 * 82.8 / 3.5).abs() < 1e-9);
+
+        // A 2x6 stud pinned both ways buckles across its 1.5 in width
+        let mut stud = test_column();
+        stud.width_in = 1.5;
+        stud.depth_in = 5.5;
+        let result = calculate(&stud).unwrap();
+        assert_eq!(result.governing_axis, ColumnAxis::Width);
+        assert!((result.slenderness_ratio - 120.0 / 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stud_with_wind_interaction() {
+        // 4x4 DF-L No.2, 10 ft pin-pin, 2000 lb axial, 40 plf wind: M = wL²/8 = 500 ft-lb
+        let mut col = test_column();
+        col.axial_load_lb = 2000.0;
+        col.applied_moment_ftlb = Some(500.0);
+        col.load_duration = LoadDuration::WindSeismic;
+        let result = calculate(&col).unwrap();
+
+        // fb = 6000 / 7.146 = 839.7 psi; Fb' = 900 × 1.6 × 1.5 = 2160 psi
+        assert!((result.actual_fb_psi - 839.7).abs() < 0.1);
+        assert!((result.allowable_fb_psi - 2160.0).abs() < 1e-9);
+        // FcE1 = 0.822 × 580,000 / (120/3.5)² = 405.6 psi
+        assert!((result.fce1_psi - 405.6).abs() < 0.1);
+
+        let fc = 2000.0 / 12.25;
+        let expected = (fc / result.allowable_fc_psi).powi(2) + 839.65 / (2160.0 * (1.0 - fc / 405.58));
+        assert!((result.combined_unity - expected).abs() < 1e-3);
+        assert!(result.combined_unity > result.axial_unity);
+
+        // Just below FcE1 the amplified bending term blows up
+        let fce1_load = result.fce1_psi * 12.25;
+        col.axial_load_lb = 0.99 * fce1_load;
+        let result = calculate(&col).unwrap();
+        assert!(result.combined_unity > 100.0 * result.bending_unity);
+        assert!(!result.passes());
+
+        // At and beyond FcE1 Eq. 3.9-3 is undefined
+        for load in [fce1_load, 1.5 * fce1_load] {
+            col.axial_load_lb = load;
+            let err = calculate(&col).unwrap_err();
+            assert!(err.to_string().contains("FcE1"), "{}", err);
+        }
+
+        // Without bending there is nothing to amplify
+        col.applied_moment_ftlb = None;
+        let result = calculate(&col).unwrap();
+        assert_eq!(result.combined_unity, result.axial_unity * result.axial_unity);
+    }
+
+    #[test]
+    fn test_eccentricity_adds_moment() {
+        let mut col = test_column();
+        col.axial_load_lb = 2000.0;
+        col.eccentricity_in = Some(1.0);
+        // M = P·e = 2000 in-lb
+        assert!((col.moment_inlb() - 2000.0).abs() < 1e-9);
+        let result = calculate(&col).unwrap();
+        assert!((result.actual_fb_psi - 2000.0 / col.section_modulus_in3()).abs() < 1e-9);
+    }
 }
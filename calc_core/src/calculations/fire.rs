@@ -0,0 +1,343 @@
+//! # Fire Design of Exposed Wood Members
+//!
+//! Checks a beam span for a required fire-resistance time per NDS Chapter 16:
+//! the exposed faces char at the nominal rate, the effective char depth
+//! (including the zero-strength layer) is removed from each exposed face, and
+//! the residual section is checked against design values raised to average
+//! ultimate strength (NDS Table 16.2.2).
+//!
+//! ## Assumptions
+//!
+//! - Nominal char rate β_n = 1.5 in/hr (NDS 16.2.1)
+//! - Demands come from an ambient analysis of the same span; the reduced
+//!   stiffness is not fed back into the continuous-beam solution
+//! - C_D, C_M, C_t, C_i and C_r do not apply; C_F and C_fu stay at their
+//!   ambient (gross section) values
+//! - Shear uses the bending strength factor; Table 16.2.2 gives no separate
+//!   value for shear
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::continuous_beam::{calculate_continuous, ContinuousBeamInput};
+//! use calc_core::calculations::fire::{check_fire, FireExposure};
+//! use calc_core::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+//! use calc_core::materials::{GlulamLayup, GlulamMaterial, GlulamStressClass, Material};
+//!
+//! let loads = EnhancedLoadCase::new("Floor")
+//!     .with_load(DiscreteLoad::uniform(LoadType::Dead, 150.0))
+//!     .with_load(DiscreteLoad::uniform(LoadType::Live, 300.0));
+//! let glulam = Material::Glulam(GlulamMaterial::new(GlulamStressClass::F24_V4, GlulamLayup::Unbalanced));
+//! let input = ContinuousBeamInput::simple_span("GL-1", 20.0, 5.125, 16.5, glulam, loads);
+//! let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+//!
+//! let fire = check_fire(
+//!     &input.spans[0],
+//!     &result.span_results[0],
+//!     &input.adjustment_factors,
+//!     FireExposure::ThreeSides,
+//!     1.0,
+//! )
+//! .unwrap();
+//! assert!(fire.passes());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CalcError, CalcResult};
+use crate::nds_factors::{AdjustmentFactors, BeamStability, SizeFactor};
+
+use super::continuous_beam::{SpanResult, SpanSegment};
+
+/// Nominal char rate β_n for a 1-hour exposure (in/hr, NDS 16.2.1)
+pub const NOMINAL_CHAR_RATE_IN_PER_HR: f64 = 1.5;
+
+/// Longest exposure covered by the NDS char model (hours)
+const MAX_DURATION_HR: f64 = 2.0;
+
+/// Bending (and tension) design value to average ultimate strength (NDS Table 16.2.2)
+const BENDING_STRENGTH_FACTOR: f64 = 2.85;
+
+/// Beam buckling design value to average ultimate strength (NDS Table 16.2.2)
+const BEAM_BUCKLING_FACTOR: f64 = 2.03;
+
+/// Faces of a beam exposed to fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FireExposure {
+    /// Both sides and the bottom; the top is protected by the supported deck
+    #[default]
+    ThreeSides,
+    /// All four faces exposed
+    FourSides,
+}
+
+impl FireExposure {
+    /// All exposure options
+    pub const ALL: [FireExposure; 2] = [FireExposure::ThreeSides, FireExposure::FourSides];
+
+    /// Human-readable name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FireExposure::ThreeSides => "3-Sided",
+            FireExposure::FourSides => "4-Sided",
+        }
+    }
+
+    /// Number of charred faces across the depth (top and/or bottom)
+    pub fn depth_faces(&self) -> f64 {
+        match self {
+            FireExposure::ThreeSides => 1.0,
+            FireExposure::FourSides => 2.0,
+        }
+    }
+}
+
+/// Nominal char depth a_char = β_n·t^0.813 (in, NDS Eq. 16.2-1)
+pub fn char_depth_in(duration_hr: f64) -> f64 {
+    NOMINAL_CHAR_RATE_IN_PER_HR * duration_hr.powf(0.813)
+}
+
+/// Effective char depth a_eff = 1.2·a_char (in, NDS Eq. 16.2-1)
+///
+/// The extra 20% is the heated zero-strength layer beneath the char.
+pub fn effective_char_depth_in(duration_hr: f64) -> f64 {
+    1.2 * char_depth_in(duration_hr)
+}
+
+/// Fire-resistance check of a single span
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FireResult {
+    /// Faces exposed to fire
+    pub exposure: FireExposure,
+    /// Required fire-resistance time (hours)
+    pub duration_hr: f64,
+    /// Nominal char depth per exposed face (in)
+    pub char_depth_in: f64,
+    /// Effective char depth per exposed face, including the zero-strength layer (in)
+    pub effective_char_depth_in: f64,
+    /// Residual section width (in)
+    pub width_in: f64,
+    /// Residual section depth (in)
+    pub depth_in: f64,
+    /// Bending stress on the residual section (psi)
+    pub actual_fb_psi: f64,
+    /// Average ultimate bending strength, 2.85·Fb·C_F·C_fu·C_L (psi)
+    pub allowable_fb_psi: f64,
+    /// Beam stability factor for the residual section
+    pub c_l: f64,
+    /// Shear stress on the residual section (psi)
+    pub actual_fv_psi: f64,
+    /// Average ultimate shear strength, 2.85·Fv (psi)
+    pub allowable_fv_psi: f64,
+    /// Bending unity ratio
+    ///
+    /// `None` when the residual section has no bending strength left.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bending_unity: Option<f64>,
+    /// Shear unity ratio
+    pub shear_unity: f64,
+}
+
+impl FireResult {
+    /// Check if bending and shear both pass (unity ≤ 1.0)
+    pub fn passes(&self) -> bool {
+        self.governing_unity().is_some_and(|unity| unity <= 1.0)
+    }
+
+    /// Get the governing (highest) unity ratio, `None` with no bending strength left
+    pub fn governing_unity(&self) -> Option<f64> {
+        self.bending_unity.map(|bending| bending.max(self.shear_unity))
+    }
+}
+
+/// Check a span for `duration_hr` hours of fire exposure
+///
+/// `demand` is the span's ambient result; its design moment and peak shear
+/// are carried onto the residual section. Bracing is taken from `factors`.
+pub fn check_fire(
+    span: &SpanSegment,
+    demand: &SpanResult,
+    factors: &AdjustmentFactors,
+    exposure: FireExposure,
+    duration_hr: f64,
+) -> CalcResult<FireResult> {
+    if !(duration_hr > 0.0 && duration_hr <= MAX_DURATION_HR) {
+        return Err(CalcError::invalid_input(
+            "duration_hr",
+            duration_hr.to_string(),
+            "Fire-resistance time must be greater than 0 and at most 2 hours",
+        ));
+    }
+
+    let a_char = char_depth_in(duration_hr);
+    let a_eff = effective_char_depth_in(duration_hr);
+    let width_in = span.total_width_in() - 2.0 * a_eff;
+    let depth_in = span.depth_in - exposure.depth_faces() * a_eff;
+    if width_in <= 0.0 || depth_in <= 0.0 {
+        return Err(CalcError::invalid_input(
+            "duration_hr",
+            duration_hr.to_string(),
+            format!(
+                "Effective char depth of {:.2} in consumes the {:.3}x{:.3} section",
+                a_eff, span.total_width_in(), span.depth_in
+            ),
+        ));
+    }
+
+    let design_moment_inlb = demand.actual_fb_psi * span.section_modulus_in3();
+    let actual_fb = design_moment_inlb / (width_in * depth_in.powi(2) / 6.0);
+    let actual_fv = 3.0 * demand.max_shear_lb / (2.0 * width_in * depth_in);
+
+    let c_f = if !span.material.is_engineered() {
+        SizeFactor::new(span.depth_in, span.width_in).factor_fb()
+    } else {
+        1.0
+    };
+    let fb_star = BENDING_STRENGTH_FACTOR
+        * span.material.fb_for_depth(span.depth_in)
+        * c_f
+        * factors.c_fu(span.width_in);
+
+    let c_l = if factors.compression_edge_braced {
+        1.0
+    } else {
+        let le = match demand.unbraced_segment {
+            Some(segment) => segment.length_ft() * 12.0,
+            None => factors.unbraced_length_in.unwrap_or(span.length_ft * 12.0),
+        };
+        let stability = BeamStability::new(le, width_in, depth_in);
+        if stability.is_fully_braced() {
+            1.0
+        } else {
+            stability.factor(fb_star, BEAM_BUCKLING_FACTOR * span.e_min_psi())
+        }
+    };
+
+    let allowable_fb = fb_star * c_l;
+    let allowable_fv = BENDING_STRENGTH_FACTOR * span.material.base_properties().fv_psi;
+
+    Ok(FireResult {
+        exposure,
+        duration_hr,
+        char_depth_in: a_char,
+        effective_char_depth_in: a_eff,
+        width_in,
+        depth_in,
+        actual_fb_psi: actual_fb,
+        allowable_fb_psi: allowable_fb,
+        c_l,
+        actual_fv_psi: actual_fv,
+        allowable_fv_psi: allowable_fv,
+        bending_unity: (allowable_fb > 0.0).then(|| actual_fb / allowable_fb),
+        shear_unity: actual_fv / allowable_fv,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::continuous_beam::{calculate_continuous, ContinuousBeamInput};
+    use crate::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+    use crate::materials::{GlulamLayup, GlulamMaterial, GlulamStressClass, Material};
+
+    fn glulam_beam() -> ContinuousBeamInput {
+        let loads = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 150.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 300.0));
+        let glulam = Material::Glulam(GlulamMaterial::new(
+            GlulamStressClass::F24_V4,
+            GlulamLayup::Unbalanced,
+        ));
+        ContinuousBeamInput::simple_span("GL-1", 20.0, 5.125, 16.5, glulam, loads)
+    }
+
+    #[test]
+    fn test_glulam_one_hour() {
+        let input = glulam_beam();
+        let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+        let span = &input.spans[0];
+        let demand = &result.span_results[0];
+
+        let fire = check_fire(
+            span,
+            demand,
+            &input.adjustment_factors,
+            FireExposure::ThreeSides,
+            1.0,
+        )
+        .unwrap();
+
+        // NDS Table 16.2.1A: 1.5 in char, 1.8 in effective at 1 hour
+        assert!((fire.char_depth_in - 1.5).abs() < 1e-9);
+        assert!((fire.effective_char_depth_in - 1.8).abs() < 1e-9);
+        assert!((fire.width_in - 1.525).abs() < 1e-9);
+        assert!((fire.depth_in - 14.7).abs() < 1e-9);
+
+        // Braced top edge: Fb_fire = 2.85 × 2400
+        let m_inlb = demand.actual_fb_psi * 5.125 * 16.5f64.powi(2) / 6.0;
+        let s_fire = 1.525 * 14.7f64.powi(2) / 6.0;
+        assert_eq!(fire.c_l, 1.0);
+        assert!((fire.allowable_fb_psi - 2.85 * 2400.0).abs() < 1e-6);
+        assert!((fire.bending_unity.unwrap() - m_inlb / s_fire / (2.85 * 2400.0)).abs() < 1e-9);
+
+        let fv = 1.5 * demand.max_shear_lb / (1.525 * 14.7);
+        assert!((fire.actual_fv_psi - fv).abs() < 1e-9);
+        assert!(fire.passes());
+
+        // Exposing the top as well removes more depth
+        let four = check_fire(
+            span,
+            demand,
+            &input.adjustment_factors,
+            FireExposure::FourSides,
+            1.0,
+        )
+        .unwrap();
+        assert!((four.depth_in - 12.9).abs() < 1e-9);
+        assert!(four.bending_unity.unwrap() > fire.bending_unity.unwrap());
+    }
+
+    #[test]
+    fn test_fire_result_roundtrip() {
+        let input = glulam_beam();
+        let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+        let mut fire = check_fire(
+            &input.spans[0],
+            &result.span_results[0],
+            &input.adjustment_factors,
+            FireExposure::ThreeSides,
+            1.0,
+        )
+        .unwrap();
+
+        let parsed: FireResult = serde_json::from_str(&serde_json::to_string(&fire).unwrap()).unwrap();
+        assert_eq!(parsed.bending_unity, fire.bending_unity);
+
+        // No bending strength left: no unity, and the check fails
+        fire.bending_unity = None;
+        let parsed: FireResult = serde_json::from_str(&serde_json::to_string(&fire).unwrap()).unwrap();
+        assert_eq!(parsed.bending_unity, None);
+        assert_eq!(parsed.governing_unity(), None);
+        assert!(!parsed.passes());
+    }
+
+    #[test]
+    fn test_char_consumes_section() {
+        let input = glulam_beam();
+        let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+        let check = |hours| {
+            check_fire(
+                &input.spans[0],
+                &result.span_results[0],
+                &input.adjustment_factors,
+                FireExposure::ThreeSides,
+                hours,
+            )
+        };
+
+        // 2 × 3.16 in of effective char exceeds the 5.125 in width
+        assert!(check(2.0).is_err());
+        assert!(check(0.0).is_err());
+        assert!(check(3.0).is_err());
+    }
+}
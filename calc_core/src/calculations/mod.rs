@@ -19,22 +19,47 @@
 //! - [`continuous_beam`] - Multi-span beam analysis with configurable supports
 //! - [`beam_analysis`] - Detailed beam analysis with superposition
 //! - [`column`] - Axial compression member analysis (wood)
+//! - [`axial_member`] - Tension or compression web and chord members (wood)
+//! - [`steel_beam`] - Simply-supported W-shape beams per AISC 360
+//! - [`spread_footing`] - Isolated concrete column footings per ACI 318
+//! - [`retaining_wall`] - Cantilever retaining wall stability and stem design
+//! - [`fire`] - Fire-resistance char check of exposed wood beams (NDS Ch. 16)
+//! - [`optimize`] - Back-calculations for member and material selection
+//! - [`moving_load`] - Moving point load envelopes and influence lines
 
+pub mod axial_member;
 pub mod beam;
 pub mod beam_analysis;
 pub mod column;
 pub mod continuous_beam;
+pub mod fire;
 pub mod moment_distribution;
+pub mod moving_load;
+pub mod optimize;
+pub mod retaining_wall;
+pub mod spread_footing;
+pub mod steel_beam;
 
 use serde::{Deserialize, Serialize};
 
+use crate::errors::CalcResult;
+use crate::loads::DesignMethod;
+
 // Re-export commonly used types
+pub use axial_member::{AxialAction, AxialMemberInput, AxialMemberResult};
 pub use beam::{BeamInput, BeamResult};
 pub use beam_analysis::{AnalysisResults, BeamAnalysis, SingleLoad};
-pub use column::{ColumnInput, ColumnResult};
+pub use column::{ColumnAxis, ColumnEndCondition, ColumnInput, ColumnResult};
+pub use fire::{FireExposure, FireResult};
+pub use retaining_wall::{RetainingWallInput, RetainingWallResult};
+pub use spread_footing::{FootingDirection, SpreadFootingInput, SpreadFootingResult};
+pub use steel_beam::{FlexuralLimitState, SteelBeamInput, SteelBeamResult};
 pub use continuous_beam::{
-    calculate_continuous, ContinuousBeamInput, ContinuousBeamResult, SpanResult, SpanSegment,
-    SupportType,
+    calculate_continuous, calculate_continuous_detailed, calculate_for_combination, quick_continuous_coefficients,
+    BearingResult, ContinuousBeamInput, ContinuousBeamResult, ContinuousCoefficients, ConvertedResult,
+    ConvertedSpanResult, DeflectionCheck, DeflectionCriteria, GoverningCondition, GoverningLoadCases, HoldDownForce, LoadContribution, LoadTypeReactions, ResultDetail,
+    SlopedLoadComponents, SpanResult, SpanSegment, SupportType, UnbracedSegment, VibrationResult,
+    CAMBER_INCREMENT_IN, GLULAM_CAMBER_MULTIPLIER,
 };
 
 /// Enum wrapper for all calculation types.
@@ -43,6 +68,7 @@ pub use continuous_beam::{
 /// while maintaining type safety and clean serialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum CalculationItem {
     /// Multi-span continuous beam calculation
     ///
@@ -52,6 +78,12 @@ pub enum CalculationItem {
     Beam(ContinuousBeamInput),
     /// Axial compression column calculation
     Column(ColumnInput),
+    /// Steel W-shape beam calculation (AISC 360)
+    SteelBeam(SteelBeamInput),
+    /// Isolated concrete spread footing calculation (ACI 318)
+    SpreadFooting(SpreadFootingInput),
+    /// Cantilever retaining wall calculation
+    RetainingWall(RetainingWallInput),
     // Future: ShearWall(ShearWallInput),
     // etc.
 }
@@ -62,6 +94,9 @@ impl CalculationItem {
         match self {
             CalculationItem::Beam(b) => &b.label,
             CalculationItem::Column(c) => &c.label,
+            CalculationItem::SteelBeam(b) => &b.label,
+            CalculationItem::SpreadFooting(f) => &f.label,
+            CalculationItem::RetainingWall(w) => &w.label,
         }
     }
 
@@ -70,6 +105,55 @@ impl CalculationItem {
         match self {
             CalculationItem::Beam(_) => "Beam",
             CalculationItem::Column(_) => "Column",
+            CalculationItem::SteelBeam(_) => "Steel Beam",
+            CalculationItem::SpreadFooting(_) => "Spread Footing",
+            CalculationItem::RetainingWall(_) => "Retaining Wall",
+        }
+    }
+
+    /// Run the calculation for this item
+    ///
+    /// The design method applies to beams; columns are checked with their
+    /// service axial load, and footings and walls use service loads for
+    /// soil checks and ACI strength design for concrete.
+    pub fn calculate(&self, method: DesignMethod) -> CalcResult<CalculationResult> {
+        match self {
+            CalculationItem::Beam(b) => calculate_continuous(b, method).map(|r| CalculationResult::Beam(Box::new(r))),
+            CalculationItem::Column(c) => column::calculate(c).map(CalculationResult::Column),
+            CalculationItem::SteelBeam(b) => steel_beam::calculate(b, method).map(CalculationResult::SteelBeam),
+            CalculationItem::SpreadFooting(f) => spread_footing::calculate(f).map(CalculationResult::SpreadFooting),
+            CalculationItem::RetainingWall(w) => retaining_wall::calculate(w).map(CalculationResult::RetainingWall),
+        }
+    }
+}
+
+/// Enum wrapper for the result of any calculation type.
+///
+/// Mirrors [`CalculationItem`] variant-for-variant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CalculationResult {
+    /// Continuous beam results
+    Beam(Box<ContinuousBeamResult>),
+    /// Column results
+    Column(ColumnResult),
+    /// Steel beam results
+    SteelBeam(SteelBeamResult),
+    /// Spread footing results
+    SpreadFooting(SpreadFootingResult),
+    /// Retaining wall results
+    RetainingWall(RetainingWallResult),
+}
+
+impl CalculationResult {
+    /// Check if the calculation passes all design checks
+    pub fn passes(&self) -> bool {
+        match self {
+            CalculationResult::Beam(r) => r.passes(),
+            CalculationResult::Column(r) => r.passes(),
+            CalculationResult::SteelBeam(r) => r.passes(),
+            CalculationResult::SpreadFooting(r) => r.passes(),
+            CalculationResult::RetainingWall(r) => r.passes(),
         }
     }
 }
@@ -17,7 +17,7 @@
 //! - "Moment Distribution" by Hardy Cross (1930)
 
 use crate::calculations::continuous_beam::{ContinuousBeamInput, SupportType};
-use crate::equations::beam::{fem_point_load, fem_uniform_full, fem_partial_uniform};
+use crate::equations::beam::{fem_applied_moment, fem_point_load, fem_trapezoidal, fem_uniform_full, fem_partial_uniform};
 use crate::loads::{LoadDistribution, LoadType};
 
 /// Maximum iterations for moment distribution
@@ -57,6 +57,10 @@ pub struct JointData {
     pub connected_spans: Vec<usize>,
     /// Whether this is the left end (index 0) of connected span
     pub is_left_end: Vec<bool>,
+    /// Share of unbalanced moment taken by a rotational spring support
+    pub spring_factor: f64,
+    /// Moment carried by the rotational spring (ft-lb)
+    pub spring_moment: f64,
 }
 
 /// Moment distribution solver for continuous beams
@@ -68,6 +72,8 @@ pub struct MomentDistribution {
     spans: Vec<SpanData>,
     /// Joint data (support conditions and distribution factors)
     joints: Vec<JointData>,
+    /// Whether the last solve balanced the joints by iteration
+    distributed: bool,
 }
 
 impl MomentDistribution {
@@ -112,10 +118,13 @@ impl MomentDistribution {
 
                 // Adjust stiffness for far-end condition
                 let far_end = input.supports[j - 1];
-                let k = if far_end == SupportType::Pinned || far_end == SupportType::Roller {
-                    spans[span_idx].k * 0.75 // 3EI/L for pinned far end
+                let far_exterior = j == 1;
+                let k = if far_exterior && matches!(far_end, SupportType::Pinned | SupportType::Roller) {
+                    spans[span_idx].k * 0.75 // 3EI/L for an exterior pinned far end
+                } else if far_end == SupportType::Free {
+                    0.0 // Overhang - statically determinate, takes no distributed moment
                 } else {
-                    spans[span_idx].k // 4EI/L for fixed/continuous far end
+                    spans[span_idx].k // 4EI/L for fixed or interior (continuous) far end
                 };
                 stiffnesses.push(k);
             }
@@ -128,28 +137,41 @@ impl MomentDistribution {
 
                 // Adjust stiffness for far-end condition
                 let far_end = input.supports[j + 1];
-                let k = if far_end == SupportType::Pinned || far_end == SupportType::Roller {
-                    spans[span_idx].k * 0.75 // 3EI/L for pinned far end
+                let far_exterior = j + 1 == n_joints - 1;
+                let k = if far_exterior && matches!(far_end, SupportType::Pinned | SupportType::Roller) {
+                    spans[span_idx].k * 0.75 // 3EI/L for an exterior pinned far end
+                } else if far_end == SupportType::Free {
+                    0.0 // Overhang - statically determinate, takes no distributed moment
                 } else {
-                    spans[span_idx].k // 4EI/L for fixed/continuous far end
+                    spans[span_idx].k // 4EI/L for fixed or interior (continuous) far end
                 };
                 stiffnesses.push(k);
             }
 
+            // A rotational spring k adds to the joint like a member of K = k/4,
+            // since member stiffness 4EI/L is tracked as EI/L
+            let spring_k = match support_type {
+                SupportType::Spring { rotational_stiffness } => rotational_stiffness / 4.0,
+                _ => 0.0,
+            };
+
             // Calculate distribution factors
-            let total_k: f64 = stiffnesses.iter().sum();
+            let total_k: f64 = stiffnesses.iter().sum::<f64>() + spring_k;
             let distribution_factors = if total_k > 0.0 && support_type != SupportType::Fixed {
                 stiffnesses.iter().map(|k| k / total_k).collect()
             } else {
                 // Fixed joint or no stiffness: no distribution (absorb all moment)
                 vec![0.0; stiffnesses.len()]
             };
+            let spring_factor = if total_k > 0.0 { spring_k / total_k } else { 0.0 };
 
             joints.push(JointData {
                 support_type,
                 distribution_factors,
                 connected_spans,
                 is_left_end,
+                spring_factor,
+                spring_moment: 0.0,
             });
         }
 
@@ -157,6 +179,7 @@ impl MomentDistribution {
             n_spans,
             spans,
             joints,
+            distributed: false,
         }
     }
 
@@ -170,6 +193,10 @@ impl MomentDistribution {
 
         let node_positions = input.node_positions();
 
+        // Resultant (total force, moment about left end) of the loads on each span,
+        // used for the statically determinate moment of free-end spans
+        let mut statics = vec![(0.0f64, 0.0f64); self.spans.len()];
+
         // Process each discrete load
         for load in &input.load_case.loads {
             // Get load factor for this type
@@ -192,9 +219,11 @@ impl MomentDistribution {
                         let (fem_a, fem_b) = fem_uniform_full(magnitude, span.length_ft);
                         span.fem_left += fem_a;
                         span.fem_right += fem_b;
+                        let force = magnitude * span.length_ft;
+                        statics[i].0 += force;
+                        statics[i].1 += force * span.length_ft / 2.0;
                         // Note: FEM signs are negative at left, positive at right
                         // for our convention (hogging = negative)
-                        let _ = i; // span index unused here but could be for partial
                     }
                 }
                 LoadDistribution::Point { position_ft } => {
@@ -208,6 +237,8 @@ impl MomentDistribution {
                             let (fem_a, fem_b) = fem_point_load(magnitude, local_pos, span.length_ft);
                             span.fem_left += fem_a;
                             span.fem_right += fem_b;
+                            statics[i].0 += magnitude;
+                            statics[i].1 += magnitude * local_pos;
                             break;
                         }
                     }
@@ -232,21 +263,51 @@ impl MomentDistribution {
                                 );
                                 span.fem_left += fem_a;
                                 span.fem_right += fem_b;
+                                let force = magnitude * (local_end - local_start);
+                                statics[i].0 += force;
+                                statics[i].1 += force * (local_start + local_end) / 2.0;
                             }
                         }
                     }
                 }
                 LoadDistribution::Moment { position_ft } => {
-                    // Applied moment - find which span and add directly
-                    // For now, treat as point moment (no FEM effect, add to final)
-                    // This is an approximation; full treatment would require
-                    // separate handling in the moment diagram
-                    let _ = position_ft;
-                    let _ = magnitude;
+                    for (i, span) in self.spans.iter_mut().enumerate() {
+                        let span_start = node_positions[i];
+                        let span_end = node_positions[i + 1];
+
+                        if *position_ft >= span_start && *position_ft <= span_end {
+                            let local_pos = position_ft - span_start;
+                            let (fem_a, fem_b) = fem_applied_moment(magnitude, local_pos, span.length_ft);
+                            span.fem_left += fem_a;
+                            span.fem_right += fem_b;
+                            statics[i].1 += magnitude;
+                            break;
+                        }
+                    }
+                }
+                LoadDistribution::Trapezoidal { start_ft, end_ft, .. } => {
+                    let (w1, w2) = load.effective_end_magnitudes();
+                    let at = |x: f64| factor * (w1 + (w2 - w1) * (x - start_ft) / (end_ft - start_ft));
+                    for (i, span) in self.spans.iter_mut().enumerate() {
+                        let span_start = node_positions[i];
+                        let a = start_ft.max(span_start);
+                        let b = end_ft.min(node_positions[i + 1]);
+
+                        if b > a {
+                            let (wa, wb) = (at(a), at(b));
+                            let (local_a, local_b) = (a - span_start, b - span_start);
+                            let (fem_a, fem_b) = fem_trapezoidal(wa, wb, local_a, local_b, span.length_ft);
+                            span.fem_left += fem_a;
+                            span.fem_right += fem_b;
+                            let c = local_b - local_a;
+                            let force = (wa + wb) * c / 2.0;
+                            statics[i].0 += force;
+                            statics[i].1 += force * local_a + c * c * (wa + 2.0 * wb) / 6.0;
+                        }
+                    }
                 }
-                LoadDistribution::Trapezoidal { .. } => {
-                    // Approximate as partial uniform with average magnitude
-                    // Already handled in beam_analysis conversion
+                LoadDistribution::HorizontalPoint { .. } => {
+                    // Axial only - no bending
                 }
             }
         }
@@ -261,10 +322,46 @@ impl MomentDistribution {
                 .unwrap_or(1.0);
 
             for (i, span_input) in input.spans.iter().enumerate() {
-                let sw = span_input.self_weight_plf() * dead_factor;
-                let (fem_a, fem_b) = fem_uniform_full(sw, self.spans[i].length_ft);
+                let l = self.spans[i].length_ft;
+                // Linearly varying with the depth of a tapered span
+                let (w1, w2) = (
+                    span_input.self_weight_at_plf(0.0) * dead_factor,
+                    span_input.self_weight_at_plf(l) * dead_factor,
+                );
+                let (fem_a, fem_b) = if span_input.is_tapered() {
+                    fem_trapezoidal(w1, w2, 0.0, l, l)
+                } else {
+                    fem_uniform_full(w1, l)
+                };
                 self.spans[i].fem_left += fem_a;
                 self.spans[i].fem_right += fem_b;
+                statics[i].0 += (w1 + w2) * l / 2.0;
+                statics[i].1 += l * l * (w1 + 2.0 * w2) / 6.0;
+            }
+        }
+
+        // Relative settlement Δ of the span ends rotates the chord clockwise
+        // by Δ/L, giving FEM = -6EIΔ/L² at both ends
+        for (i, span) in self.spans.iter_mut().enumerate() {
+            let delta_in = input.settlement_in(i + 1) - input.settlement_in(i);
+            let l_in = span.length_ft * 12.0;
+            let fem = -6.0 * span.ei * delta_in / (l_in * l_in) / 12.0;
+            span.fem_left += fem;
+            span.fem_right += fem;
+        }
+
+        // Spans with a free end are cantilevers: the moment at the supported
+        // end follows from statics rather than from fixed-end moments
+        for (i, span) in self.spans.iter_mut().enumerate() {
+            let (force, moment_about_left) = statics[i];
+            let left_free = input.supports[i] == SupportType::Free;
+            let right_free = input.supports[i + 1] == SupportType::Free;
+            if right_free && !left_free {
+                span.fem_left = -moment_about_left;
+                span.fem_right = 0.0;
+            } else if left_free && !right_free {
+                span.fem_left = 0.0;
+                span.fem_right = force * span.length_ft - moment_about_left;
             }
         }
     }
@@ -278,14 +375,23 @@ impl MomentDistribution {
             span.moment_left = span.fem_left;
             span.moment_right = span.fem_right;
         }
+        for joint in &mut self.joints {
+            joint.spring_moment = 0.0;
+        }
+        self.distributed = false;
 
         // Handle special cases first
         if self.n_spans == 0 {
             return true;
         }
 
-        // For single-span beams, handle special cases
-        if self.n_spans == 1 {
+        // For single-span beams, handle special cases; spring supports need
+        // the general iteration
+        let has_spring = self
+            .joints
+            .iter()
+            .any(|j| matches!(j.support_type, SupportType::Spring { .. }));
+        if self.n_spans == 1 && !has_spring {
             let left_support = self.joints[0].support_type;
             let right_support = self.joints[1].support_type;
 
@@ -330,7 +436,7 @@ impl MomentDistribution {
 
             // Case 5: Cantilever (Fixed-Free or Free-Fixed)
             if left_is_fixed && right_is_free {
-                // Free end has zero moment, fixed end keeps FEM
+                // Free end has zero moment, fixed end keeps the statical moment
                 self.spans[0].moment_right = 0.0;
                 return true;
             }
@@ -393,20 +499,21 @@ impl MomentDistribution {
         }
 
         // Multi-span moment distribution iteration
+        self.distributed = true;
         for _iteration in 0..MAX_ITERATIONS {
             let mut max_unbalance = 0.0f64;
 
-            // Process each interior joint
-            for j in 1..self.joints.len() - 1 {
+            // Process each joint; released exterior supports stay balanced
+            for j in 0..self.joints.len() {
                 let joint = &self.joints[j];
 
-                // Skip fixed joints (absorb all moment)
-                if joint.support_type == SupportType::Fixed {
+                // Skip fixed joints (absorb all moment) and free ends
+                if matches!(joint.support_type, SupportType::Fixed | SupportType::Free) {
                     continue;
                 }
 
                 // Calculate unbalanced moment at this joint
-                let mut unbalanced = 0.0;
+                let mut unbalanced = joint.spring_moment;
                 for (idx, &span_idx) in joint.connected_spans.iter().enumerate() {
                     let moment = if joint.is_left_end[idx] {
                         self.spans[span_idx].moment_left
@@ -423,6 +530,7 @@ impl MomentDistribution {
                 }
 
                 // Distribute the unbalanced moment
+                let spring_share = -unbalanced * joint.spring_factor;
                 for (idx, &span_idx) in joint.connected_spans.iter().enumerate() {
                     let df = joint.distribution_factors[idx];
                     let distributed = -unbalanced * df;
@@ -447,15 +555,15 @@ impl MomentDistribution {
                         SupportType::Free
                     };
 
+                    let far_exterior = far_joint == 0 || far_joint == self.joints.len() - 1;
                     let carryover = if far_support == SupportType::Fixed {
                         0.5 // Fixed far end - full carryover
-                    } else if matches!(
-                        far_support,
-                        SupportType::Pinned | SupportType::Roller | SupportType::Free
-                    ) {
+                    } else if far_support == SupportType::Free
+                        || (far_exterior && matches!(far_support, SupportType::Pinned | SupportType::Roller))
+                    {
                         0.0 // Pinned/Roller/Free far end - no carryover (can't resist moment)
                     } else {
-                        0.5 // Continuous - full carryover
+                        0.5 // Continuous over an interior support - full carryover
                     };
 
                     // Apply carryover
@@ -467,6 +575,7 @@ impl MomentDistribution {
                         }
                     }
                 }
+                self.joints[j].spring_moment += spring_share;
             }
 
             // Check convergence
@@ -537,14 +646,19 @@ impl MomentDistribution {
 /// Result of moment distribution analysis
 #[derive(Debug, Clone)]
 pub struct DistributionResult {
-    /// Moment at left end of each span (ft-lb)
+    /// Moment at left end of each span (ft-lb), clockwise on the member positive
     pub span_moments_left: Vec<f64>,
-    /// Moment at right end of each span (ft-lb)
+    /// Moment at right end of each span (ft-lb), clockwise on the member positive
+    ///
+    /// A hogging moment is negative at the left end and positive at the right.
     pub span_moments_right: Vec<f64>,
     /// Support moment at each node (ft-lb)
     pub support_moments: Vec<f64>,
     /// Whether solution converged
     pub converged: bool,
+    /// Whether the joints were balanced by distribution, as opposed to a
+    /// single span solved in closed form
+    pub distributed: bool,
 }
 
 /// Analyze a continuous beam using moment distribution
@@ -564,6 +678,7 @@ pub fn analyze_moment_distribution(
         span_moments_right: end_moments.iter().map(|(_, r)| *r).collect(),
         support_moments,
         converged,
+        distributed: solver.distributed,
     }
 }
 
@@ -638,6 +753,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_three_span_equal_uniform() {
+        // Interior supports carry moment over to each other: M = wL²/10 = 1000 ft-lb
+        let load_case = EnhancedLoadCase::new("Test")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 100.0))
+            .without_self_weight();
+
+        let input = ContinuousBeamInput {
+            label: "Three-span test".to_string(),
+            spans: vec![super::super::continuous_beam::SpanSegment::new(10.0, 1.5, 9.25, test_material()); 3],
+            supports: vec![
+                SupportType::Pinned,
+                SupportType::Roller,
+                SupportType::Roller,
+                SupportType::Roller,
+            ],
+            load_case,
+            ..Default::default()
+        };
+
+        let result = analyze_moment_distribution(&input, &[(LoadType::Dead, 1.0)]);
+
+        assert!(result.converged, "Should converge");
+        for node in [1, 2] {
+            let moment = result.support_moments[node].abs();
+            assert!(approx_eq(moment, 1000.0, 1.0), "Support {} moment = {} (expected 1000)", node, moment);
+        }
+    }
+
+    #[test]
+    fn test_spring_exterior_support_limits() {
+        // Two spans with a rotational spring at the left end, compared to
+        // the same beam pinned (k = 0) and fixed (k -> infinity) there
+        let load_case = EnhancedLoadCase::new("Test")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 100.0))
+            .without_self_weight();
+        let solve = |left: SupportType| {
+            let input = ContinuousBeamInput {
+                label: "Spring test".to_string(),
+                spans: vec![super::super::continuous_beam::SpanSegment::new(10.0, 1.5, 9.25, test_material()); 2],
+                supports: vec![left, SupportType::Roller, SupportType::Roller],
+                load_case: load_case.clone(),
+                ..Default::default()
+            };
+            analyze_moment_distribution(&input, &[(LoadType::Dead, 1.0)])
+        };
+
+        for (k, limit) in [(0.0, SupportType::Pinned), (1e15, SupportType::Fixed)] {
+            let spring = solve(SupportType::Spring { rotational_stiffness: k });
+            let expected = solve(limit);
+            assert!(spring.converged, "Should converge");
+            for node in 0..3 {
+                assert!(
+                    approx_eq(spring.support_moments[node], expected.support_moments[node], 1.0),
+                    "k = {}: support {} moment = {} (expected {})",
+                    k,
+                    node,
+                    spring.support_moments[node],
+                    expected.support_moments[node]
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_single_span_simply_supported() {
         // Single span simply-supported - should have zero end moments
@@ -767,6 +946,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_propped_cantilever_far_end_stiffness() {
+        let load_case = EnhancedLoadCase::new("Test")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 100.0))
+            .without_self_weight();
+        let span = super::super::continuous_beam::SpanSegment::new(10.0, 1.5, 9.25, test_material());
+        let load_factors = vec![(LoadType::Dead, 1.0)];
+
+        // Propped cantilever: M at the fixed end = wL²/8 = 1250 ft-lb
+        let input = ContinuousBeamInput {
+            label: "Propped".to_string(),
+            spans: vec![span.clone()],
+            supports: vec![SupportType::Fixed, SupportType::Roller],
+            load_case: load_case.clone(),
+            ..Default::default()
+        };
+        let result = analyze_moment_distribution(&input, &load_factors);
+        assert!(result.converged);
+        assert!(approx_eq(result.support_moments[0].abs(), 1250.0, 1.0), "{}", result.support_moments[0]);
+        assert!(approx_eq(result.support_moments[1], 0.0, 1e-6));
+
+        // Propped cantilever beside a fixed-pinned span. At B the pinned-far
+        // span takes 3EI/L against 4EI/L, so DF = 3/7 and 4/7:
+        //   unbalance wL²/8 - wL²/12 = 416.7, M_B = 833.3 + 4/7(416.7) = 1071.4
+        //   M_A = 833.3 - ½·4/7(416.7) = 714.3
+        let input = ContinuousBeamInput {
+            label: "Fixed-Roller-Pinned".to_string(),
+            spans: vec![span.clone(), span],
+            supports: vec![SupportType::Fixed, SupportType::Roller, SupportType::Pinned],
+            load_case,
+            ..Default::default()
+        };
+        let result = analyze_moment_distribution(&input, &load_factors);
+        assert!(result.converged);
+        assert!(approx_eq(result.support_moments[0].abs(), 5000.0 / 7.0, 1.0), "{}", result.support_moments[0]);
+        assert!(approx_eq(result.support_moments[1].abs(), 7500.0 / 7.0, 1.0), "{}", result.support_moments[1]);
+        assert!(approx_eq(result.support_moments[2], 0.0, 1e-6));
+    }
+
     #[test]
     fn test_two_span_free_left_exterior() {
         // Bug repro: Two-span beam with Free left exterior (cantilever + continuation)
@@ -0,0 +1,308 @@
+//! # Moving Load Envelopes
+//!
+//! Envelopes of moment and shear as a single point load (a crane or
+//! monorail wheel) travels across a continuous beam, and influence lines
+//! for a single response quantity.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::continuous_beam::ContinuousBeamInput;
+//! use calc_core::calculations::moving_load::moving_load_envelope;
+//! use calc_core::loads::EnhancedLoadCase;
+//! use calc_core::materials::Material;
+//!
+//! let input = ContinuousBeamInput::simple_span(
+//!     "MR-1",
+//!     20.0,
+//!     5.125,
+//!     18.0,
+//!     Material::default(),
+//!     EnhancedLoadCase::new("Crane"),
+//! );
+//!
+//! // 2000 lb wheel stepped every 0.5 ft
+//! let envelope = moving_load_envelope(&input, 2000.0, 0.5).unwrap();
+//! assert!((envelope.max_moment_ftlb - 2000.0 * 20.0 / 4.0).abs() < 1.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::continuous_beam::{calculate_for_combination, response_at, ContinuousBeamInput};
+use crate::errors::{CalcError, CalcResult};
+use crate::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadCombination, LoadType};
+
+/// Moment and shear envelopes for a moving point load
+///
+/// Envelope diagrams are `(position_ft, value)` pairs at the analysis
+/// stations of the beam, measured from its left end. Only the moving load
+/// acts; the loads in the input's load case are not included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovingLoadEnvelope {
+    /// Moving load magnitude (lb)
+    pub magnitude_lbs: f64,
+
+    /// Load positions analyzed, from the left end (ft)
+    pub load_positions_ft: Vec<f64>,
+
+    /// Largest moment at each station over all load positions (ft-lb)
+    pub moment_max: Vec<(f64, f64)>,
+
+    /// Smallest (most negative) moment at each station (ft-lb)
+    pub moment_min: Vec<(f64, f64)>,
+
+    /// Largest shear at each station (lb)
+    pub shear_max: Vec<(f64, f64)>,
+
+    /// Smallest (most negative) shear at each station (lb)
+    pub shear_min: Vec<(f64, f64)>,
+
+    /// Largest moment magnitude anywhere (ft-lb)
+    pub max_moment_ftlb: f64,
+
+    /// Station where the largest moment occurs (ft)
+    pub max_moment_position_ft: f64,
+
+    /// Load position producing the largest moment (ft)
+    pub max_moment_load_position_ft: f64,
+
+    /// Largest shear magnitude anywhere (lb)
+    pub max_shear_lb: f64,
+
+    /// Station where the largest shear occurs (ft)
+    pub max_shear_position_ft: f64,
+
+    /// Load position producing the largest shear (ft)
+    pub max_shear_load_position_ft: f64,
+}
+
+/// Sweep a point load across the beam and envelope the moment and shear
+///
+/// The load is placed every `step_ft` from the left end, plus at the right
+/// end, and each position is analyzed unfactored as live load. Support
+/// settlement and the input's own loads are left out, so the envelope is the
+/// response to the moving load alone.
+pub fn moving_load_envelope(
+    input: &ContinuousBeamInput,
+    magnitude_lbs: f64,
+    step_ft: f64,
+) -> CalcResult<MovingLoadEnvelope> {
+    let positions = load_positions(input, step_ft)?;
+    let combo = LoadCombination::new("L", "L").with_factor(LoadType::Live, 1.0);
+    let mut envelope = MovingLoadEnvelope {
+        magnitude_lbs,
+        load_positions_ft: positions.clone(),
+        moment_max: Vec::new(),
+        moment_min: Vec::new(),
+        shear_max: Vec::new(),
+        shear_min: Vec::new(),
+        max_moment_ftlb: 0.0,
+        max_moment_position_ft: 0.0,
+        max_moment_load_position_ft: 0.0,
+        max_shear_lb: 0.0,
+        max_shear_position_ft: 0.0,
+        max_shear_load_position_ft: 0.0,
+    };
+
+    for position_ft in positions {
+        let single = ContinuousBeamInput {
+            load_case: EnhancedLoadCase::new("Moving load")
+                .with_load(DiscreteLoad::point(LoadType::Live, magnitude_lbs, position_ft))
+                .without_self_weight(),
+            support_settlements_in: Vec::new(),
+            pattern_live_load: false,
+            weak_axis_load_case: None,
+            ..input.clone()
+        };
+        let result = calculate_for_combination(&single, &combo, DesignMethod::Asd)?;
+
+        // Shear steps under the load and at supports appear as repeated
+        // positions; each station takes every value at its position
+        let shear = &result.shear_diagram;
+        let shear_at = |x: f64| {
+            let start = shear.partition_point(|p| p.0 < x - 1e-9);
+            let end = shear.partition_point(|p| p.0 <= x + 1e-9);
+            shear[start..end].iter().map(|p| p.1)
+        };
+
+        if envelope.moment_max.is_empty() {
+            envelope.moment_max = result.moment_diagram.clone();
+            envelope.moment_min = result.moment_diagram.clone();
+            envelope.shear_max = result.moment_diagram.iter().map(|p| (p.0, f64::MIN)).collect();
+            envelope.shear_min = result.moment_diagram.iter().map(|p| (p.0, f64::MAX)).collect();
+        }
+
+        for (k, &(x, m)) in result.moment_diagram.iter().enumerate() {
+            envelope.moment_max[k].1 = envelope.moment_max[k].1.max(m);
+            envelope.moment_min[k].1 = envelope.moment_min[k].1.min(m);
+            if m.abs() > envelope.max_moment_ftlb {
+                envelope.max_moment_ftlb = m.abs();
+                envelope.max_moment_position_ft = x;
+                envelope.max_moment_load_position_ft = position_ft;
+            }
+
+            for v in shear_at(x) {
+                envelope.shear_max[k].1 = envelope.shear_max[k].1.max(v);
+                envelope.shear_min[k].1 = envelope.shear_min[k].1.min(v);
+                if v.abs() > envelope.max_shear_lb {
+                    envelope.max_shear_lb = v.abs();
+                    envelope.max_shear_position_ft = x;
+                    envelope.max_shear_load_position_ft = position_ft;
+                }
+            }
+        }
+    }
+
+    Ok(envelope)
+}
+
+/// Response quantity traced by an influence line
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum InfluenceQuantity {
+    /// Vertical reaction at a node (lb per lb), upward positive
+    Reaction { node: usize },
+    /// Moment at a position from the left end (ft-lb per lb), sagging positive
+    Moment { position_ft: f64 },
+    /// Shear at a position from the left end (lb per lb), just left of the position
+    Shear { position_ft: f64 },
+}
+
+/// Influence line of `quantity` as a unit load crosses the beam
+///
+/// Returns `(load_position_ft, ordinate)` with the unit load placed every
+/// `step_ft` from the left end, plus at the right end. Each position is a
+/// separate moment distribution of the unit load alone.
+pub fn influence_line(
+    input: &ContinuousBeamInput,
+    quantity: InfluenceQuantity,
+    step_ft: f64,
+) -> CalcResult<Vec<(f64, f64)>> {
+    input.validate()?;
+    if let InfluenceQuantity::Reaction { node } = quantity {
+        if node >= input.node_count() {
+            return Err(CalcError::invalid_input(
+                "node",
+                node.to_string(),
+                format!("Beam has {} nodes", input.node_count()),
+            ));
+        }
+    }
+
+    let load_factors = [(LoadType::Live, 1.0)];
+    load_positions(input, step_ft)?
+        .into_iter()
+        .map(|position_ft| {
+            let single = ContinuousBeamInput {
+                load_case: EnhancedLoadCase::new("Unit load")
+                    .with_load(DiscreteLoad::point(LoadType::Live, 1.0, position_ft))
+                    .without_self_weight(),
+                support_settlements_in: Vec::new(),
+                ..input.clone()
+            };
+            let at = match quantity {
+                InfluenceQuantity::Reaction { .. } => 0.0,
+                InfluenceQuantity::Moment { position_ft } | InfluenceQuantity::Shear { position_ft } => position_ft,
+            };
+            let (reactions, v, m) = response_at(&single, &load_factors, at);
+            let ordinate = match quantity {
+                InfluenceQuantity::Reaction { node } => reactions[node],
+                InfluenceQuantity::Moment { .. } => m,
+                InfluenceQuantity::Shear { .. } => v,
+            };
+            Ok((position_ft, ordinate))
+        })
+        .collect()
+}
+
+/// Load positions every `step_ft` from the left end, plus the right end
+fn load_positions(input: &ContinuousBeamInput, step_ft: f64) -> CalcResult<Vec<f64>> {
+    if step_ft <= 0.0 {
+        return Err(CalcError::invalid_input(
+            "step_ft",
+            step_ft.to_string(),
+            "Load step must be positive",
+        ));
+    }
+
+    let total_ft = input.total_length_ft();
+    let steps = (total_ft / step_ft + 1e-9).floor() as usize;
+    let mut positions: Vec<f64> = (0..=steps).map(|k| k as f64 * step_ft).collect();
+    if total_ft - positions[steps] > 1e-9 {
+        positions.push(total_ft);
+    }
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::continuous_beam::{SpanSegment, SupportType};
+    use crate::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
+
+    fn test_material() -> Material {
+        Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2))
+    }
+
+    #[test]
+    fn test_simple_span_moment_envelope_peaks_at_midspan() {
+        let (l, p) = (20.0, 1000.0);
+        let input =
+            ContinuousBeamInput::simple_span("MR-1", l, 3.5, 11.25, test_material(), EnhancedLoadCase::new("Crane"));
+        let envelope = moving_load_envelope(&input, p, 0.2).unwrap();
+
+        assert!((envelope.max_moment_ftlb - p * l / 4.0).abs() < 1e-6);
+        assert!((envelope.max_moment_position_ft - l / 2.0).abs() < 1e-9);
+        assert!((envelope.max_moment_load_position_ft - l / 2.0).abs() < 1e-9);
+
+        // Stations every 0.4 ft are all load positions, so the envelope
+        // there is the load at x: M = P x (L - x) / L
+        for &(x, m) in &envelope.moment_max {
+            assert!((m - p * x * (l - x) / l).abs() < 1e-6, "x = {}: {}", x, m);
+        }
+        assert!(envelope.moment_min.iter().all(|&(_, m)| m.abs() < 1e-6));
+
+        // Shear peaks at P with the load right at a support
+        assert!((envelope.max_shear_lb - p).abs() < 1e-6);
+        assert_eq!(envelope.load_positions_ft.len(), 101);
+    }
+
+    #[test]
+    fn test_two_span_envelope_has_negative_moment() {
+        let input = ContinuousBeamInput::new(
+            "MR-2",
+            vec![SpanSegment::new(12.0, 3.5, 11.25, test_material()); 2],
+            vec![SupportType::Pinned, SupportType::Roller, SupportType::Roller],
+            EnhancedLoadCase::new("Crane"),
+        );
+        let envelope = moving_load_envelope(&input, 1000.0, 0.5).unwrap();
+
+        // Hogging over the interior support with the load in either span
+        let support = envelope.moment_min.iter().find(|p| (p.0 - 12.0).abs() < 1e-9).unwrap();
+        assert!(support.1 < -100.0);
+        assert!(moving_load_envelope(&input, 1000.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_midspan_moment_influence_line_is_triangular() {
+        let l = 20.0;
+        let input =
+            ContinuousBeamInput::simple_span("B-1", l, 3.5, 11.25, test_material(), EnhancedLoadCase::new("Unit"));
+        let line = influence_line(&input, InfluenceQuantity::Moment { position_ft: l / 2.0 }, 0.5).unwrap();
+
+        assert_eq!(line.len(), 41);
+        for &(a, ordinate) in &line {
+            let expected = if a <= l / 2.0 { a / 2.0 } else { (l - a) / 2.0 };
+            assert!((ordinate - expected).abs() < 1e-9, "a = {}: {}", a, ordinate);
+        }
+        let peak = line.iter().fold(0.0f64, |m, p| m.max(p.1));
+        assert!((peak - l / 4.0).abs() < 1e-9);
+
+        // Left reaction falls linearly from 1 to 0
+        let reaction = influence_line(&input, InfluenceQuantity::Reaction { node: 0 }, 5.0).unwrap();
+        for &(a, r) in &reaction {
+            assert!((r - (1.0 - a / l)).abs() < 1e-9);
+        }
+        assert!(influence_line(&input, InfluenceQuantity::Reaction { node: 2 }, 5.0).is_err());
+    }
+}
@@ -0,0 +1,627 @@
+//! # Cantilever Retaining Wall Calculation
+//!
+//! Checks a reinforced concrete cantilever retaining wall per foot of wall
+//! length: overturning and sliding stability, soil bearing under the base
+//! (with the kern check), and stem flexure and shear at the top of the base
+//! per ACI 318-19.
+//!
+//! ## Assumptions
+//!
+//! - Level backfill with Rankine active pressure on a vertical plane through
+//!   the heel; wall friction is neglected
+//! - Surcharge acts as a uniform lateral pressure Ka·q; its weight over the
+//!   heel is not counted as resisting load
+//! - Soil over the toe and passive resistance in front of the wall are
+//!   neglected
+//! - Vertical back face on the stem; any taper is on the front face
+//! - Stem strength uses a lateral earth pressure load factor of 1.6
+//!   (ASCE 7 / ACI 318 load H), normal-weight concrete, λs = 1.0
+//! - Toe and heel slab reinforcement is not checked
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::retaining_wall::{calculate, RetainingWallInput};
+//!
+//! let input = RetainingWallInput::new("RW-1", 12.0, 12.0, 18.0, 2.5, 4.5)
+//!     .with_allowable_bearing(3000.0)
+//!     .with_rebar(5, 8.0);
+//!
+//! let result = calculate(&input).unwrap();
+//! assert!(result.passes());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CalcError, CalcResult};
+
+use super::spread_footing::{rebar_properties, required_steel_area, CONCRETE_UNIT_WEIGHT_PCF, PHI_SHEAR};
+
+/// Load factor on lateral earth pressure for strength design
+const EARTH_PRESSURE_LOAD_FACTOR: f64 = 1.6;
+
+fn default_soil_unit_weight_pcf() -> f64 {
+    120.0
+}
+
+fn default_active_coefficient() -> f64 {
+    1.0 / 3.0
+}
+
+fn default_friction_coefficient() -> f64 {
+    0.5
+}
+
+fn default_allowable_bearing_psf() -> f64 {
+    2000.0
+}
+
+fn default_min_fs() -> f64 {
+    1.5
+}
+
+fn default_fc_psi() -> f64 {
+    3000.0
+}
+
+fn default_fy_psi() -> f64 {
+    60_000.0
+}
+
+fn default_cover_in() -> f64 {
+    2.0
+}
+
+fn default_bar_size() -> u8 {
+    5
+}
+
+fn default_bar_spacing_in() -> f64 {
+    12.0
+}
+
+/// Input parameters for a cantilever retaining wall.
+///
+/// Heights and plan lengths are in feet, thicknesses in inches. The base
+/// width is toe + stem thickness at the base + heel.
+///
+/// ## JSON Example
+///
+/// ```json
+/// {
+///   "label": "RW-1",
+///   "height_ft": 12.0,
+///   "stem_top_in": 12.0,
+///   "stem_bottom_in": 12.0,
+///   "base_thickness_in": 18.0,
+///   "toe_ft": 2.5,
+///   "heel_ft": 4.5,
+///   "soil_unit_weight_pcf": 120.0,
+///   "active_coefficient": 0.333,
+///   "surcharge_psf": 0.0,
+///   "friction_coefficient": 0.5,
+///   "allowable_bearing_psf": 3000.0,
+///   "fc_psi": 3000.0,
+///   "fy_psi": 60000.0,
+///   "cover_in": 2.0,
+///   "bar_size": 5,
+///   "bar_spacing_in": 8.0
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetainingWallInput {
+    /// User label for this wall (e.g., "RW-1")
+    pub label: String,
+
+    /// Retained height from the bottom of the base to the top of the stem (ft)
+    pub height_ft: f64,
+
+    /// Stem thickness at the top (in)
+    pub stem_top_in: f64,
+
+    /// Stem thickness at the top of the base (in)
+    pub stem_bottom_in: f64,
+
+    /// Base slab thickness (in)
+    pub base_thickness_in: f64,
+
+    /// Base projection in front of the stem (ft)
+    pub toe_ft: f64,
+
+    /// Base projection behind the stem, under the backfill (ft)
+    pub heel_ft: f64,
+
+    /// Backfill unit weight γ (pcf)
+    #[serde(default = "default_soil_unit_weight_pcf")]
+    pub soil_unit_weight_pcf: f64,
+
+    /// Active earth pressure coefficient Ka
+    #[serde(default = "default_active_coefficient")]
+    pub active_coefficient: f64,
+
+    /// Uniform surcharge on the backfill (psf)
+    #[serde(default)]
+    pub surcharge_psf: f64,
+
+    /// Coefficient of friction between the base and the soil
+    #[serde(default = "default_friction_coefficient")]
+    pub friction_coefficient: f64,
+
+    /// Allowable soil bearing pressure (psf)
+    #[serde(default = "default_allowable_bearing_psf")]
+    pub allowable_bearing_psf: f64,
+
+    /// Required factor of safety against overturning
+    #[serde(default = "default_min_fs")]
+    pub min_overturning_fs: f64,
+
+    /// Required factor of safety against sliding
+    #[serde(default = "default_min_fs")]
+    pub min_sliding_fs: f64,
+
+    /// Specified concrete compressive strength f'c (psi)
+    #[serde(default = "default_fc_psi")]
+    pub fc_psi: f64,
+
+    /// Specified reinforcement yield strength fy (psi)
+    #[serde(default = "default_fy_psi")]
+    pub fy_psi: f64,
+
+    /// Clear cover to the stem bars on the soil face (in)
+    #[serde(default = "default_cover_in")]
+    pub cover_in: f64,
+
+    /// Bar number of the vertical stem bars on the soil face
+    #[serde(default = "default_bar_size")]
+    pub bar_size: u8,
+
+    /// Spacing of the vertical stem bars (in)
+    #[serde(default = "default_bar_spacing_in")]
+    pub bar_spacing_in: f64,
+}
+
+impl RetainingWallInput {
+    /// Create a wall with a prismatic stem, level backfill at 120 pcf with
+    /// Ka = 1/3, base friction 0.5, 2000 psf bearing, FS = 1.5, and #5 bars at
+    /// 12 in
+    pub fn new(
+        label: impl Into<String>,
+        height_ft: f64,
+        stem_thickness_in: f64,
+        base_thickness_in: f64,
+        toe_ft: f64,
+        heel_ft: f64,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            height_ft,
+            stem_top_in: stem_thickness_in,
+            stem_bottom_in: stem_thickness_in,
+            base_thickness_in,
+            toe_ft,
+            heel_ft,
+            soil_unit_weight_pcf: default_soil_unit_weight_pcf(),
+            active_coefficient: default_active_coefficient(),
+            surcharge_psf: 0.0,
+            friction_coefficient: default_friction_coefficient(),
+            allowable_bearing_psf: default_allowable_bearing_psf(),
+            min_overturning_fs: default_min_fs(),
+            min_sliding_fs: default_min_fs(),
+            fc_psi: default_fc_psi(),
+            fy_psi: default_fy_psi(),
+            cover_in: default_cover_in(),
+            bar_size: default_bar_size(),
+            bar_spacing_in: default_bar_spacing_in(),
+        }
+    }
+
+    /// Taper the stem to a thinner top (builder pattern)
+    pub fn with_stem_top(mut self, stem_top_in: f64) -> Self {
+        self.stem_top_in = stem_top_in;
+        self
+    }
+
+    /// Set the backfill unit weight and active coefficient (builder pattern)
+    pub fn with_soil(mut self, unit_weight_pcf: f64, active_coefficient: f64) -> Self {
+        self.soil_unit_weight_pcf = unit_weight_pcf;
+        self.active_coefficient = active_coefficient;
+        self
+    }
+
+    /// Set the backfill surcharge in psf (builder pattern)
+    pub fn with_surcharge(mut self, surcharge_psf: f64) -> Self {
+        self.surcharge_psf = surcharge_psf;
+        self
+    }
+
+    /// Set the base friction coefficient (builder pattern)
+    pub fn with_friction_coefficient(mut self, friction_coefficient: f64) -> Self {
+        self.friction_coefficient = friction_coefficient;
+        self
+    }
+
+    /// Set the allowable soil bearing pressure in psf (builder pattern)
+    pub fn with_allowable_bearing(mut self, allowable_bearing_psf: f64) -> Self {
+        self.allowable_bearing_psf = allowable_bearing_psf;
+        self
+    }
+
+    /// Set the stem bar size and spacing in inches (builder pattern)
+    pub fn with_rebar(mut self, bar_size: u8, bar_spacing_in: f64) -> Self {
+        self.bar_size = bar_size;
+        self.bar_spacing_in = bar_spacing_in;
+        self
+    }
+
+    /// Validate input parameters.
+    pub fn validate(&self) -> CalcResult<()> {
+        let positive = [
+            ("height_ft", self.height_ft),
+            ("stem_top_in", self.stem_top_in),
+            ("stem_bottom_in", self.stem_bottom_in),
+            ("base_thickness_in", self.base_thickness_in),
+            ("soil_unit_weight_pcf", self.soil_unit_weight_pcf),
+            ("active_coefficient", self.active_coefficient),
+            ("friction_coefficient", self.friction_coefficient),
+            ("allowable_bearing_psf", self.allowable_bearing_psf),
+            ("min_overturning_fs", self.min_overturning_fs),
+            ("min_sliding_fs", self.min_sliding_fs),
+            ("fc_psi", self.fc_psi),
+            ("fy_psi", self.fy_psi),
+            ("bar_spacing_in", self.bar_spacing_in),
+        ];
+        for (field, value) in positive {
+            if value <= 0.0 {
+                return Err(CalcError::invalid_input(field, value.to_string(), "Must be positive"));
+            }
+        }
+        if self.toe_ft < 0.0 || self.heel_ft < 0.0 || self.surcharge_psf < 0.0 {
+            return Err(CalcError::invalid_input(
+                "toe_ft",
+                format!("{} / {} / {}", self.toe_ft, self.heel_ft, self.surcharge_psf),
+                "Toe, heel, and surcharge cannot be negative",
+            ));
+        }
+        if self.stem_top_in > self.stem_bottom_in {
+            return Err(CalcError::invalid_input(
+                "stem_top_in",
+                self.stem_top_in.to_string(),
+                "Stem cannot be thicker at the top than at the base",
+            ));
+        }
+        if self.base_thickness_in >= self.height_ft * 12.0 {
+            return Err(CalcError::invalid_input(
+                "base_thickness_in",
+                self.base_thickness_in.to_string(),
+                "Base must be thinner than the wall height",
+            ));
+        }
+        if rebar_properties(self.bar_size).is_none() {
+            return Err(CalcError::invalid_input(
+                "bar_size",
+                self.bar_size.to_string(),
+                "Bar size must be #3 through #11",
+            ));
+        }
+        if self.cover_in < 0.0 || self.stem_effective_depth_in() <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "cover_in",
+                self.cover_in.to_string(),
+                "Cover leaves no effective depth in the stem",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Base width B = toe + stem + heel (ft)
+    pub fn base_width_ft(&self) -> f64 {
+        self.toe_ft + self.stem_bottom_in / 12.0 + self.heel_ft
+    }
+
+    /// Stem height above the base (ft)
+    pub fn stem_height_ft(&self) -> f64 {
+        self.height_ft - self.base_thickness_in / 12.0
+    }
+
+    /// Effective depth of the stem bars at the top of the base (in)
+    pub fn stem_effective_depth_in(&self) -> f64 {
+        let diameter = rebar_properties(self.bar_size).map_or(0.0, |(d, _)| d);
+        self.stem_bottom_in - self.cover_in - diameter / 2.0
+    }
+}
+
+/// Results of a retaining wall calculation, per foot of wall.
+///
+/// Stability and bearing use service loads; stem demands are factored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetainingWallResult {
+    /// Base width B (ft)
+    pub base_width_ft: f64,
+
+    // === Stability (service) ===
+    /// Total vertical load: stem, base, and soil over the heel (lb/ft)
+    pub vertical_load_lb: f64,
+
+    /// Moment of the vertical loads about the toe (ft-lb/ft)
+    pub resisting_moment_ftlb: f64,
+
+    /// Active soil thrust ½·Ka·γ·H² (lb/ft)
+    pub active_force_lb: f64,
+
+    /// Surcharge thrust Ka·q·H (lb/ft)
+    pub surcharge_force_lb: f64,
+
+    /// Moment of the lateral thrusts about the toe (ft-lb/ft)
+    pub overturning_moment_ftlb: f64,
+
+    /// Factor of safety against overturning, resisting / overturning
+    pub overturning_fs: f64,
+
+    /// Overturning unity: required FS / computed FS
+    pub overturning_unity: f64,
+
+    /// Base friction resistance μ·ΣV (lb/ft)
+    pub sliding_resistance_lb: f64,
+
+    /// Factor of safety against sliding, friction / lateral thrust
+    pub sliding_fs: f64,
+
+    /// Sliding unity: required FS / computed FS
+    pub sliding_unity: f64,
+
+    // === Soil Bearing (service) ===
+    /// Distance from the toe to the resultant (ft)
+    pub resultant_from_toe_ft: f64,
+
+    /// Eccentricity of the resultant from the base center, toward the toe (ft)
+    pub eccentricity_ft: f64,
+
+    /// Whether the resultant lies in the middle third (|e| ≤ B/6)
+    pub within_kern: bool,
+
+    /// Soil pressure under the toe (psf)
+    pub toe_pressure_psf: f64,
+
+    /// Soil pressure under the heel (psf); zero when the heel lifts off
+    pub heel_pressure_psf: f64,
+
+    /// Bearing unity: maximum pressure / allowable
+    pub bearing_unity: f64,
+
+    // === Stem at the Top of the Base (ACI 318 strength design) ===
+    /// Stem height above the base (ft)
+    pub stem_height_ft: f64,
+
+    /// Factored stem shear Vu (lb/ft)
+    pub stem_shear_lb: f64,
+
+    /// Factored stem moment Mu (ft-lb/ft)
+    pub stem_moment_ftlb: f64,
+
+    /// Effective depth of the stem bars (in)
+    pub stem_effective_depth_in: f64,
+
+    /// Design shear strength φVc = φ·2λ√f'c·b·d (lb/ft)
+    pub stem_shear_capacity_lb: f64,
+
+    /// Stem shear unity: Vu / φVc
+    pub stem_shear_unity: f64,
+
+    /// Steel area required for Mu, or the wall minimum if larger (in²/ft)
+    pub stem_as_required_in2: f64,
+
+    /// Steel area provided (in²/ft)
+    pub stem_as_provided_in2: f64,
+
+    /// Stem flexure unity: As required / As provided
+    pub stem_flexure_unity: f64,
+}
+
+impl RetainingWallResult {
+    /// Check if stability, bearing, and the stem all pass (unity ≤ 1.0)
+    pub fn passes(&self) -> bool {
+        self.governing_unity() <= 1.0
+    }
+
+    /// Get the governing (highest) unity ratio
+    pub fn governing_unity(&self) -> f64 {
+        self.overturning_unity
+            .max(self.sliding_unity)
+            .max(self.bearing_unity)
+            .max(self.stem_shear_unity)
+            .max(self.stem_flexure_unity)
+    }
+}
+
+/// Check stability, bearing, and stem strength of a cantilever retaining wall.
+///
+/// # Arguments
+///
+/// * `input` - Wall geometry, soil, and stem reinforcement
+///
+/// # Returns
+///
+/// * `Ok(RetainingWallResult)` - Calculation results, per foot of wall
+/// * `Err(CalcError)` - If inputs are invalid, the resultant falls off the
+///   base, or the stem is too thin for its moment
+pub fn calculate(input: &RetainingWallInput) -> CalcResult<RetainingWallResult> {
+    input.validate()?;
+
+    let base_width = input.base_width_ft();
+    let stem_height = input.stem_height_ft();
+    let t_top = input.stem_top_in / 12.0;
+    let t_bottom = input.stem_bottom_in / 12.0;
+    let taper = t_bottom - t_top;
+
+    // (weight lb/ft, arm from the toe ft)
+    let weights = [
+        (t_top * stem_height * CONCRETE_UNIT_WEIGHT_PCF, input.toe_ft + taper + t_top / 2.0),
+        (taper * stem_height / 2.0 * CONCRETE_UNIT_WEIGHT_PCF, input.toe_ft + 2.0 * taper / 3.0),
+        (base_width * input.base_thickness_in / 12.0 * CONCRETE_UNIT_WEIGHT_PCF, base_width / 2.0),
+        (
+            input.heel_ft * stem_height * input.soil_unit_weight_pcf,
+            base_width - input.heel_ft / 2.0,
+        ),
+    ];
+    let vertical_load_lb: f64 = weights.iter().map(|(w, _)| w).sum();
+    let resisting_moment_ftlb: f64 = weights.iter().map(|(w, x)| w * x).sum();
+
+    let ka = input.active_coefficient;
+    let h = input.height_ft;
+    let active_force_lb = 0.5 * ka * input.soil_unit_weight_pcf * h * h;
+    let surcharge_force_lb = ka * input.surcharge_psf * h;
+    let overturning_moment_ftlb = active_force_lb * h / 3.0 + surcharge_force_lb * h / 2.0;
+    let sliding_resistance_lb = input.friction_coefficient * vertical_load_lb;
+    let overturning_fs = resisting_moment_ftlb / overturning_moment_ftlb;
+    let sliding_fs = sliding_resistance_lb / (active_force_lb + surcharge_force_lb);
+
+    let resultant_from_toe_ft = (resisting_moment_ftlb - overturning_moment_ftlb) / vertical_load_lb;
+    if resultant_from_toe_ft <= 0.0 {
+        return Err(CalcError::calculation_failed(
+            "retaining_wall",
+            "Resultant falls outside the toe; the wall overturns",
+        ));
+    }
+    let eccentricity_ft = base_width / 2.0 - resultant_from_toe_ft;
+    let within_kern = eccentricity_ft.abs() <= base_width / 6.0;
+    let (toe_pressure_psf, heel_pressure_psf) = if within_kern {
+        let average = vertical_load_lb / base_width;
+        let variation = 6.0 * eccentricity_ft / base_width;
+        (average * (1.0 + variation), average * (1.0 - variation))
+    } else if eccentricity_ft > 0.0 {
+        // Triangular pressure over 3x̄ from the toe; the heel lifts off
+        (2.0 * vertical_load_lb / (3.0 * resultant_from_toe_ft), 0.0)
+    } else {
+        (0.0, 2.0 * vertical_load_lb / (3.0 * (base_width - resultant_from_toe_ft)))
+    };
+
+    // Stem cantilevers from the top of the base
+    let gamma_ka = ka * input.soil_unit_weight_pcf;
+    let q_ka = ka * input.surcharge_psf;
+    let stem_shear_lb =
+        EARTH_PRESSURE_LOAD_FACTOR * (gamma_ka * stem_height.powi(2) / 2.0 + q_ka * stem_height);
+    let stem_moment_ftlb =
+        EARTH_PRESSURE_LOAD_FACTOR * (gamma_ka * stem_height.powi(3) / 6.0 + q_ka * stem_height.powi(2) / 2.0);
+
+    let d = input.stem_effective_depth_in();
+    let b = 12.0;
+    let stem_shear_capacity_lb = PHI_SHEAR * 2.0 * input.fc_psi.sqrt() * b * d;
+    let as_flexure = required_steel_area(stem_moment_ftlb * 12.0, b, d, input.fc_psi, input.fy_psi).ok_or_else(|| {
+        CalcError::calculation_failed(
+            "retaining_wall",
+            format!("{:.0} in stem is too thin for Mu = {:.0} ft-lb/ft", input.stem_bottom_in, stem_moment_ftlb),
+        )
+    })?;
+    // ACI 318-19 Table 11.6.1: minimum vertical wall steel
+    let (_, bar_area) = rebar_properties(input.bar_size).unwrap_or_default();
+    let min_ratio = if input.bar_size <= 5 { 0.0012 } else { 0.0015 };
+    let stem_as_required_in2 = as_flexure.max(min_ratio * b * input.stem_bottom_in);
+    let stem_as_provided_in2 = bar_area * 12.0 / input.bar_spacing_in;
+
+    Ok(RetainingWallResult {
+        base_width_ft: base_width,
+        vertical_load_lb,
+        resisting_moment_ftlb,
+        active_force_lb,
+        surcharge_force_lb,
+        overturning_moment_ftlb,
+        overturning_fs,
+        overturning_unity: input.min_overturning_fs / overturning_fs,
+        sliding_resistance_lb,
+        sliding_fs,
+        sliding_unity: input.min_sliding_fs / sliding_fs,
+        resultant_from_toe_ft,
+        eccentricity_ft,
+        within_kern,
+        toe_pressure_psf,
+        heel_pressure_psf,
+        bearing_unity: toe_pressure_psf.max(heel_pressure_psf) / input.allowable_bearing_psf,
+        stem_height_ft: stem_height,
+        stem_shear_lb,
+        stem_moment_ftlb,
+        stem_effective_depth_in: d,
+        stem_shear_capacity_lb,
+        stem_shear_unity: stem_shear_lb / stem_shear_capacity_lb,
+        stem_as_required_in2,
+        stem_as_provided_in2,
+        stem_flexure_unity: stem_as_required_in2 / stem_as_provided_in2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 12 ft wall, 12 in stem, 18 in base, 2.5 ft toe, 4.5 ft heel (B = 8 ft)
+    fn example_wall() -> RetainingWallInput {
+        RetainingWallInput::new("RW-1", 12.0, 12.0, 18.0, 2.5, 4.5)
+            .with_allowable_bearing(3000.0)
+            .with_rebar(5, 8.0)
+    }
+
+    #[test]
+    fn test_overturning_fs_and_toe_bearing() {
+        let result = calculate(&example_wall()).unwrap();
+
+        // Stem  1.0 × 10.5 × 150 = 1575 lb at 3.0 ft  →  4725.0
+        // Base  8.0 × 1.5 × 150  = 1800 lb at 4.0 ft  →  7200.0
+        // Soil  4.5 × 10.5 × 120 = 5670 lb at 5.75 ft → 32602.5
+        assert!((result.vertical_load_lb - 9045.0).abs() < 1e-9);
+        assert!((result.resisting_moment_ftlb - 44_527.5).abs() < 1e-9);
+
+        // Pa = ½(1/3)(120)(12²) = 2880 lb at 4 ft
+        assert!((result.active_force_lb - 2880.0).abs() < 1e-9);
+        assert!((result.overturning_moment_ftlb - 11_520.0).abs() < 1e-9);
+        assert!((result.overturning_fs - 3.865).abs() < 1e-3);
+        assert!((result.sliding_fs - 1.570).abs() < 1e-3);
+
+        // x̄ = 33007.5 / 9045 = 3.649 ft, e = 0.351 ft < B/6 = 1.333 ft
+        assert!((result.eccentricity_ft - 0.3507).abs() < 1e-4);
+        assert!(result.within_kern);
+        // q = 9045/8 × (1 ± 6e/B)
+        assert!((result.toe_pressure_psf - 1428.0).abs() < 0.1);
+        assert!((result.heel_pressure_psf - 833.2).abs() < 0.1);
+
+        assert!(result.passes());
+    }
+
+    #[test]
+    fn test_surcharge_and_stem_demand() {
+        let input = example_wall().with_surcharge(250.0);
+        let result = calculate(&input).unwrap();
+
+        // Ka·q·H = (1/3)(250)(12) = 1000 lb at 6 ft
+        assert!((result.surcharge_force_lb - 1000.0).abs() < 1e-9);
+        assert!((result.overturning_moment_ftlb - (11_520.0 + 6000.0)).abs() < 1e-9);
+        assert!((result.sliding_fs - 0.5 * 9045.0 / 3880.0).abs() < 1e-12);
+        assert!(result.sliding_fs < 1.5);
+        assert!(!result.passes());
+
+        // 10.5 ft stem: Vu = 1.6(40 × 10.5²/2 + 83.3 × 10.5), Mu = 1.6(40 × 10.5³/6 + 83.3 × 10.5²/2)
+        let q_ka = 250.0 / 3.0;
+        assert!((result.stem_shear_lb - 1.6 * (2205.0 + q_ka * 10.5)).abs() < 1e-6);
+        assert!((result.stem_moment_ftlb - 1.6 * (7717.5 + q_ka * 55.125)).abs() < 1e-6);
+        // d = 12 − 2 − 0.3125; #5 at 8 in = 0.465 in²/ft
+        assert!((result.stem_effective_depth_in - 9.6875).abs() < 1e-12);
+        assert!((result.stem_as_provided_in2 - 0.465).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_resultant_outside_kern() {
+        // A short heel pulls the resultant toward the toe
+        let input = RetainingWallInput::new("RW-2", 12.0, 12.0, 18.0, 1.0, 2.0).with_allowable_bearing(3000.0);
+        let result = calculate(&input).unwrap();
+
+        assert!(!result.within_kern);
+        assert_eq!(result.heel_pressure_psf, 0.0);
+        let expected = 2.0 * result.vertical_load_lb / (3.0 * result.resultant_from_toe_ft);
+        assert!((result.toe_pressure_psf - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert!(calculate(&example_wall().with_stem_top(14.0)).is_err());
+        assert!(calculate(&example_wall().with_rebar(12, 8.0)).is_err());
+        assert!(calculate(&example_wall().with_soil(120.0, 0.0)).is_err());
+    }
+}
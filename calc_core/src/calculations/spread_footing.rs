@@ -0,0 +1,589 @@
+//! # Spread Footing Calculation
+//!
+//! Sizes and checks an isolated reinforced concrete spread footing under a
+//! single column: soil bearing at service loads, then one-way shear, two-way
+//! (punching) shear, and flexural reinforcement per ACI 318-19 at factored
+//! loads.
+//!
+//! ## Assumptions
+//!
+//! - Concentric axial column load, no moment (uniform soil pressure)
+//! - Footing self-weight (150 pcf) is included in service bearing and drops
+//!   out of the factored net pressure used for concrete design
+//! - Normal-weight concrete (λ = 1.0), size effect factor λs = 1.0
+//! - Same bar size and count in each direction, checked against the average
+//!   effective depth d = h − cover − db
+//! - Tension-controlled flexure (φ = 0.90); shear φ = 0.75
+//! - Interior column for punching (αs = 40)
+//! - Bar development and short-direction band placement are not checked
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::spread_footing::{calculate, SpreadFootingInput};
+//!
+//! let input = SpreadFootingInput::new("F-1", 60_000.0, 40_000.0, 3000.0, 6.5, 6.5, 18.0, 16.0)
+//!     .with_rebar(6, 8);
+//!
+//! let result = calculate(&input).unwrap();
+//! assert!(result.passes());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CalcError, CalcResult};
+
+/// Unit weight of normal-weight reinforced concrete (pcf)
+pub const CONCRETE_UNIT_WEIGHT_PCF: f64 = 150.0;
+
+/// Shear resistance factor φ (ACI 318-19 Table 21.2.1)
+pub(crate) const PHI_SHEAR: f64 = 0.75;
+
+/// Flexural resistance factor φ for tension-controlled sections
+const PHI_FLEXURE: f64 = 0.90;
+
+/// Minimum slab reinforcement ratio for Grade 60 bars (ACI 318-19 Table 24.4.3.2)
+const MIN_STEEL_RATIO: f64 = 0.0018;
+
+/// Standard ASTM A615 bar sizes: (bar number, nominal diameter in, area in²)
+const REBAR_SIZES: [(u8, f64, f64); 9] = [
+    (3, 0.375, 0.11),
+    (4, 0.500, 0.20),
+    (5, 0.625, 0.31),
+    (6, 0.750, 0.44),
+    (7, 0.875, 0.60),
+    (8, 1.000, 0.79),
+    (9, 1.128, 1.00),
+    (10, 1.270, 1.27),
+    (11, 1.410, 1.56),
+];
+
+/// Nominal diameter (in) and area (in²) of a standard bar, if the size exists
+pub fn rebar_properties(bar_size: u8) -> Option<(f64, f64)> {
+    REBAR_SIZES
+        .iter()
+        .find(|(size, _, _)| *size == bar_size)
+        .map(|&(_, diameter, area)| (diameter, area))
+}
+
+/// Tension steel area (in²) for a factored moment on a rectangular section
+///
+/// Solves Mu = φ·As·fy·(d − a/2) with a = As·fy / (0.85·f'c·b) and φ = 0.90.
+/// Returns `None` if the section is too shallow for any steel area to work.
+pub(crate) fn required_steel_area(mu_inlb: f64, b_in: f64, d_in: f64, fc_psi: f64, fy_psi: f64) -> Option<f64> {
+    let k = 0.85 * fc_psi * b_in;
+    let discriminant = d_in * d_in - 2.0 * mu_inlb / (PHI_FLEXURE * k);
+    (discriminant >= 0.0).then(|| k / fy_psi * (d_in - discriminant.sqrt()))
+}
+
+fn default_fc_psi() -> f64 {
+    3000.0
+}
+
+fn default_fy_psi() -> f64 {
+    60_000.0
+}
+
+fn default_cover_in() -> f64 {
+    3.0
+}
+
+fn default_bar_size() -> u8 {
+    5
+}
+
+/// Input parameters for an isolated spread footing.
+///
+/// Length runs along the column's length dimension; bars spanning the length
+/// are spread across the width and vice versa.
+///
+/// ## JSON Example
+///
+/// ```json
+/// {
+///   "label": "F-1",
+///   "dead_load_lb": 60000.0,
+///   "live_load_lb": 40000.0,
+///   "allowable_bearing_psf": 3000.0,
+///   "length_ft": 6.5,
+///   "width_ft": 6.5,
+///   "thickness_in": 18.0,
+///   "column_length_in": 16.0,
+///   "column_width_in": 16.0,
+///   "fc_psi": 3000.0,
+///   "fy_psi": 60000.0,
+///   "cover_in": 3.0,
+///   "bar_size": 6,
+///   "bar_count": 8
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadFootingInput {
+    /// User label for this footing (e.g., "F-1")
+    pub label: String,
+
+    /// Service dead load from the column (lb)
+    pub dead_load_lb: f64,
+
+    /// Service live load from the column (lb)
+    pub live_load_lb: f64,
+
+    /// Allowable gross soil bearing pressure (psf)
+    pub allowable_bearing_psf: f64,
+
+    /// Footing plan length (ft)
+    pub length_ft: f64,
+
+    /// Footing plan width (ft)
+    pub width_ft: f64,
+
+    /// Footing thickness h (in)
+    pub thickness_in: f64,
+
+    /// Column dimension parallel to the footing length (in)
+    pub column_length_in: f64,
+
+    /// Column dimension parallel to the footing width (in)
+    pub column_width_in: f64,
+
+    /// Specified concrete compressive strength f'c (psi)
+    #[serde(default = "default_fc_psi")]
+    pub fc_psi: f64,
+
+    /// Specified reinforcement yield strength fy (psi)
+    #[serde(default = "default_fy_psi")]
+    pub fy_psi: f64,
+
+    /// Clear cover to the bottom bars (in)
+    #[serde(default = "default_cover_in")]
+    pub cover_in: f64,
+
+    /// Bar number of the bottom mat (e.g., 5 for #5)
+    #[serde(default = "default_bar_size")]
+    pub bar_size: u8,
+
+    /// Number of bars in each direction
+    #[serde(default)]
+    pub bar_count: u32,
+}
+
+impl SpreadFootingInput {
+    /// Create a footing under a square column, with f'c = 3000 psi, Grade 60
+    /// bars, 3 in cover, and no reinforcement yet
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        label: impl Into<String>,
+        dead_load_lb: f64,
+        live_load_lb: f64,
+        allowable_bearing_psf: f64,
+        length_ft: f64,
+        width_ft: f64,
+        thickness_in: f64,
+        column_size_in: f64,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            dead_load_lb,
+            live_load_lb,
+            allowable_bearing_psf,
+            length_ft,
+            width_ft,
+            thickness_in,
+            column_length_in: column_size_in,
+            column_width_in: column_size_in,
+            fc_psi: default_fc_psi(),
+            fy_psi: default_fy_psi(),
+            cover_in: default_cover_in(),
+            bar_size: default_bar_size(),
+            bar_count: 0,
+        }
+    }
+
+    /// Set a rectangular column size in inches (builder pattern)
+    pub fn with_column(mut self, column_length_in: f64, column_width_in: f64) -> Self {
+        self.column_length_in = column_length_in;
+        self.column_width_in = column_width_in;
+        self
+    }
+
+    /// Set f'c in psi (builder pattern)
+    pub fn with_fc_psi(mut self, fc_psi: f64) -> Self {
+        self.fc_psi = fc_psi;
+        self
+    }
+
+    /// Set the bar size and the number of bars each way (builder pattern)
+    pub fn with_rebar(mut self, bar_size: u8, bar_count: u32) -> Self {
+        self.bar_size = bar_size;
+        self.bar_count = bar_count;
+        self
+    }
+
+    /// Validate input parameters.
+    pub fn validate(&self) -> CalcResult<()> {
+        let positive = [
+            ("allowable_bearing_psf", self.allowable_bearing_psf),
+            ("length_ft", self.length_ft),
+            ("width_ft", self.width_ft),
+            ("thickness_in", self.thickness_in),
+            ("column_length_in", self.column_length_in),
+            ("column_width_in", self.column_width_in),
+            ("fc_psi", self.fc_psi),
+            ("fy_psi", self.fy_psi),
+        ];
+        for (field, value) in positive {
+            if value <= 0.0 {
+                return Err(CalcError::invalid_input(field, value.to_string(), "Must be positive"));
+            }
+        }
+        if self.dead_load_lb < 0.0 || self.live_load_lb < 0.0 {
+            return Err(CalcError::invalid_input(
+                "dead_load_lb",
+                format!("{} / {}", self.dead_load_lb, self.live_load_lb),
+                "Column loads cannot be negative",
+            ));
+        }
+        if self.column_length_in >= self.length_ft * 12.0 || self.column_width_in >= self.width_ft * 12.0 {
+            return Err(CalcError::invalid_input(
+                "column_length_in",
+                format!("{} x {}", self.column_length_in, self.column_width_in),
+                "Column must be smaller than the footing",
+            ));
+        }
+        if rebar_properties(self.bar_size).is_none() {
+            return Err(CalcError::invalid_input(
+                "bar_size",
+                self.bar_size.to_string(),
+                "Bar size must be #3 through #11",
+            ));
+        }
+        if self.cover_in < 0.0 || self.effective_depth_in() <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "cover_in",
+                self.cover_in.to_string(),
+                format!("Cover plus a #{} bar leaves no effective depth", self.bar_size),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Plan area of the footing (ft²)
+    pub fn area_ft2(&self) -> f64 {
+        self.length_ft * self.width_ft
+    }
+
+    /// Footing self-weight (lb)
+    pub fn self_weight_lb(&self) -> f64 {
+        self.area_ft2() * self.thickness_in / 12.0 * CONCRETE_UNIT_WEIGHT_PCF
+    }
+
+    /// Average effective depth of the two bar layers, d = h − cover − db (in)
+    pub fn effective_depth_in(&self) -> f64 {
+        let diameter = rebar_properties(self.bar_size).map_or(0.0, |(d, _)| d);
+        self.thickness_in - self.cover_in - diameter
+    }
+}
+
+/// One-way shear and flexure for bars spanning one plan direction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FootingDirection {
+    /// Cantilever from the column face to the footing edge (ft)
+    pub cantilever_ft: f64,
+
+    /// Footing dimension resisting shear and bending across this direction (ft)
+    pub section_width_ft: f64,
+
+    /// Factored shear at d from the column face, Vu (lb)
+    pub shear_lb: f64,
+
+    /// Design one-way shear strength φVc = φ·2λ√f'c·b·d (lb)
+    pub shear_capacity_lb: f64,
+
+    /// One-way shear unity: Vu / φVc
+    pub shear_unity: f64,
+
+    /// Factored moment at the column face, Mu (ft-lb)
+    pub moment_ftlb: f64,
+
+    /// Steel area required for Mu, before the minimum (in²)
+    pub as_flexure_in2: f64,
+
+    /// Minimum steel area 0.0018·b·h (in²)
+    pub as_minimum_in2: f64,
+
+    /// Governing required steel area (in²)
+    pub as_required_in2: f64,
+
+    /// Steel area provided (in²)
+    pub as_provided_in2: f64,
+
+    /// Flexure unity: As required / As provided
+    pub flexure_unity: f64,
+}
+
+/// Results of a spread footing calculation.
+///
+/// Bearing is checked at service loads (D + L plus footing weight); shear and
+/// flexure use the factored net soil pressure.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpreadFootingResult {
+    // === Soil Bearing (service) ===
+    /// Footing self-weight (lb)
+    pub self_weight_lb: f64,
+
+    /// Service load on the soil, D + L + footing (lb)
+    pub service_load_lb: f64,
+
+    /// Gross soil bearing pressure (psf)
+    pub bearing_pressure_psf: f64,
+
+    /// Bearing unity: pressure / allowable
+    pub bearing_unity: f64,
+
+    /// Plan area needed for the service column load at this thickness (ft²)
+    pub required_area_ft2: f64,
+
+    // === Factored Loads (ACI 318 strength design) ===
+    /// Governing factored column load, max(1.4D, 1.2D + 1.6L) (lb)
+    pub factored_load_lb: f64,
+
+    /// Factored net soil pressure qu = Pu / A (psf)
+    pub factored_pressure_psf: f64,
+
+    /// Average effective depth d (in)
+    pub effective_depth_in: f64,
+
+    // === Two-Way (Punching) Shear, ACI 318-19 22.6 ===
+    /// Critical perimeter b0 at d/2 from the column faces (in)
+    pub punching_perimeter_in: f64,
+
+    /// Factored punching shear Vu outside the critical section (lb)
+    pub punching_shear_lb: f64,
+
+    /// Design two-way shear strength φVc (lb)
+    pub punching_capacity_lb: f64,
+
+    /// Punching shear unity: Vu / φVc
+    pub punching_shear_unity: f64,
+
+    // === One-Way Shear and Flexure ===
+    /// Bars spanning the footing length
+    pub along_length: FootingDirection,
+
+    /// Bars spanning the footing width
+    pub along_width: FootingDirection,
+}
+
+impl SpreadFootingResult {
+    /// Governing one-way shear unity of the two directions
+    pub fn one_way_shear_unity(&self) -> f64 {
+        self.along_length.shear_unity.max(self.along_width.shear_unity)
+    }
+
+    /// Governing flexure unity of the two directions
+    pub fn flexure_unity(&self) -> f64 {
+        self.along_length.flexure_unity.max(self.along_width.flexure_unity)
+    }
+
+    /// Check if bearing, shear, and flexure all pass (unity ≤ 1.0)
+    pub fn passes(&self) -> bool {
+        self.governing_unity() <= 1.0
+    }
+
+    /// Get the governing (highest) unity ratio
+    pub fn governing_unity(&self) -> f64 {
+        self.bearing_unity
+            .max(self.punching_shear_unity)
+            .max(self.one_way_shear_unity())
+            .max(self.flexure_unity())
+    }
+}
+
+/// One-way shear and flexure for bars spanning `span_ft`, spread over `width_ft`
+fn check_direction(
+    input: &SpreadFootingInput,
+    qu_psf: f64,
+    span_ft: f64,
+    width_ft: f64,
+    column_in: f64,
+) -> CalcResult<FootingDirection> {
+    let d = input.effective_depth_in();
+    let b = width_ft * 12.0;
+    let cantilever_ft = (span_ft - column_in / 12.0) / 2.0;
+
+    // ACI 318-19 22.5.5.1: Vc = 2λ√f'c·b·d, critical section at d from the face
+    let shear_lb = qu_psf * width_ft * (cantilever_ft - d / 12.0).max(0.0);
+    let shear_capacity_lb = PHI_SHEAR * 2.0 * input.fc_psi.sqrt() * b * d;
+
+    let moment_ftlb = qu_psf * width_ft * cantilever_ft.powi(2) / 2.0;
+    let as_flexure_in2 = required_steel_area(moment_ftlb * 12.0, b, d, input.fc_psi, input.fy_psi).ok_or_else(|| {
+        CalcError::calculation_failed(
+            "spread_footing",
+            format!("{:.0} in footing is too thin for Mu = {:.0} ft-lb", input.thickness_in, moment_ftlb),
+        )
+    })?;
+    let as_minimum_in2 = MIN_STEEL_RATIO * b * input.thickness_in;
+    let as_required_in2 = as_flexure_in2.max(as_minimum_in2);
+    let bar_area = rebar_properties(input.bar_size).map_or(0.0, |(_, area)| area);
+    let as_provided_in2 = bar_area * input.bar_count as f64;
+    let flexure_unity = if as_provided_in2 > 0.0 {
+        as_required_in2 / as_provided_in2
+    } else {
+        f64::MAX
+    };
+
+    Ok(FootingDirection {
+        cantilever_ft,
+        section_width_ft: width_ft,
+        shear_lb,
+        shear_capacity_lb,
+        shear_unity: shear_lb / shear_capacity_lb,
+        moment_ftlb,
+        as_flexure_in2,
+        as_minimum_in2,
+        as_required_in2,
+        as_provided_in2,
+        flexure_unity,
+    })
+}
+
+/// Check soil bearing, shear, and reinforcement of a spread footing.
+///
+/// # Arguments
+///
+/// * `input` - Footing geometry, column loads, materials, and rebar
+///
+/// # Returns
+///
+/// * `Ok(SpreadFootingResult)` - Calculation results
+/// * `Err(CalcError)` - If inputs are invalid or the footing is too thin to
+///   develop the factored moment
+pub fn calculate(input: &SpreadFootingInput) -> CalcResult<SpreadFootingResult> {
+    input.validate()?;
+
+    let area = input.area_ft2();
+    let self_weight_lb = input.self_weight_lb();
+    let column_service = input.dead_load_lb + input.live_load_lb;
+    let service_load_lb = column_service + self_weight_lb;
+    let bearing_pressure_psf = service_load_lb / area;
+
+    let net_allowable = input.allowable_bearing_psf - self_weight_lb / area;
+    if net_allowable <= 0.0 {
+        return Err(CalcError::calculation_failed(
+            "spread_footing",
+            "Footing self-weight exceeds the allowable soil bearing",
+        ));
+    }
+
+    let factored_load_lb = (1.4 * input.dead_load_lb).max(1.2 * input.dead_load_lb + 1.6 * input.live_load_lb);
+    let qu = factored_load_lb / area;
+    let d = input.effective_depth_in();
+
+    // ACI 318-19 22.6.5.2: least of 4, 2 + 4/β, and 2 + αs·d/b0, times λ√f'c
+    let (c1, c2) = (input.column_length_in, input.column_width_in);
+    let punching_perimeter_in = 2.0 * (c1 + d) + 2.0 * (c2 + d);
+    let beta = c1.max(c2) / c1.min(c2);
+    let vc_coefficient = 4.0_f64.min(2.0 + 4.0 / beta).min(2.0 + 40.0 * d / punching_perimeter_in);
+    let punching_capacity_lb = PHI_SHEAR * vc_coefficient * input.fc_psi.sqrt() * punching_perimeter_in * d;
+    let punched_area = (c1 + d) * (c2 + d) / 144.0;
+    let punching_shear_lb = (factored_load_lb - qu * punched_area).max(0.0);
+
+    Ok(SpreadFootingResult {
+        self_weight_lb,
+        service_load_lb,
+        bearing_pressure_psf,
+        bearing_unity: bearing_pressure_psf / input.allowable_bearing_psf,
+        required_area_ft2: column_service / net_allowable,
+        factored_load_lb,
+        factored_pressure_psf: qu,
+        effective_depth_in: d,
+        punching_perimeter_in,
+        punching_shear_lb,
+        punching_capacity_lb,
+        punching_shear_unity: punching_shear_lb / punching_capacity_lb,
+        along_length: check_direction(input, qu, input.length_ft, input.width_ft, c1)?,
+        along_width: check_direction(input, qu, input.width_ft, input.length_ft, c2)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_footing() -> SpreadFootingInput {
+        SpreadFootingInput::new("F-1", 60_000.0, 40_000.0, 3000.0, 6.5, 6.5, 18.0, 16.0).with_rebar(6, 8)
+    }
+
+    #[test]
+    fn test_centric_square_footing_bearing_and_punching() {
+        let result = calculate(&square_footing()).unwrap();
+
+        // 6.5 ft × 6.5 ft × 1.5 ft × 150 pcf = 9506 lb
+        assert!((result.self_weight_lb - 9506.25).abs() < 0.01);
+        let q = (100_000.0 + 9506.25) / 42.25;
+        assert!((result.bearing_pressure_psf - q).abs() < 1e-9);
+        assert!((result.bearing_unity - q / 3000.0).abs() < 1e-12);
+        // 100 kips over 3000 − 225 psf net
+        assert!((result.required_area_ft2 - 100_000.0 / 2775.0).abs() < 1e-9);
+
+        // Pu = 1.2(60) + 1.6(40) = 136 kips, d = 18 − 3 − 0.75 = 14.25 in
+        assert_eq!(result.factored_load_lb, 136_000.0);
+        assert_eq!(result.effective_depth_in, 14.25);
+
+        // b0 = 4(16 + 14.25) = 121 in; 4√f'c governs over 2 + 40d/b0 = 6.7
+        assert!((result.punching_perimeter_in - 121.0).abs() < 1e-12);
+        let vu = 136_000.0 - 136_000.0 / 42.25 * (30.25_f64 / 12.0).powi(2);
+        let phi_vc = 0.75 * 4.0 * 3000.0_f64.sqrt() * 121.0 * 14.25;
+        assert!((result.punching_shear_lb - vu).abs() < 1e-6);
+        assert!((result.punching_capacity_lb - phi_vc).abs() < 1e-6);
+        assert!((result.punching_shear_unity - vu / phi_vc).abs() < 1e-12);
+
+        assert!(result.passes());
+    }
+
+    #[test]
+    fn test_one_way_shear_and_flexure() {
+        let result = calculate(&square_footing()).unwrap();
+        let qu = 136_000.0 / 42.25;
+
+        // Cantilever (6.5 − 16/12)/2 = 2.583 ft; shear section at d = 1.1875 ft
+        let dir = &result.along_length;
+        assert!((dir.cantilever_ft - 31.0 / 12.0).abs() < 1e-12);
+        let vu = qu * 6.5 * (31.0 / 12.0 - 14.25 / 12.0);
+        assert!((dir.shear_lb - vu).abs() < 1e-6);
+        assert!((dir.shear_capacity_lb - 0.75 * 2.0 * 3000.0_f64.sqrt() * 78.0 * 14.25).abs() < 1e-6);
+
+        // Flexural steel satisfies Mu = φ·As·fy·(d − a/2)
+        let mu = qu * 6.5 * (31.0_f64 / 12.0).powi(2) / 2.0;
+        assert!((dir.moment_ftlb - mu).abs() < 1e-6);
+        let a = dir.as_flexure_in2 * 60_000.0 / (0.85 * 3000.0 * 78.0);
+        assert!((0.9 * dir.as_flexure_in2 * 60_000.0 * (14.25 - a / 2.0) / 12.0 - mu).abs() < 1e-3);
+
+        // 0.0018 × 78 × 18 = 2.53 in² minimum; 8 #6 = 3.52 in²
+        assert!((dir.as_minimum_in2 - 2.5272).abs() < 1e-9);
+        assert!((dir.as_provided_in2 - 3.52).abs() < 1e-9);
+
+        // Square footing under a square column: both directions match
+        assert!((result.along_width.moment_ftlb - mu).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_undersized_footing_fails() {
+        let small = SpreadFootingInput::new("F-2", 60_000.0, 40_000.0, 3000.0, 5.0, 5.0, 10.0, 16.0).with_rebar(5, 4);
+        let result = calculate(&small).unwrap();
+        assert!(result.bearing_unity > 1.0);
+        assert!(result.punching_shear_unity > 1.0);
+        assert!(!result.passes());
+
+        // No bars at all never passes flexure
+        let bare = calculate(&square_footing().with_rebar(6, 0)).unwrap();
+        assert!(!bare.passes());
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert!(calculate(&square_footing().with_rebar(2, 8)).is_err());
+        assert!(calculate(&square_footing().with_column(90.0, 16.0)).is_err());
+        assert!(calculate(&square_footing().with_fc_psi(0.0)).is_err());
+    }
+}
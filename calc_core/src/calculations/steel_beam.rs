@@ -0,0 +1,587 @@
+//! # Steel Beam Calculation
+//!
+//! Checks a simply-supported rolled W-shape beam per AISC 360-22: flexure
+//! per Chapter F (yielding, lateral-torsional buckling, and flange local
+//! buckling) and shear per Chapter G.
+//!
+//! ## Assumptions
+//!
+//! - Simply-supported (pin-roller) boundary conditions
+//! - Single rolled W-shape from [`builtin_common_shapes`], bending about the
+//!   strong axis
+//! - Compact web (AISC F2/F3); noncompact and slender webs are rejected
+//! - Unstiffened web for shear (kv = 5.34)
+//! - Deflection is not checked
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::steel_beam::{calculate, SteelBeamInput};
+//! use calc_core::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+//!
+//! let load_case = EnhancedLoadCase::new("Floor")
+//!     .with_load(DiscreteLoad::uniform(LoadType::Dead, 300.0))
+//!     .with_load(DiscreteLoad::uniform(LoadType::Live, 800.0));
+//!
+//! let input = SteelBeamInput::new("SB-1", 20.0, "W18X35", load_case).with_unbraced_length(10.0);
+//!
+//! let result = calculate(&input, DesignMethod::Lrfd).unwrap();
+//! assert!(result.passes());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CalcError, CalcResult};
+use crate::loads::{
+    select_combinations, validate_combinations, CodeEdition, DesignMethod, EnhancedLoadCase, LoadCombination, LoadType,
+};
+use crate::materials::{builtin_common_shapes, ShapeType, SteelShape, STEEL_E_KSI};
+
+use super::beam_analysis::{BeamAnalysis, SingleLoad};
+use super::continuous_beam::GoverningCondition;
+
+/// Yield stress of ASTM A992, the usual grade for W-shapes (ksi)
+pub const A992_FY_KSI: f64 = 50.0;
+
+/// Flexural resistance factor φb and safety factor Ωb (AISC F1)
+const PHI_B: f64 = 0.90;
+const OMEGA_B: f64 = 1.67;
+
+fn default_fy_ksi() -> f64 {
+    A992_FY_KSI
+}
+
+fn default_cb() -> f64 {
+    1.0
+}
+
+/// Input parameters for a simply-supported steel beam.
+///
+/// ## JSON Example
+///
+/// ```json
+/// {
+///   "label": "SB-1",
+///   "span_ft": 20.0,
+///   "shape": "W18X35",
+///   "fy_ksi": 50.0,
+///   "load_case": {
+///     "label": "Floor",
+///     "include_self_weight": true,
+///     "loads": [
+///       { "load_type": "Dead", "distribution": "UniformFull", "magnitude": 300.0 },
+///       { "load_type": "Live", "distribution": "UniformFull", "magnitude": 800.0 }
+///     ]
+///   },
+///   "unbraced_length_ft": 10.0,
+///   "cb": 1.0
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SteelBeamInput {
+    /// User label for this beam (e.g., "SB-1")
+    pub label: String,
+
+    /// Span length in feet
+    pub span_ft: f64,
+
+    /// AISC Manual label of the W-shape (e.g., "W18X35")
+    pub shape: String,
+
+    /// Specified minimum yield stress Fy (ksi)
+    #[serde(default = "default_fy_ksi")]
+    pub fy_ksi: f64,
+
+    /// Loads on the beam; self-weight uses the shape's nominal weight
+    pub load_case: EnhancedLoadCase,
+
+    /// Length between points that brace the compression flange, Lb (ft)
+    ///
+    /// Zero for a continuously braced flange (e.g., under a deck).
+    pub unbraced_length_ft: f64,
+
+    /// Lateral-torsional buckling modification factor Cb (AISC F1)
+    ///
+    /// 1.0 is always conservative.
+    #[serde(default = "default_cb")]
+    pub cb: f64,
+
+    /// ASCE 7 edition the load combinations follow
+    #[serde(default)]
+    pub code_edition: CodeEdition,
+
+    /// User-defined load combinations replacing the built-in set for the
+    /// design method they are tagged with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_combinations: Option<Vec<LoadCombination>>,
+}
+
+impl SteelBeamInput {
+    /// Create an A992 beam unbraced over its full span, with Cb = 1.0
+    pub fn new(
+        label: impl Into<String>,
+        span_ft: f64,
+        shape: impl Into<String>,
+        load_case: EnhancedLoadCase,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            span_ft,
+            shape: shape.into(),
+            fy_ksi: A992_FY_KSI,
+            load_case,
+            unbraced_length_ft: span_ft,
+            cb: default_cb(),
+            code_edition: CodeEdition::default(),
+            custom_combinations: None,
+        }
+    }
+
+    /// Set the yield stress Fy in ksi (builder pattern)
+    pub fn with_fy_ksi(mut self, fy_ksi: f64) -> Self {
+        self.fy_ksi = fy_ksi;
+        self
+    }
+
+    /// Set the unbraced length Lb in feet (builder pattern)
+    pub fn with_unbraced_length(mut self, unbraced_length_ft: f64) -> Self {
+        self.unbraced_length_ft = unbraced_length_ft;
+        self
+    }
+
+    /// Set the LTB modification factor Cb (builder pattern)
+    pub fn with_cb(mut self, cb: f64) -> Self {
+        self.cb = cb;
+        self
+    }
+
+    /// Set the ASCE 7 edition for load combinations (builder pattern)
+    pub fn with_code_edition(mut self, edition: CodeEdition) -> Self {
+        self.code_edition = edition;
+        self
+    }
+
+    /// Replace the built-in load combinations (builder pattern)
+    pub fn with_custom_combinations(mut self, combinations: Vec<LoadCombination>) -> Self {
+        self.custom_combinations = Some(combinations);
+        self
+    }
+
+    /// Load combinations to analyze under `method`, per [`select_combinations`]
+    pub fn load_combinations(&self, method: DesignMethod) -> Vec<LoadCombination> {
+        select_combinations(self.code_edition, self.custom_combinations.as_deref(), method)
+    }
+
+    /// Validate input parameters.
+    pub fn validate(&self) -> CalcResult<()> {
+        if self.span_ft <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "span_ft",
+                self.span_ft.to_string(),
+                "Span must be positive",
+            ));
+        }
+        if self.fy_ksi <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "fy_ksi",
+                self.fy_ksi.to_string(),
+                "Yield stress must be positive",
+            ));
+        }
+        if self.unbraced_length_ft < 0.0 {
+            return Err(CalcError::invalid_input(
+                "unbraced_length_ft",
+                self.unbraced_length_ft.to_string(),
+                "Unbraced length cannot be negative",
+            ));
+        }
+        if self.cb < 1.0 {
+            return Err(CalcError::invalid_input(
+                "cb",
+                self.cb.to_string(),
+                "Cb must be at least 1.0",
+            ));
+        }
+        self.load_case.validate(self.span_ft)?;
+        if let Some(combos) = &self.custom_combinations {
+            validate_combinations(combos)?;
+        }
+        self.section().map(|_| ())
+    }
+
+    /// Section properties of the shape
+    ///
+    /// Only W-shapes with flexural-torsional properties (rts, ho, J) are accepted.
+    pub fn section(&self) -> CalcResult<SteelShape> {
+        let shape = builtin_common_shapes().lookup(&self.shape)?.clone();
+        if shape.shape_type != ShapeType::W {
+            return Err(CalcError::invalid_input(
+                "shape",
+                &self.shape,
+                "Only W-shapes are supported",
+            ));
+        }
+        let complete = shape.depth_in.is_some()
+            && shape.tw_in.is_some()
+            && shape.rts_in.is_some()
+            && shape.ho_in.is_some()
+            && shape.bf_2tf.is_some()
+            && shape.h_tw.is_some();
+        if !complete {
+            return Err(CalcError::invalid_input(
+                "shape",
+                &self.shape,
+                "Shape is missing d, tw, rts, ho, bf/2tf, or h/tw",
+            ));
+        }
+        Ok(shape)
+    }
+}
+
+/// Limit state that sets the nominal flexural strength Mn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FlexuralLimitState {
+    /// Full plastic moment, Mn = Mp (AISC F2.1)
+    #[default]
+    Yielding,
+    /// Lateral-torsional buckling (AISC F2.2)
+    LateralTorsionalBuckling,
+    /// Compression flange local buckling (AISC F3.2)
+    FlangeLocalBuckling,
+}
+
+impl FlexuralLimitState {
+    /// Human-readable name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FlexuralLimitState::Yielding => "Yielding",
+            FlexuralLimitState::LateralTorsionalBuckling => "Lateral-Torsional Buckling",
+            FlexuralLimitState::FlangeLocalBuckling => "Flange Local Buckling",
+        }
+    }
+}
+
+/// Results of a steel beam calculation.
+///
+/// Required and available strengths are both ASD (Ma, Mn/Ω) or both LRFD
+/// (Mu, φMn), following the design method.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SteelBeamResult {
+    /// Load combination producing the largest moment
+    pub governing_combination: String,
+
+    /// Shape self-weight (plf), included as dead load when enabled
+    pub self_weight_plf: f64,
+
+    // === Required Strength ===
+    /// Maximum moment over all combinations (ft-lb)
+    pub max_moment_ftlb: f64,
+
+    /// Maximum shear over all combinations (lb)
+    pub max_shear_lb: f64,
+
+    /// Maximum left reaction over all combinations (lb)
+    pub reaction_left_lb: f64,
+
+    /// Maximum right reaction over all combinations (lb)
+    pub reaction_right_lb: f64,
+
+    // === Flexure (AISC Chapter F) ===
+    /// Plastic moment Mp = Fy·Zx (ft-lb)
+    pub plastic_moment_ftlb: f64,
+
+    /// Limiting unbraced length for yielding, Lp (ft)
+    pub lp_ft: f64,
+
+    /// Limiting unbraced length for inelastic LTB, Lr (ft)
+    pub lr_ft: f64,
+
+    /// Flange slenderness bf/2tf
+    pub flange_slenderness: f64,
+
+    /// Limit state setting Mn
+    pub limit_state: FlexuralLimitState,
+
+    /// Nominal flexural strength Mn (ft-lb)
+    pub nominal_moment_ftlb: f64,
+
+    /// Available flexural strength, Mn/Ωb or φb·Mn (ft-lb)
+    pub available_moment_ftlb: f64,
+
+    /// Bending unity: required / available moment
+    pub bending_unity: f64,
+
+    // === Shear (AISC Chapter G) ===
+    /// Web slenderness h/tw
+    pub web_slenderness: f64,
+
+    /// Web shear strength coefficient Cv1
+    pub cv1: f64,
+
+    /// Nominal shear strength Vn = 0.6·Fy·Aw·Cv1 (lb)
+    pub nominal_shear_lb: f64,
+
+    /// Available shear strength, Vn/Ωv or φv·Vn (lb)
+    pub available_shear_lb: f64,
+
+    /// Shear unity: required / available shear
+    pub shear_unity: f64,
+}
+
+impl SteelBeamResult {
+    /// Check if flexure and shear both pass (unity ≤ 1.0)
+    pub fn passes(&self) -> bool {
+        self.bending_unity <= 1.0 && self.shear_unity <= 1.0
+    }
+
+    /// Get the governing (highest) unity ratio
+    pub fn governing_unity(&self) -> f64 {
+        self.bending_unity.max(self.shear_unity)
+    }
+
+    /// Get the check that governs the design
+    pub fn governing_condition(&self) -> GoverningCondition {
+        GoverningCondition::from_unities(self.bending_unity, self.shear_unity, 0.0)
+    }
+}
+
+/// Nominal flexural strength Mn (ft-lb) and the limit state that sets it
+///
+/// AISC F2 yielding and lateral-torsional buckling, with F3 flange local
+/// buckling for noncompact and slender flanges. Returns Mn, Lp, Lr (ft).
+fn nominal_flexure(shape: &SteelShape, fy_ksi: f64, lb_ft: f64, cb: f64) -> (f64, FlexuralLimitState, f64, f64) {
+    let e = STEEL_E_KSI;
+    let (sx, zx, ry, j) = (shape.sx_in3, shape.zx_in3, shape.ry_in, shape.j_in4);
+    let rts = shape.rts_in.unwrap_or_default();
+    let ho = shape.ho_in.unwrap_or_default();
+
+    // kip-in
+    let mp = fy_ksi * zx;
+    let my_07 = 0.7 * fy_ksi * sx;
+
+    // F2-5, F2-6 (c = 1 for doubly symmetric I-shapes)
+    let lp = 1.76 * ry * (e / fy_ksi).sqrt();
+    let jc = j / (sx * ho);
+    let lr = 1.95 * rts * e / (0.7 * fy_ksi) * (jc + (jc * jc + 6.76 * (0.7 * fy_ksi / e).powi(2)).sqrt()).sqrt();
+
+    let lb = lb_ft * 12.0;
+    let mut mn = mp;
+    let mut limit_state = FlexuralLimitState::Yielding;
+
+    let ltb = if lb <= lp {
+        mp
+    } else if lb <= lr {
+        cb * (mp - (mp - my_07) * (lb - lp) / (lr - lp))
+    } else {
+        let slenderness = lb / rts;
+        let fcr = cb * std::f64::consts::PI.powi(2) * e / slenderness.powi(2)
+            * (1.0 + 0.078 * jc * slenderness.powi(2)).sqrt();
+        fcr * sx
+    };
+    if ltb < mn {
+        mn = ltb;
+        limit_state = FlexuralLimitState::LateralTorsionalBuckling;
+    }
+
+    // F3-1, F3-2
+    let lambda = shape.bf_2tf.unwrap_or_default();
+    let lambda_pf = 0.38 * (e / fy_ksi).sqrt();
+    let lambda_rf = (e / fy_ksi).sqrt();
+    let flb = if lambda <= lambda_pf {
+        mp
+    } else if lambda <= lambda_rf {
+        mp - (mp - my_07) * (lambda - lambda_pf) / (lambda_rf - lambda_pf)
+    } else {
+        let kc = (4.0 / shape.h_tw.unwrap_or_default().sqrt()).clamp(0.35, 0.76);
+        0.9 * e * kc * sx / (lambda * lambda)
+    };
+    if flb < mn {
+        mn = flb;
+        limit_state = FlexuralLimitState::FlangeLocalBuckling;
+    }
+
+    (mn * 1000.0 / 12.0, limit_state, lp / 12.0, lr / 12.0)
+}
+
+/// Nominal shear strength Vn (lb), Cv1, and the (φv, Ωv) pair per AISC G2.1
+fn nominal_shear(shape: &SteelShape, fy_ksi: f64) -> (f64, f64, (f64, f64)) {
+    let e = STEEL_E_KSI;
+    let h_tw = shape.h_tw.unwrap_or_default();
+    let aw = shape.depth_in.unwrap_or_default() * shape.tw_in.unwrap_or_default();
+
+    // G2.1(a): rolled I-shapes with stocky webs
+    if h_tw <= 2.24 * (e / fy_ksi).sqrt() {
+        return (0.6 * fy_ksi * 1000.0 * aw, 1.0, (1.00, 1.50));
+    }
+
+    // G2.1(b)(1), unstiffened web
+    let kv = 5.34;
+    let limit = 1.10 * (kv * e / fy_ksi).sqrt();
+    let cv1 = if h_tw <= limit { 1.0 } else { limit / h_tw };
+    (0.6 * fy_ksi * 1000.0 * aw * cv1, cv1, (0.90, 1.67))
+}
+
+/// Available strength from nominal strength for the design method
+fn available(nominal: f64, method: DesignMethod, (phi, omega): (f64, f64)) -> f64 {
+    match method {
+        DesignMethod::Asd => nominal / omega,
+        DesignMethod::Lrfd => phi * nominal,
+    }
+}
+
+/// Calculate flexural and shear capacity of a steel beam.
+///
+/// # Arguments
+///
+/// * `input` - Beam parameters (shape, Fy, span, loads, Lb, Cb)
+/// * `method` - Design method (ASD or LRFD) for load combinations and
+///   resistance factors
+///
+/// # Returns
+///
+/// * `Ok(SteelBeamResult)` - Calculation results
+/// * `Err(CalcError)` - If inputs are invalid or the shape has a noncompact web
+pub fn calculate(input: &SteelBeamInput, method: DesignMethod) -> CalcResult<SteelBeamResult> {
+    input.validate()?;
+    let shape = input.section()?;
+
+    let h_tw = shape.h_tw.unwrap_or_default();
+    if h_tw > 3.76 * (STEEL_E_KSI / input.fy_ksi).sqrt() {
+        return Err(CalcError::calculation_failed(
+            "steel_beam",
+            format!("{} has a noncompact web (AISC F4/F5 not supported)", shape.label),
+        ));
+    }
+
+    let mut loads_by_type: Vec<(LoadType, SingleLoad)> = Vec::new();
+    if input.load_case.include_self_weight {
+        loads_by_type.push((LoadType::Dead, SingleLoad::uniform_full(shape.weight_plf)));
+    }
+    for load in &input.load_case.loads {
+        if let Some(single) = SingleLoad::from_discrete(load) {
+            loads_by_type.push((load.load_type, single));
+        }
+    }
+
+    let mut result = SteelBeamResult {
+        self_weight_plf: shape.weight_plf,
+        ..Default::default()
+    };
+    let e_psi = STEEL_E_KSI * 1000.0;
+    for combo in &input.load_combinations(method) {
+        let mut analysis = BeamAnalysis::new(input.span_ft, e_psi, shape.ix_in4);
+        for (load_type, single) in &loads_by_type {
+            let factor = combo.get_factor(*load_type);
+            if factor.abs() > 1e-10 {
+                analysis.add_load(single.scaled(factor));
+            }
+        }
+        if analysis.loads.is_empty() {
+            continue;
+        }
+
+        let results = analysis.analyze();
+        if results.max_moment_ftlb > result.max_moment_ftlb {
+            result.max_moment_ftlb = results.max_moment_ftlb;
+            result.governing_combination = combo.name.clone();
+        }
+        result.max_shear_lb = result.max_shear_lb.max(results.max_shear_lb);
+        result.reaction_left_lb = result.reaction_left_lb.max(results.reaction_left_lb);
+        result.reaction_right_lb = result.reaction_right_lb.max(results.reaction_right_lb);
+    }
+
+    let (mn, limit_state, lp_ft, lr_ft) =
+        nominal_flexure(&shape, input.fy_ksi, input.unbraced_length_ft, input.cb);
+    result.plastic_moment_ftlb = input.fy_ksi * shape.zx_in3 * 1000.0 / 12.0;
+    result.lp_ft = lp_ft;
+    result.lr_ft = lr_ft;
+    result.flange_slenderness = shape.bf_2tf.unwrap_or_default();
+    result.limit_state = limit_state;
+    result.nominal_moment_ftlb = mn;
+    result.available_moment_ftlb = available(mn, method, (PHI_B, OMEGA_B));
+    result.bending_unity = result.max_moment_ftlb / result.available_moment_ftlb;
+
+    let (vn, cv1, factors) = nominal_shear(&shape, input.fy_ksi);
+    result.web_slenderness = h_tw;
+    result.cv1 = cv1;
+    result.nominal_shear_lb = vn;
+    result.available_shear_lb = available(vn, method, factors);
+    result.shear_unity = result.max_shear_lb / result.available_shear_lb;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loads::DiscreteLoad;
+
+    fn floor_beam(shape: &str, unbraced_length_ft: f64) -> SteelBeamInput {
+        let load_case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 300.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 800.0));
+        SteelBeamInput::new("SB-1", 20.0, shape, load_case).with_unbraced_length(unbraced_length_ft)
+    }
+
+    #[test]
+    fn test_braced_beam_reaches_plastic_moment() {
+        let result = calculate(&floor_beam("W18X35", 0.0), DesignMethod::Lrfd).unwrap();
+
+        // W18X35: Mp = 50 ksi × 66.5 in³ = 277.1 kip-ft, φMp = 249.4 kip-ft
+        assert_eq!(result.limit_state, FlexuralLimitState::Yielding);
+        assert!((result.plastic_moment_ftlb - 277_083.0).abs() < 1.0);
+        assert!((result.available_moment_ftlb - 0.9 * result.plastic_moment_ftlb).abs() < 1e-6);
+
+        // 1.2(300 + 35) + 1.6(800) = 1682 plf over 20 ft
+        assert!((result.max_moment_ftlb - 1682.0 * 400.0 / 8.0).abs() < 1.0);
+        assert!(result.passes());
+    }
+
+    #[test]
+    fn test_lateral_torsional_buckling_ranges() {
+        // AISC Manual Table 3-2, W18X35: Lp = 4.31 ft, Lr = 12.3 ft
+        let braced = calculate(&floor_beam("W18X35", 0.0), DesignMethod::Asd).unwrap();
+        assert!((braced.lp_ft - 4.31).abs() < 0.02);
+        assert!((braced.lr_ft - 12.3).abs() < 0.2);
+
+        // Inelastic: linear between Mp at Lp and 0.7FySx at Lr
+        let inelastic = calculate(&floor_beam("W18X35", 8.0), DesignMethod::Asd).unwrap();
+        let mp = braced.plastic_moment_ftlb;
+        let mr = 0.7 * 50.0 * 57.6 * 1000.0 / 12.0;
+        let expected = mp - (mp - mr) * (8.0 - braced.lp_ft) / (braced.lr_ft - braced.lp_ft);
+        assert_eq!(inelastic.limit_state, FlexuralLimitState::LateralTorsionalBuckling);
+        assert!((inelastic.nominal_moment_ftlb - expected).abs() < 1.0);
+        assert!((inelastic.available_moment_ftlb - expected / 1.67).abs() < 1.0);
+
+        // Elastic: below 0.7FySx past Lr; Cb raises it back up to Mp at most
+        let elastic = calculate(&floor_beam("W18X35", 20.0), DesignMethod::Asd).unwrap();
+        assert!(elastic.nominal_moment_ftlb < mr);
+        let with_cb = calculate(&floor_beam("W18X35", 20.0).with_cb(1.14), DesignMethod::Asd).unwrap();
+        assert!(with_cb.nominal_moment_ftlb > elastic.nominal_moment_ftlb);
+        assert!(with_cb.nominal_moment_ftlb <= mp);
+    }
+
+    #[test]
+    fn test_noncompact_flange() {
+        // W14X90 (bf/2tf = 10.2) is noncompact at 50 ksi: AISC φMn = 574 kip-ft
+        let result = calculate(&floor_beam("W14X90", 0.0), DesignMethod::Lrfd).unwrap();
+        assert_eq!(result.limit_state, FlexuralLimitState::FlangeLocalBuckling);
+        assert!((result.available_moment_ftlb / 1000.0 - 574.0).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_web_shear() {
+        // W12X26: Vn = 0.6 × 50 × 12.2 × 0.23 = 84.2 kips, Vn/Ωv = 56.1 kips
+        let result = calculate(&floor_beam("W12X26", 0.0), DesignMethod::Asd).unwrap();
+        assert_eq!(result.cv1, 1.0);
+        assert!((result.nominal_shear_lb - 84_180.0).abs() < 1.0);
+        assert!((result.available_shear_lb - 56_120.0).abs() < 1.0);
+        assert!((result.shear_unity - result.max_shear_lb / result.available_shear_lb).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rejects_unknown_shape() {
+        assert!(calculate(&floor_beam("W99X999", 0.0), DesignMethod::Asd).is_err());
+        assert!(calculate(&floor_beam("W18X35", 0.0).with_cb(0.5), DesignMethod::Asd).is_err());
+    }
+}
@@ -302,6 +302,79 @@ pub fn partial_uniform_moment(w: f64, a: f64, b: f64, l: f64, x: f64) -> f64 {
     }
 }
 
+// =============================================================================
+// TRAPEZOIDAL LOAD FORMULAS
+// Simply-supported beam with load varying linearly from w1 at a to w2 at b
+// =============================================================================
+
+/// Calculate reactions for trapezoidal load varying from w1 at a to w2 at b
+///
+/// ```text
+///                  ↓↓↓ w2
+///           ↓↓↓↓↓↓↓↓↓↓
+///    w1 ↓↓↓↓↓↓↓↓↓↓↓↓↓↓
+///    ══════════════════
+///    △     a     b     △
+///   R1  ←─────L─────→ R2
+/// ```
+///
+/// # Formulas
+/// Loaded length c = b - a, total load W = (w1 + w2)c/2
+/// Moment of load about a: M_a = c²(w1 + 2w2)/6
+/// - R2 = (W·a + M_a)/L
+/// - R1 = W - R2
+///
+/// Reduces to [`partial_uniform_reactions`] when w1 = w2.
+#[inline]
+pub fn trapezoidal_reactions(w1: f64, w2: f64, a: f64, b: f64, l: f64) -> (f64, f64) {
+    let c = b - a;
+    let total_load = (w1 + w2) * c / 2.0;
+    let moment_about_a = c * c * (w1 + 2.0 * w2) / 6.0;
+    let r2 = (total_load * a + moment_about_a) / l;
+    (total_load - r2, r2)
+}
+
+/// Resultant of the part of a trapezoidal load left of position x
+///
+/// # Returns
+/// (F, M) - Load left of x and its moment about x (positive for downward load)
+#[inline]
+pub fn trapezoidal_load_to(w1: f64, w2: f64, a: f64, b: f64, x: f64) -> (f64, f64) {
+    if x <= a || b <= a {
+        return (0.0, 0.0);
+    }
+    let slope = (w2 - w1) / (b - a);
+    let u = x.min(b) - a;
+    let force = w1 * u + slope * u * u / 2.0;
+    // Moment about the end of the active portion, then carried to x
+    let moment = w1 * u * u / 2.0 + slope * u.powi(3) / 6.0 + force * (x - a - u);
+    (force, moment)
+}
+
+/// Calculate shear at position x for trapezoidal load
+///
+/// # Formulas
+/// - V(x) = R1 - F(x)
+///
+/// where F(x) is the load between a and x
+#[inline]
+pub fn trapezoidal_shear(w1: f64, w2: f64, a: f64, b: f64, l: f64, x: f64) -> f64 {
+    let (r1, _) = trapezoidal_reactions(w1, w2, a, b, l);
+    r1 - trapezoidal_load_to(w1, w2, a, b, x).0
+}
+
+/// Calculate moment at position x for trapezoidal load
+///
+/// # Formulas
+/// - M(x) = R1·x - w1·u²/2 - (w2 - w1)u³/(6c)   for a < x < b, u = x - a
+///
+/// Beyond b the full load acts at its centroid.
+#[inline]
+pub fn trapezoidal_moment(w1: f64, w2: f64, a: f64, b: f64, l: f64, x: f64) -> f64 {
+    let (r1, _) = trapezoidal_reactions(w1, w2, a, b, l);
+    r1 * x - trapezoidal_load_to(w1, w2, a, b, x).1
+}
+
 // =============================================================================
 // APPLIED MOMENT FORMULAS
 // Simply-supported beam with applied moment M0 at position a
@@ -321,6 +394,35 @@ pub fn applied_moment_reactions(m0: f64, l: f64) -> (f64, f64) {
     (r1, r2)
 }
 
+/// Calculate deflection at position x for applied moment M0 at position a
+///
+/// M0 is clockwise-positive, so the bending moment steps up by M0 at a.
+///
+/// # Formulas (unit-load method)
+///
+/// For x ≤ a:
+/// ```text
+/// δ(x) = M0 [x(L-a)²/(2L) - x(L² - x²)/(6L)] / EI
+/// ```
+///
+/// For x > a:
+/// ```text
+/// δ(x) = M0 [(L-x)(xL - a²)/(2L) - x(L² - x²)/(6L)] / EI
+/// ```
+///
+/// # Returns
+/// Deflection (positive downward)
+#[inline]
+pub fn applied_moment_deflection(m0: f64, a: f64, l: f64, x: f64, e: f64, i: f64) -> f64 {
+    let ei = e * i;
+    let step = if x <= a {
+        x * (l - a).powi(2) / (2.0 * l)
+    } else {
+        (l - x) * (x * l - a * a) / (2.0 * l)
+    };
+    m0 * (step - x * (l * l - x * x) / (6.0 * l)) / ei
+}
+
 // =============================================================================
 // FIXED-END MOMENT (FEM) FORMULAS
 // Used for moment distribution in indeterminate beam analysis
@@ -369,27 +471,111 @@ pub fn fem_point_load(p: f64, a: f64, l: f64) -> (f64, f64) {
     (fem_a, fem_b)
 }
 
+/// Fixed-end moments for applied moment M0 (clockwise) at position a
+///
+/// # Formulas
+/// - FEM_A = M0·b(2a - b)/L²
+/// - FEM_B = M0·a(2b - a)/L²
+///
+/// where b = L - a
+#[inline]
+pub fn fem_applied_moment(m0: f64, a: f64, l: f64) -> (f64, f64) {
+    let b = l - a;
+    let l2 = l * l;
+    (m0 * b * (2.0 * a - b) / l2, m0 * a * (2.0 * b - a) / l2)
+}
+
 /// Fixed-end moments for partial uniform load w from 'a' to 'b'
 ///
 /// Uses numerical integration by dividing load into point loads
 #[inline]
 pub fn fem_partial_uniform(w: f64, start: f64, end: f64, l: f64) -> (f64, f64) {
-    // Divide into 20 segments for accuracy
-    let num_segments = 20;
-    let segment_length = (end - start) / num_segments as f64;
-    let segment_load = w * segment_length;
+    partial_load_segments(w, start, end, PARTIAL_LOAD_SEGMENTS).fold((0.0, 0.0), |(fem_a, fem_b), (p, a)| {
+        let (fa, fb) = fem_point_load(p, a, l);
+        (fem_a + fa, fem_b + fb)
+    })
+}
 
-    let mut fem_a = 0.0;
-    let mut fem_b = 0.0;
+/// Fixed-end moments for trapezoidal load varying from w1 at 'a' to w2 at 'b'
+///
+/// Integrates the point load FEMs against w(x) with 3-point Gauss-Legendre
+/// quadrature, which is exact for the quartic integrand.
+#[inline]
+pub fn fem_trapezoidal(w1: f64, w2: f64, start: f64, end: f64, l: f64) -> (f64, f64) {
+    const GAUSS: [(f64, f64); 3] = [
+        (-0.774_596_669_241_483_4, 5.0 / 9.0),
+        (0.0, 8.0 / 9.0),
+        (0.774_596_669_241_483_4, 5.0 / 9.0),
+    ];
+    let half = (end - start) / 2.0;
+    let mid = (start + end) / 2.0;
+    GAUSS.iter().fold((0.0, 0.0), |(fem_a, fem_b), (t, weight)| {
+        let x = mid + half * t;
+        let w = w1 + (w2 - w1) * (1.0 + t) / 2.0;
+        let (fa, fb) = fem_point_load(w * weight * half, x, l);
+        (fem_a + fa, fem_b + fb)
+    })
+}
 
-    for i in 0..num_segments {
-        let pos = start + (i as f64 + 0.5) * segment_length;
-        let (fa, fb) = fem_point_load(segment_load, pos, l);
-        fem_a += fa;
-        fem_b += fb;
-    }
+// =============================================================================
+// PARTIAL UNIFORM LOAD INTEGRATION
+// Partial uniform loads replaced by equivalent point loads (midpoint rule)
+// =============================================================================
 
-    (fem_a, fem_b)
+/// Default number of point loads used to integrate a partial uniform load
+pub const PARTIAL_LOAD_SEGMENTS: usize = 20;
+
+/// Split partial uniform load w from `start` to `end` into `count` point loads
+///
+/// Each segment of length Δ = (end - start)/count is replaced by a point
+/// load w·Δ at its midpoint. Yields (P, a) pairs.
+#[inline]
+pub fn partial_load_segments(w: f64, start: f64, end: f64, count: usize) -> impl Iterator<Item = (f64, f64)> {
+    let count = count.max(1);
+    let segment_length = (end - start) / count as f64;
+    (0..count).map(move |i| (w * segment_length, start + (i as f64 + 0.5) * segment_length))
+}
+
+/// Split trapezoidal load from w1 at `start` to w2 at `end` into `count` point loads
+///
+/// Like [`partial_load_segments`], with each segment carrying the load
+/// intensity at its midpoint.
+#[inline]
+pub fn trapezoidal_load_segments(
+    w1: f64,
+    w2: f64,
+    start: f64,
+    end: f64,
+    count: usize,
+) -> impl Iterator<Item = (f64, f64)> {
+    let count = count.max(1);
+    let segment_length = (end - start) / count as f64;
+    (0..count).map(move |i| {
+        let t = (i as f64 + 0.5) / count as f64;
+        ((w1 + (w2 - w1) * t) * segment_length, start + t * (end - start))
+    })
+}
+
+/// Deflection at x from a set of point loads on a simple span
+///
+/// Superposes [`point_load_deflection`] over (P, a) pairs, typically from
+/// [`partial_load_segments`]. Converges to the exact partial uniform load
+/// deflection as the segment count increases.
+///
+/// # Returns
+/// Deflection (positive downward)
+#[inline]
+pub fn integrate_point_load_deflection(
+    segments: impl IntoIterator<Item = (f64, f64)>,
+    l: f64,
+    x: f64,
+    e: f64,
+    i: f64,
+) -> f64 {
+    segments
+        .into_iter()
+        .map(|(p, a)| point_load_deflection(p, a, l, x, e, i))
+        .sum()
 }
 
 // =============================================================================
@@ -737,6 +923,83 @@ mod tests {
         assert!(approx_eq(r2, 300.0), "R2 = {} (expected 300)", r2);
     }
 
+    #[test]
+    fn test_partial_load_integration() {
+        let w = 100.0;
+        let l = 240.0;
+        let e = 1_600_000.0;
+        let i = 100.0;
+
+        // Load over the full span matches the closed-form uniform deflection
+        let full = integrate_point_load_deflection(
+            partial_load_segments(w, 0.0, l, PARTIAL_LOAD_SEGMENTS),
+            l,
+            l / 2.0,
+            e,
+            i,
+        );
+        let exact = uniform_load_deflection(w, l, l / 2.0, e, i);
+        assert!((full - exact).abs() / exact < 1e-3);
+
+        // Partial load converges as segments increase
+        let partial = |n| integrate_point_load_deflection(partial_load_segments(w, 60.0, 180.0, n), l, 100.0, e, i);
+        let reference = partial(2000);
+        let coarse = (partial(5) - reference).abs();
+        let fine = (partial(40) - reference).abs();
+        assert!(fine < coarse);
+        assert!(fine / reference < 1e-3);
+    }
+
+    #[test]
+    fn test_triangular_load() {
+        // 12 ft beam, 0 to 300 plf over the full span: W = 1800 lb at 8 ft
+        // R1 = wL/6 = 600 lb, R2 = wL/3 = 1200 lb
+        // M_max = wL²/(9√3) = 2771.3 ft-lb at x = L/√3
+        let (r1, r2) = trapezoidal_reactions(0.0, 300.0, 0.0, 12.0, 12.0);
+        assert!(approx_eq(r1, 600.0), "R1 = {} (expected 600)", r1);
+        assert!(approx_eq(r2, 1200.0), "R2 = {} (expected 1200)", r2);
+
+        let x_max = 12.0 / 3.0f64.sqrt();
+        let m_max = trapezoidal_moment(0.0, 300.0, 0.0, 12.0, 12.0, x_max);
+        assert!(approx_eq(m_max, 300.0 * 144.0 / (9.0 * 3.0f64.sqrt())));
+        assert!(trapezoidal_shear(0.0, 300.0, 0.0, 12.0, 12.0, x_max).abs() < 1e-9);
+        assert!(approx_eq(trapezoidal_shear(0.0, 300.0, 0.0, 12.0, 12.0, 12.0), -1200.0));
+
+        // FEM_A = wL²/30, FEM_B = wL²/20 (Roark's Table 8.1, Case 2e)
+        let (fem_a, fem_b) = fem_trapezoidal(0.0, 300.0, 0.0, 12.0, 12.0);
+        assert!(approx_eq(fem_a, -300.0 * 144.0 / 30.0));
+        assert!(approx_eq(fem_b, 300.0 * 144.0 / 20.0));
+    }
+
+    #[test]
+    fn test_trapezoidal_matches_partial_uniform() {
+        let (r1, r2) = trapezoidal_reactions(100.0, 100.0, 3.0, 7.0, 10.0);
+        let (p1, p2) = partial_uniform_reactions(100.0, 3.0, 7.0, 10.0);
+        assert!(approx_eq(r1, p1) && approx_eq(r2, p2));
+
+        for x in [2.0, 5.0, 8.0] {
+            assert!(approx_eq(
+                trapezoidal_moment(100.0, 100.0, 3.0, 7.0, 10.0, x),
+                partial_uniform_moment(100.0, 3.0, 7.0, 10.0, x)
+            ));
+        }
+
+        let (fem_a, fem_b) = fem_trapezoidal(100.0, 100.0, 0.0, 10.0, 10.0);
+        let (exact_a, exact_b) = fem_uniform_full(100.0, 10.0);
+        assert!(approx_eq(fem_a, exact_a) && approx_eq(fem_b, exact_b));
+    }
+
+    #[test]
+    fn test_applied_moment_midspan() {
+        // Antisymmetric: no midspan deflection, equal fixed-end moments of M0/4
+        let d = applied_moment_deflection(1000.0, 60.0, 120.0, 60.0, 1.6e6, 100.0);
+        assert!(approx_eq(d, 0.0), "δ(L/2) = {}", d);
+
+        let (fem_a, fem_b) = fem_applied_moment(1000.0, 5.0, 10.0);
+        assert!(approx_eq(fem_a, 250.0), "FEM_A = {}", fem_a);
+        assert!(approx_eq(fem_b, 250.0), "FEM_B = {}", fem_b);
+    }
+
     // Superposition principle test
     #[test]
     fn test_superposition() {
@@ -45,10 +45,20 @@ pub use beam::{
     partial_uniform_reactions,
     partial_uniform_moment,
     partial_uniform_shear,
+    trapezoidal_reactions,
+    trapezoidal_load_to,
+    trapezoidal_moment,
+    trapezoidal_shear,
     // Fixed-end moments (for moment distribution)
     fem_uniform_full,
     fem_point_load,
     fem_partial_uniform,
+    fem_trapezoidal,
+    // Partial uniform load integration
+    PARTIAL_LOAD_SEGMENTS,
+    partial_load_segments,
+    trapezoidal_load_segments,
+    integrate_point_load_deflection,
     // Fixed-fixed beam formulas
     fixed_fixed_uniform_reactions,
     fixed_fixed_uniform_end_moments,
@@ -269,6 +269,12 @@ pub enum Equation {
     /// Shear for partial uniform load
     PartialUniformShear,
 
+    // -------------------------------------------------------------------------
+    // Simply-Supported Beam: Trapezoidal Load
+    // -------------------------------------------------------------------------
+    /// Reactions for load varying linearly from w1 at a to w2 at b
+    TrapezoidalReactions,
+
     // -------------------------------------------------------------------------
     // Fixed-End Moments (for Moment Distribution)
     // -------------------------------------------------------------------------
@@ -278,6 +284,10 @@ pub enum Equation {
     FEMPointLoad,
     /// FEM for partial uniform load (numerical)
     FEMPartialUniform,
+    /// FEM for trapezoidal load (Gauss quadrature)
+    FEMTrapezoidal,
+    /// Joint balancing by distribution and carry-over (Hardy Cross)
+    MomentDistribution,
 
     // -------------------------------------------------------------------------
     // Fixed-Fixed Beam
@@ -569,6 +579,27 @@ impl Equation {
                 source_function: "partial_uniform_shear",
             },
 
+            // Trapezoidal Load
+            Equation::TrapezoidalReactions => EquationMetadata {
+                name: "Trapezoidal Load Reactions",
+                description: "Reactions for load varying linearly from w1 at position a to w2 at b",
+                formula_typst: r#"$R_2 = (W a + c^2 (w_1 + 2 w_2) / 6) / L$, $R_1 = W - R_2$ where $W = (w_1 + w_2) c / 2$, $c = b - a$"#,
+                formula_plain: "R2 = (Wa + c^2(w1 + 2w2)/6)/L, R1 = W - R2 where W = (w1+w2)c/2, c = b-a",
+                reference: CodeReference::Mechanics,
+                variables: vec![
+                    Variable::new("w_1", "Load intensity at a", "plf"),
+                    Variable::new("w_2", "Load intensity at b", "plf"),
+                    Variable::new("W", "Total load", "lb"),
+                    Variable::new("c", "Loaded length", "ft"),
+                    Variable::new("a", "Load start position", "ft"),
+                    Variable::new("b", "Load end position", "ft"),
+                ],
+                assumptions: vec!["Simply-supported", "Load varies linearly between a and b"],
+                category: EquationCategory::Reactions,
+                source_module: "equations/beam.rs",
+                source_function: "trapezoidal_reactions",
+            },
+
             // Fixed-End Moments
             Equation::FEMUniformFull => EquationMetadata {
                 name: "FEM for Uniform Load",
@@ -618,6 +649,38 @@ impl Equation {
                 source_function: "fem_partial_uniform",
             },
 
+            Equation::FEMTrapezoidal => EquationMetadata {
+                name: "FEM for Trapezoidal Load",
+                description: "Fixed-end moments for linearly varying load (Gauss quadrature)",
+                formula_typst: r#"$"FEM" = integral_a^b w(x) "FEM"_P (x) dif x$ (3-point Gauss)"#,
+                formula_plain: "FEM = integral of w(x) * FEM_P(x) from a to b (3-point Gauss)",
+                reference: CodeReference::Mechanics,
+                variables: vec![
+                    Variable::new("FEM", "Fixed-end moment", "ft-lb"),
+                    Variable::new("w(x)", "Load intensity at x", "plf"),
+                ],
+                assumptions: vec!["Integrates point load FEMs", "Exact for linearly varying load"],
+                category: EquationCategory::FixedEndMoments,
+                source_module: "equations/beam.rs",
+                source_function: "fem_trapezoidal",
+            },
+
+            Equation::MomentDistribution => EquationMetadata {
+                name: "Moment Distribution",
+                description: "Support moments of an indeterminate beam by balancing each joint and carrying over",
+                formula_typst: r#"$"DF"_i = K_i / sum K$, $K = 4 E I / L$ ($3 E I / L$ to a pinned exterior end), carry-over $= 1/2$"#,
+                formula_plain: "DF_i = K_i / sum(K), K = 4EI/L (3EI/L to a pinned exterior end), carry-over = 1/2",
+                reference: CodeReference::Hibbeler { edition: 10, chapter: 11 },
+                variables: vec![
+                    Variable::new("DF", "Distribution factor", "-"),
+                    Variable::new("K", "Member stiffness", "lb-in"),
+                ],
+                assumptions: vec!["Starts from the fixed-end moments", "Iterated until each joint is within 0.1 ft-lb of balance"],
+                category: EquationCategory::FixedEndMoments,
+                source_module: "calculations/moment_distribution.rs",
+                source_function: "analyze_moment_distribution",
+            },
+
             // Fixed-Fixed Beam
             Equation::FixedFixedUniformEndMoments => EquationMetadata {
                 name: "Fixed-Fixed End Moments",
@@ -988,10 +1051,14 @@ pub static ALL_EQUATIONS: &[Equation] = &[
     Equation::PartialUniformReactions,
     Equation::PartialUniformMoment,
     Equation::PartialUniformShear,
+    // Trapezoidal load
+    Equation::TrapezoidalReactions,
     // Fixed-end moments
     Equation::FEMUniformFull,
     Equation::FEMPointLoad,
     Equation::FEMPartialUniform,
+    Equation::FEMTrapezoidal,
+    Equation::MomentDistribution,
     // Fixed-fixed
     Equation::FixedFixedUniformEndMoments,
     Equation::FixedFixedUniformMaxPositiveMoment,
@@ -1621,7 +1688,7 @@ mod tests {
 
         // Should contain statistics
         assert!(markdown.contains("## Statistics"), "Missing statistics");
-        assert!(markdown.contains("**Total Equations:** 35"), "Wrong equation count");
+        assert!(markdown.contains("**Total Equations:** 38"), "Wrong equation count");
         assert!(markdown.contains("**Categories:** 8"), "Wrong category count");
 
         // Should contain audit instructions
@@ -160,6 +160,56 @@ impl CalcError {
     }
 }
 
+/// How prominently a [`CalcWarning`] should be shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WarningSeverity {
+    /// Worth knowing; no action expected
+    Info,
+    /// Worth reviewing before relying on the result
+    Caution,
+}
+
+/// Non-fatal advisory attached to a successful result.
+///
+/// Unlike [`CalcError`], a warning never stops a calculation. `code` is a
+/// short stable string for programmatic handling, like
+/// [`CalcError::error_code`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalcWarning {
+    /// Short code (e.g., "LONG_SPAN")
+    pub code: String,
+    /// Human-readable explanation
+    pub message: String,
+    /// How prominently to show it
+    pub severity: WarningSeverity,
+}
+
+impl CalcWarning {
+    /// Create an Info warning
+    pub fn info(code: impl Into<String>, message: impl Into<String>) -> Self {
+        CalcWarning {
+            code: code.into(),
+            message: message.into(),
+            severity: WarningSeverity::Info,
+        }
+    }
+
+    /// Create a Caution warning
+    pub fn caution(code: impl Into<String>, message: impl Into<String>) -> Self {
+        CalcWarning {
+            code: code.into(),
+            message: message.into(),
+            severity: WarningSeverity::Caution,
+        }
+    }
+}
+
+impl std::fmt::Display for CalcWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +222,15 @@ mod tests {
         assert_eq!(error, roundtrip);
     }
 
+    #[test]
+    fn test_warning_serialization() {
+        let warning = CalcWarning::caution("LONG_SPAN", "Span 1 is 52.0 ft");
+        let json = serde_json::to_string(&warning).unwrap();
+        assert!(json.contains("\"severity\":\"Caution\""));
+        let roundtrip: CalcWarning = serde_json::from_str(&json).unwrap();
+        assert_eq!(warning, roundtrip);
+    }
+
     #[test]
     fn test_error_codes() {
         assert_eq!(CalcError::missing_field("test").error_code(), "MISSING_FIELD");
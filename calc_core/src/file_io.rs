@@ -4,11 +4,15 @@
 //! - **Atomic saves**: Write to .tmp, verify, rename to prevent corruption
 //! - **File locking**: Prevent concurrent edits on shared drives (native only)
 //! - **Version validation**: Ensure schema compatibility
+//! - **Migration**: Upgrade documents written by older versions on load
+//! - **CSV export**: Beam loads and span results for spreadsheet cross-checks
+//! - **Project diff**: Items added, removed, or changed between two copies of a job
 //!
 //! ## File Format
 //!
 //! Projects are saved as `.stf` (Stratify) files containing JSON.
 //! Lock files use `.stf.lock` extension with metadata about who holds the lock.
+//! The top-level `schema_version` records the document layout; see [`migrate`].
 //!
 //! ## Platform Notes
 //!
@@ -35,6 +39,7 @@
 //! drop(lock);
 //! ```
 
+use std::collections::BTreeSet;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::Path;
@@ -43,9 +48,15 @@ use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+use crate::calculations::continuous_beam::{ContinuousBeamInput, ContinuousBeamResult};
+use crate::calculations::CalculationItem;
 use crate::errors::{CalcError, CalcResult};
-use crate::project::{Project, SCHEMA_VERSION};
+use crate::loads::{CodeEdition, LoadDistribution};
+use crate::nds_factors::AdjustmentFactors;
+use crate::project::{GlobalSettings, Project, FILE_SCHEMA_VERSION, SCHEMA_VERSION};
+use crate::section_deductions::SectionDeductions;
 
 // fs2 is only available on native platforms
 #[cfg(not(target_arch = "wasm32"))]
@@ -421,7 +432,7 @@ pub fn save_project(project: &Project, path: &Path) -> CalcResult<()> {
 ///
 /// # Returns
 ///
-/// * `Ok(Project)` - Successfully loaded project
+/// * `Ok(Project)` - Successfully loaded project, migrated to the current layout
 /// * `Err(CalcError::VersionMismatch)` - File version is incompatible
 /// * `Err(CalcError::SerializationError)` - Invalid JSON
 /// * `Err(CalcError::FileError)` - I/O error
@@ -442,23 +453,114 @@ pub fn load_project(path: &Path) -> CalcResult<Project> {
         CalcError::file_error("open", path.display().to_string(), e.to_string())
     })?;
 
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).map_err(|e| {
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents).map_err(|e| {
         CalcError::file_error("read", path.display().to_string(), e.to_string())
     })?;
 
-    // Parse JSON
-    let project: Project =
-        serde_json::from_str(&contents).map_err(|e| CalcError::SerializationError {
-            reason: format!("Invalid JSON in {}: {}", path.display(), e),
-        })?;
+    parse_project(&contents).map_err(|e| match e {
+        CalcError::SerializationError { reason } => CalcError::SerializationError {
+            reason: format!("Invalid JSON in {}: {}", path.display(), reason),
+        },
+        other => other,
+    })
+}
+
+/// Parse a project from `.stf` file contents.
+///
+/// The document is migrated to [`FILE_SCHEMA_VERSION`] before it is
+/// deserialized, and its schema version is then validated.
+pub fn parse_project(contents: &[u8]) -> CalcResult<Project> {
+    let document: serde_json::Value =
+        serde_json::from_slice(contents).map_err(|e| CalcError::SerializationError { reason: e.to_string() })?;
+
+    let project: Project = serde_json::from_value(migrate(document)?)
+        .map_err(|e| CalcError::SerializationError { reason: e.to_string() })?;
 
-    // Validate schema version
     validate_version(&project.meta.version)?;
 
     Ok(project)
 }
 
+/// Upgrade a serialized project to [`FILE_SCHEMA_VERSION`].
+///
+/// A document without a `schema_version` is version 1. Each step fills in
+/// what its version added:
+///
+/// - 1 → 2: missing `settings` fields, and each beam's
+///   `adjustment_factors` and `section_deductions`, get their defaults
+/// - 2 → 3: a missing `code_edition` in `settings` and on each beam is set
+///   to [`CodeEdition::legacy`], the edition these files were designed to.
+///   This runs first so the 1 → 2 defaults do not fill in the current
+///   edition.
+///
+/// A version newer than this build returns `CalcError::VersionMismatch`.
+pub fn migrate(mut document: serde_json::Value) -> CalcResult<serde_json::Value> {
+    let version = document
+        .get("schema_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(1);
+
+    if version > u64::from(FILE_SCHEMA_VERSION) {
+        return Err(CalcError::VersionMismatch {
+            file_version: format!("schema {}", version),
+            expected_version: format!("schema {} or older", FILE_SCHEMA_VERSION),
+        });
+    }
+
+    let Some(root) = document.as_object_mut() else {
+        return Err(CalcError::SerializationError {
+            reason: "Project file must contain a JSON object".to_string(),
+        });
+    };
+
+    if version < 3 {
+        let legacy = serde_json::to_value(CodeEdition::legacy()).unwrap_or_default();
+        let settings = root.entry("settings").or_insert_with(|| serde_json::json!({}));
+        if let Some(settings) = settings.as_object_mut() {
+            settings.entry("code_edition").or_insert_with(|| legacy.clone());
+        }
+        if let Some(items) = root.get_mut("items").and_then(serde_json::Value::as_object_mut) {
+            for item in items.values_mut().filter(|item| item["type"] == "Beam") {
+                if let Some(beam) = item.as_object_mut() {
+                    beam.entry("code_edition").or_insert_with(|| legacy.clone());
+                }
+            }
+        }
+    }
+
+    if version < 2 {
+        let settings = root.entry("settings").or_insert_with(|| serde_json::json!({}));
+        fill_defaults(settings, &GlobalSettings::default());
+
+        if let Some(items) = root.get_mut("items").and_then(serde_json::Value::as_object_mut) {
+            for item in items.values_mut().filter(|item| item["type"] == "Beam") {
+                if let Some(beam) = item.as_object_mut() {
+                    beam.entry("adjustment_factors")
+                        .or_insert_with(|| serde_json::to_value(AdjustmentFactors::default()).unwrap_or_default());
+                    beam.entry("section_deductions")
+                        .or_insert_with(|| serde_json::to_value(SectionDeductions::default()).unwrap_or_default());
+                }
+            }
+        }
+    }
+
+    root.insert("schema_version".to_string(), FILE_SCHEMA_VERSION.into());
+    Ok(document)
+}
+
+/// Insert every field of `defaults` that `target` (a JSON object) lacks
+fn fill_defaults(target: &mut serde_json::Value, defaults: &impl Serialize) {
+    let (Some(target), Ok(serde_json::Value::Object(defaults))) =
+        (target.as_object_mut(), serde_json::to_value(defaults))
+    else {
+        return;
+    };
+    for (key, value) in defaults {
+        target.entry(key).or_insert(value);
+    }
+}
+
 /// Load a project, returning whether it's read-only due to a lock.
 ///
 /// # Returns
@@ -513,6 +615,235 @@ fn validate_version(file_version: &str) -> CalcResult<()> {
     Ok(())
 }
 
+/// Export a beam's loads and per-span results as CSV.
+///
+/// Writes a header naming the beam and governing combination, then a
+/// `Loads` section and a `Span Results` section separated by a blank line,
+/// each with its own column header row. Magnitudes are effective values
+/// (tributary width applied) in plf, lb, or ft-lb as given by the `unit`
+/// column; positions are in ft from the left end. Fields containing commas,
+/// quotes, or line breaks are quoted.
+///
+/// # Example
+///
+/// ```rust
+/// use calc_core::calculations::continuous_beam::{calculate_continuous, ContinuousBeamInput};
+/// use calc_core::file_io::export_beam_csv;
+/// use calc_core::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+/// use calc_core::materials::Material;
+///
+/// let loads = EnhancedLoadCase::new("Floor").with_load(DiscreteLoad::uniform(LoadType::Live, 40.0));
+/// let beam = ContinuousBeamInput::simple_span("B-1", 12.0, 1.5, 9.25, Material::default(), loads);
+/// let result = calculate_continuous(&beam, DesignMethod::Asd)?;
+///
+/// let mut csv = Vec::new();
+/// export_beam_csv(&beam, &result, &mut csv)?;
+/// assert!(String::from_utf8(csv).unwrap().starts_with("Beam,B-1"));
+/// # Ok::<(), calc_core::errors::CalcError>(())
+/// ```
+pub fn export_beam_csv(
+    beam: &ContinuousBeamInput,
+    result: &ContinuousBeamResult,
+    mut writer: impl Write,
+) -> CalcResult<()> {
+    let mut lines = vec![
+        format!("Beam,{}", csv_field(&beam.label)),
+        format!("Combination,{}", csv_field(&result.governing_label())),
+        String::new(),
+        "Loads".to_string(),
+        "type,distribution,magnitude,end_magnitude,unit,start_ft,end_ft,note".to_string(),
+    ];
+
+    for load in &beam.load_case.loads {
+        let magnitude = load.effective_magnitude();
+        let (magnitude, end_magnitude, unit, start, end) = match load.distribution {
+            LoadDistribution::Point { position_ft } | LoadDistribution::HorizontalPoint { position_ft } => {
+                (magnitude, None, "lb", Some(position_ft), None)
+            }
+            LoadDistribution::Moment { position_ft } => (magnitude, None, "ft-lb", Some(position_ft), None),
+            LoadDistribution::UniformFull => (magnitude, None, "plf", None, None),
+            LoadDistribution::UniformPartial { start_ft, end_ft } => {
+                (magnitude, None, "plf", Some(start_ft), Some(end_ft))
+            }
+            LoadDistribution::Trapezoidal {
+                start_ft,
+                end_ft,
+                start_magnitude,
+                end_magnitude,
+            } => (start_magnitude, Some(end_magnitude), "plf", Some(start_ft), Some(end_ft)),
+        };
+        let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+        lines.push(format!(
+            "{},{},{},{},{},{},{},{}",
+            load.load_type.code(),
+            csv_field(load.distribution.display_name()),
+            magnitude,
+            optional(end_magnitude),
+            unit,
+            optional(start),
+            optional(end),
+            csv_field(&load.note),
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("Span Results".to_string());
+    lines.push(
+        "span,length_ft,max_positive_moment_ftlb,max_negative_moment_ftlb,max_shear_lb,max_deflection_in,\
+         bending_unity,shear_unity,deflection_unity"
+            .to_string(),
+    );
+    for (span, r) in beam.spans.iter().zip(&result.span_results) {
+        lines.push(format!(
+            "{},{},{:.1},{:.1},{:.1},{:.4},{:.3},{:.3},{:.3}",
+            r.span_index,
+            span.length_ft,
+            r.max_positive_moment_ftlb,
+            r.max_negative_moment_ftlb,
+            r.max_shear_lb,
+            r.max_deflection_in,
+            r.bending_unity,
+            r.shear_unity,
+            r.deflection_unity,
+        ));
+    }
+
+    let mut csv = lines.join("\n");
+    csv.push('\n');
+    writer
+        .write_all(csv.as_bytes())
+        .map_err(|e| CalcError::file_error("write CSV", beam.label.clone(), e.to_string()))
+}
+
+/// Quote a CSV field if it contains a comma, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// ============================================================================
+// Project Diff (Cross-platform)
+// ============================================================================
+
+/// Items that differ between two projects, matched by UUID
+///
+/// Each list is sorted by UUID so the serialized diff is stable.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectDiff {
+    /// Items only in the second project
+    pub added: Vec<ItemSummary>,
+
+    /// Items only in the first project
+    pub removed: Vec<ItemSummary>,
+
+    /// Items in both projects whose inputs differ
+    pub modified: Vec<ModifiedItem>,
+}
+
+impl ProjectDiff {
+    /// Whether the two projects hold the same items
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// An item added to or removed from a project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSummary {
+    /// Item UUID
+    pub id: Uuid,
+
+    /// Calculation type (e.g., "Beam")
+    pub calc_type: String,
+
+    /// Item label
+    pub label: String,
+}
+
+/// An item present in both projects with different inputs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModifiedItem {
+    /// Item UUID
+    pub id: Uuid,
+
+    /// Calculation type in the second project
+    pub calc_type: String,
+
+    /// Label in the second project
+    pub label: String,
+
+    /// Serialized input fields that differ (e.g., "spans", "load_case",
+    /// "adjustment_factors"), or "type" when the calculation type changed
+    pub changed_fields: Vec<String>,
+}
+
+/// Compare the items of project `a` against those of project `b`
+///
+/// Read-only: reports what changed from `a` to `b` without merging.
+/// Project metadata and settings are not compared.
+///
+/// # Example
+///
+/// ```rust
+/// use calc_core::calculations::CalculationItem;
+/// use calc_core::file_io::diff_projects;
+/// use calc_core::project::{templates, Project};
+///
+/// let a = Project::new("Engineer", "25-001", "Client");
+/// let mut b = a.clone();
+/// b.add_item(CalculationItem::Beam(templates::header()));
+///
+/// let diff = diff_projects(&a, &b);
+/// assert_eq!(diff.added.len(), 1);
+/// assert!(diff.removed.is_empty() && diff.modified.is_empty());
+/// ```
+pub fn diff_projects(a: &Project, b: &Project) -> ProjectDiff {
+    let ids: BTreeSet<&Uuid> = a.items.keys().chain(b.items.keys()).collect();
+    let summary = |id: Uuid, item: &CalculationItem| ItemSummary {
+        id,
+        calc_type: item.calc_type().to_string(),
+        label: item.label().to_string(),
+    };
+
+    let mut diff = ProjectDiff::default();
+    for &id in ids {
+        match (a.items.get(&id), b.items.get(&id)) {
+            (None, Some(item)) => diff.added.push(summary(id, item)),
+            (Some(item), None) => diff.removed.push(summary(id, item)),
+            (Some(old), Some(new)) => {
+                let changed_fields = changed_fields(old, new);
+                if !changed_fields.is_empty() {
+                    diff.modified.push(ModifiedItem {
+                        id,
+                        calc_type: new.calc_type().to_string(),
+                        label: new.label().to_string(),
+                        changed_fields,
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    diff
+}
+
+/// Top-level serialized fields of two items that differ, sorted by name
+fn changed_fields(a: &CalculationItem, b: &CalculationItem) -> Vec<String> {
+    let to_map = |item: &CalculationItem| match serde_json::to_value(item) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    let (a, b) = (to_map(a), to_map(b));
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    keys.into_iter()
+        .filter(|key| a.get(*key) != b.get(*key))
+        .cloned()
+        .collect()
+}
+
 // ============================================================================
 // Tests (Native only - require filesystem)
 // ============================================================================
@@ -632,4 +963,163 @@ mod tests {
         // Clean up
         let _ = fs::remove_file(&path);
     }
+
+    #[test]
+    fn test_export_beam_csv() {
+        use crate::calculations::continuous_beam::calculate_continuous;
+        use crate::loads::{CodeEdition, DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+        use crate::materials::Material;
+
+        let loads = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 10.0).with_tributary_width(2.0))
+            .with_load(DiscreteLoad::partial_uniform(LoadType::Live, 80.0, 2.0, 8.0))
+            .with_load(DiscreteLoad::point(LoadType::Snow, 500.0, 6.0).with_note("Post, from \"roof\""))
+            .without_self_weight();
+        let beam = ContinuousBeamInput::simple_span("B-1, Level 2", 12.0, 1.5, 9.25, Material::default(), loads)
+            .with_code_edition(CodeEdition::Asce7_16);
+        let result = calculate_continuous(&beam, DesignMethod::Asd).unwrap();
+
+        let mut csv = Vec::new();
+        export_beam_csv(&beam, &result, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(
+            csv,
+            "Beam,\"B-1, Level 2\"\n\
+             Combination,ASD-4b\n\
+             \n\
+             Loads\n\
+             type,distribution,magnitude,end_magnitude,unit,start_ft,end_ft,note\n\
+             D,Uniform,20,,plf,,,\n\
+             L,Partial Uniform,80,,plf,2,8,\n\
+             S,Point,500,,lb,6,,\"Post, from \"\"roof\"\"\"\n\
+             \n\
+             Span Results\n\
+             span,length_ft,max_positive_moment_ftlb,max_negative_moment_ftlb,max_shear_lb,max_deflection_in,\
+             bending_unity,shear_unity,deflection_unity\n\
+             0,12,2265.0,0.0,517.5,0.3280,1.283,0.311,0.547\n"
+        );
+    }
+
+    #[test]
+    fn test_migrate_v1_project() {
+        // Written before schema_version, design_method, and the beam
+        // adjustment factors and section deductions existed
+        let v1 = r#"{
+            "meta": {
+                "version": "0.1.0",
+                "engineer": "Jane Engineer",
+                "job_id": "24-007",
+                "client": "ACME Corp",
+                "created": "2024-03-01T12:00:00Z",
+                "modified": "2024-03-01T12:00:00Z"
+            },
+            "settings": {
+                "code": "IBC2021",
+                "seismic_design_category": null,
+                "risk_category": "II",
+                "default_materials": { "wood": "DF-L No.2", "steel": "A992", "concrete_fc_psi": 3000 }
+            },
+            "items": {
+                "8f1c6a52-3b0e-4d2a-9a57-2f4d6f1b9e10": {
+                    "type": "Beam",
+                    "label": "B-1",
+                    "spans": [{
+                        "id": "0b5e7c1d-6a2f-4e8b-b1c3-9d4e5f6a7b8c",
+                        "length_ft": 12.0,
+                        "width_in": 1.5,
+                        "depth_in": 9.25,
+                        "material": { "type": "SawnLumber", "species": "DF-L", "grade": "No.2" }
+                    }],
+                    "supports": ["Pinned", "Roller"],
+                    "load_case": { "loads": [], "include_self_weight": true, "label": "Floor" }
+                }
+            }
+        }"#;
+
+        let path = temp_project_path("migrate_v1");
+        fs::write(&path, v1).unwrap();
+        let project = load_project(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(project.schema_version, FILE_SCHEMA_VERSION);
+        assert_eq!(project.settings.code, "IBC2021");
+        assert_eq!(project.settings.design_method, GlobalSettings::default().design_method);
+        let Some(crate::calculations::CalculationItem::Beam(beam)) = project.items.values().next() else {
+            panic!("expected the beam to load");
+        };
+        assert_eq!(beam.label, "B-1");
+        assert!(beam.validate().is_ok());
+        assert_eq!(project.settings.code_edition, CodeEdition::Asce7_16);
+        assert_eq!(beam.code_edition, CodeEdition::Asce7_16);
+
+        // Migrating a current document leaves it unchanged
+        let current = serde_json::to_value(&project).unwrap();
+        assert_eq!(migrate(current.clone()).unwrap(), current);
+    }
+
+    #[test]
+    fn test_migrate_v2_project_keeps_asce7_16() {
+        use crate::project::templates;
+
+        let mut project = Project::new("Test", "TEST", "Client");
+        let id = project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        let mut v2 = serde_json::to_value(&project).unwrap();
+        v2["schema_version"] = 2.into();
+        v2["settings"].as_object_mut().unwrap().remove("code_edition");
+        v2["items"][id.to_string()].as_object_mut().unwrap().remove("code_edition");
+
+        let loaded = parse_project(v2.to_string().as_bytes()).unwrap();
+        assert_eq!(loaded.schema_version, FILE_SCHEMA_VERSION);
+        assert_eq!(loaded.settings.code_edition, CodeEdition::Asce7_16);
+        let Some(CalculationItem::Beam(beam)) = loaded.get_item(&id) else {
+            panic!("expected the beam to load");
+        };
+        assert_eq!(beam.code_edition, CodeEdition::Asce7_16);
+
+        // An edition the file already records is kept
+        v2["settings"]["code_edition"] = serde_json::to_value(CodeEdition::Asce7_22).unwrap();
+        let loaded = parse_project(v2.to_string().as_bytes()).unwrap();
+        assert_eq!(loaded.settings.code_edition, CodeEdition::Asce7_22);
+    }
+
+    #[test]
+    fn test_diff_projects() {
+        use crate::project::templates;
+
+        let mut a = Project::new("Test", "TEST", "Client");
+        let changed = a.add_item(CalculationItem::Beam(templates::floor_beam()));
+        a.add_item(CalculationItem::Beam(templates::header()));
+        assert!(diff_projects(&a, &a.clone()).is_empty());
+
+        let mut b = a.clone();
+        if let Some(CalculationItem::Beam(beam)) = b.get_item_mut(&changed) {
+            beam.spans[0].length_ft += 2.0;
+        }
+        let added = b.add_item(CalculationItem::Beam(templates::deck_joist()));
+
+        let diff = diff_projects(&a, &b);
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].id, added);
+        assert_eq!(diff.added[0].calc_type, "Beam");
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(diff.modified[0].id, changed);
+        assert_eq!(diff.modified[0].changed_fields, vec!["spans"]);
+
+        // Reversed, the added beam is removed
+        let reverse = diff_projects(&b, &a);
+        assert_eq!(reverse.removed[0].id, added);
+
+        let json = serde_json::to_string(&diff).unwrap();
+        assert_eq!(serde_json::from_str::<ProjectDiff>(&json).unwrap(), diff);
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_schema() {
+        let mut document = serde_json::to_value(Project::new("Test", "TEST", "Client")).unwrap();
+        document["schema_version"] = (FILE_SCHEMA_VERSION + 1).into();
+
+        let err = parse_project(document.to_string().as_bytes()).unwrap_err();
+        assert!(matches!(err, CalcError::VersionMismatch { .. }));
+    }
 }
@@ -35,6 +35,8 @@
 //! - [`errors`] - Structured error types
 //! - [`file_io`] - File operations with atomic saves and locking
 //! - [`pdf`] - PDF report generation with Typst
+//! - [`record`] - Archival records pairing an input with its computed result
+//! - `schema` - JSON Schema for input types (`json-schema` feature)
 
 pub mod calculations;
 pub mod equations;
@@ -46,11 +48,14 @@ pub mod materials;
 pub mod nds_factors;
 pub mod pdf;
 pub mod project;
+pub mod record;
+#[cfg(feature = "json-schema")]
+pub mod schema;
 pub mod section_deductions;
 pub mod units;
 
 // Re-export commonly used types at crate root for convenience
-pub use errors::{CalcError, CalcResult};
+pub use errors::{CalcError, CalcResult, CalcWarning};
 pub use file_io::{save_project, load_project, FileLock};
 pub use loads::{LoadType, LoadCase, LoadCombination, DesignMethod};
 pub use materials::Material;
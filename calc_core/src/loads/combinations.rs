@@ -1,7 +1,8 @@
-//! ASCE 7-22 Load Combinations
+//! ASCE 7 Load Combinations
 //!
-//! This module provides standard load combinations per ASCE 7-22 for both
-//! Allowable Stress Design (ASD) and Load and Resistance Factor Design (LRFD).
+//! This module provides standard load combinations per ASCE 7-22 (default) or
+//! ASCE 7-16 for both Allowable Stress Design (ASD) and Load and Resistance
+//! Factor Design (LRFD). See [`CodeEdition`] for how the editions differ.
 //!
 //! ## Wind Load Sign Convention
 //!
@@ -16,7 +17,112 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use super::load_types::LoadType;
-use super::LoadCase;
+use super::{DesignMethod, LoadCase};
+use crate::errors::{CalcError, CalcResult};
+
+/// ASCE 7 edition that sets the load combinations
+///
+/// ASCE 7-22 moved to strength-level ground snow loads, so its combinations
+/// carry smaller snow factors: 0.7S in place of S (ASD), 1.0S in place of
+/// 1.6S (LRFD), and 0.15S in place of the 0.5S and 0.2S companion terms
+/// (LRFD). Wind (0.6W ASD, 1.0W LRFD), rain and all other factors are the
+/// same in both editions.
+///
+/// Defaults to 7-22. Project files saved before the edition was selectable
+/// are migrated to 7-16 (see [`CodeEdition::legacy`]), the combinations
+/// they were designed with, so their snow demand does not drop on load.
+///
+/// # Example
+/// ```
+/// use calc_core::loads::{asce7_asd_combinations, CodeEdition, LoadType};
+///
+/// let snow = |edition| {
+///     asce7_asd_combinations(edition)
+///         .into_iter()
+///         .find(|c| c.name == "ASD-3b")
+///         .unwrap()
+///         .get_factor(LoadType::Snow)
+/// };
+/// assert_eq!(snow(CodeEdition::Asce7_16), 1.0);
+/// assert_eq!(snow(CodeEdition::Asce7_22), 0.7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum CodeEdition {
+    /// ASCE/SEI 7-16
+    #[serde(rename = "ASCE7-16")]
+    Asce7_16,
+    /// ASCE/SEI 7-22
+    #[default]
+    #[serde(rename = "ASCE7-22")]
+    Asce7_22,
+}
+
+impl CodeEdition {
+    /// All supported editions
+    pub const ALL: [CodeEdition; 2] = [CodeEdition::Asce7_16, CodeEdition::Asce7_22];
+
+    /// Edition of project files saved before it was selectable (7-16)
+    ///
+    /// Set by [`crate::file_io::migrate`] on files older than schema 3.
+    pub fn legacy() -> Self {
+        CodeEdition::Asce7_16
+    }
+
+    /// Human-readable name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CodeEdition::Asce7_16 => "ASCE 7-16",
+            CodeEdition::Asce7_22 => "ASCE 7-22",
+        }
+    }
+
+    /// Snow factor and equation term when snow is the principal variable load (ASD)
+    fn asd_snow(&self) -> (f64, &'static str) {
+        match self {
+            CodeEdition::Asce7_16 => (1.0, "S"),
+            CodeEdition::Asce7_22 => (0.7, "0.7S"),
+        }
+    }
+
+    /// Snow factor and equation term alongside 0.75L (ASD)
+    fn asd_companion_snow(&self) -> (f64, &'static str) {
+        match self {
+            CodeEdition::Asce7_16 => (0.75, "0.75S"),
+            CodeEdition::Asce7_22 => (0.525, "0.525S"),
+        }
+    }
+
+    /// Snow factor and equation term when snow is the principal variable load (LRFD)
+    fn lrfd_snow(&self) -> (f64, &'static str) {
+        match self {
+            CodeEdition::Asce7_16 => (1.6, "1.6S"),
+            CodeEdition::Asce7_22 => (1.0, "1.0S"),
+        }
+    }
+
+    /// Snow factor and equation term as a companion to L or W (LRFD)
+    fn lrfd_companion_snow(&self) -> (f64, &'static str) {
+        match self {
+            CodeEdition::Asce7_16 => (0.5, "0.5S"),
+            CodeEdition::Asce7_22 => (0.15, "0.15S"),
+        }
+    }
+
+    /// Snow factor and equation term with seismic (LRFD)
+    fn lrfd_seismic_snow(&self) -> (f64, &'static str) {
+        match self {
+            CodeEdition::Asce7_16 => (0.2, "0.2S"),
+            CodeEdition::Asce7_22 => (0.15, "0.15S"),
+        }
+    }
+}
+
+impl std::fmt::Display for CodeEdition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
 
 /// A load combination with factors for each load type
 ///
@@ -26,7 +132,7 @@ use super::LoadCase;
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::{LoadCombination, LoadCase, LoadType};
+/// use calc_core::loads::{DesignMethod, LoadCombination, LoadCase, LoadType};
 /// use std::collections::HashMap;
 ///
 /// let combo = LoadCombination {
@@ -36,6 +142,7 @@ use super::LoadCase;
 ///         (LoadType::Dead, 1.0),
 ///         (LoadType::Live, 1.0),
 ///     ].into_iter().collect(),
+///     method: DesignMethod::Asd,
 /// };
 ///
 /// let case = LoadCase::new("Floor")
@@ -45,6 +152,7 @@ use super::LoadCase;
 /// assert_eq!(combo.apply(&case), 70.0);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LoadCombination {
     /// Combination identifier (e.g., "ASD-1", "LRFD-2a")
     pub name: String,
@@ -54,6 +162,13 @@ pub struct LoadCombination {
 
     /// Load factors keyed by load type
     pub factors: HashMap<LoadType, f64>,
+
+    /// Design method the factors are written for
+    ///
+    /// A custom combination is only analyzed under its own method, so
+    /// factored LRFD loads never reach ASD stress or service checks.
+    #[serde(default)]
+    pub method: DesignMethod,
 }
 
 impl LoadCombination {
@@ -63,9 +178,16 @@ impl LoadCombination {
             name: name.into(),
             equation: equation.into(),
             factors: HashMap::new(),
+            method: DesignMethod::Asd,
         }
     }
 
+    /// Set the design method the factors are written for (builder pattern)
+    pub fn for_method(mut self, method: DesignMethod) -> Self {
+        self.method = method;
+        self
+    }
+
     /// Add a load factor (builder pattern)
     pub fn with_factor(mut self, load_type: LoadType, factor: f64) -> Self {
         self.factors.insert(load_type, factor);
@@ -89,23 +211,26 @@ impl LoadCombination {
     }
 }
 
-/// Generate ASCE 7-22 ASD load combinations (Section 2.4.1)
+/// Generate ASCE 7 ASD load combinations (Section 2.4.1)
 ///
-/// Returns all standard ASD combinations including alternates for roof loads.
+/// Returns all standard ASD combinations for `edition`, including alternates
+/// for roof loads.
 /// The combinations account for:
 /// - Basic gravity combinations (D, D+L, D+Lr/S/R)
 /// - Combined gravity and lateral (D+0.75L+0.75Lr/S/R)
 /// - Lateral load combinations (D+W, D+E)
-/// - Uplift/overturning combinations (0.6D+W, 0.6D+E)
+/// - Uplift/overturning combinations (0.6D + 0.6W, 0.6D - 0.6W, 0.6D + 0.7E)
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::asce7_asd_combinations;
+/// use calc_core::loads::{asce7_asd_combinations, CodeEdition};
 ///
-/// let combos = asce7_asd_combinations();
+/// let combos = asce7_asd_combinations(CodeEdition::default());
 /// assert!(combos.len() >= 9);
 /// ```
-pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
+pub fn asce7_asd_combinations(edition: CodeEdition) -> Vec<LoadCombination> {
+    let (s, s_eq) = edition.asd_snow();
+    let (s_c, s_c_eq) = edition.asd_companion_snow();
     vec![
         // 1. D
         LoadCombination::new("ASD-1", "D")
@@ -121,10 +246,10 @@ pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Dead, 1.0)
             .with_factor(LoadType::LiveRoof, 1.0),
 
-        // 3b. D + S
-        LoadCombination::new("ASD-3b", "D + S")
+        // 3b. D + S (0.7S in 7-22)
+        LoadCombination::new("ASD-3b", format!("D + {}", s_eq))
             .with_factor(LoadType::Dead, 1.0)
-            .with_factor(LoadType::Snow, 1.0),
+            .with_factor(LoadType::Snow, s),
 
         // 3c. D + R
         LoadCombination::new("ASD-3c", "D + R")
@@ -137,11 +262,11 @@ pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Live, 0.75)
             .with_factor(LoadType::LiveRoof, 0.75),
 
-        // 4b. D + 0.75L + 0.75S
-        LoadCombination::new("ASD-4b", "D + 0.75L + 0.75S")
+        // 4b. D + 0.75L + 0.75S (0.525S in 7-22)
+        LoadCombination::new("ASD-4b", format!("D + 0.75L + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.0)
             .with_factor(LoadType::Live, 0.75)
-            .with_factor(LoadType::Snow, 0.75),
+            .with_factor(LoadType::Snow, s_c),
 
         // 4c. D + 0.75L + 0.75R
         LoadCombination::new("ASD-4c", "D + 0.75L + 0.75R")
@@ -179,18 +304,18 @@ pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::LiveRoof, 0.75),
 
         // 6b. D + 0.75L + 0.75(0.6W) + 0.75S (downward wind)
-        LoadCombination::new("ASD-6b", "D + 0.75L + 0.45W + 0.75S")
+        LoadCombination::new("ASD-6b", format!("D + 0.75L + 0.45W + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.0)
             .with_factor(LoadType::Live, 0.75)
             .with_factor(LoadType::Wind, 0.45)
-            .with_factor(LoadType::Snow, 0.75),
+            .with_factor(LoadType::Snow, s_c),
 
         // 6b'. D + 0.75L - 0.45W + 0.75S (wind uplift)
-        LoadCombination::new("ASD-6b'", "D + 0.75L - 0.45W + 0.75S")
+        LoadCombination::new("ASD-6b'", format!("D + 0.75L - 0.45W + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.0)
             .with_factor(LoadType::Live, 0.75)
             .with_factor(LoadType::Wind, -0.45)
-            .with_factor(LoadType::Snow, 0.75),
+            .with_factor(LoadType::Snow, s_c),
 
         // 6c. D + 0.75L + 0.75(0.6W) + 0.75R (downward wind)
         LoadCombination::new("ASD-6c", "D + 0.75L + 0.45W + 0.75R")
@@ -207,11 +332,11 @@ pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Rain, 0.75),
 
         // 7. D + 0.75L + 0.75(0.7E) + 0.75S
-        LoadCombination::new("ASD-7", "D + 0.75L + 0.525E + 0.75S")
+        LoadCombination::new("ASD-7", format!("D + 0.75L + 0.525E + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.0)
             .with_factor(LoadType::Live, 0.75)
             .with_factor(LoadType::Seismic, 0.525) // 0.75 * 0.7
-            .with_factor(LoadType::Snow, 0.75),
+            .with_factor(LoadType::Snow, s_c),
 
         // 8. 0.6D + 0.6W (downward wind)
         LoadCombination::new("ASD-8", "0.6D + 0.6W")
@@ -230,21 +355,24 @@ pub fn asce7_asd_combinations() -> Vec<LoadCombination> {
     ]
 }
 
-/// Generate ASCE 7-22 LRFD load combinations (Section 2.3.1)
+/// Generate ASCE 7 LRFD load combinations (Section 2.3.1)
 ///
-/// Returns all standard LRFD combinations including alternates.
+/// Returns all standard LRFD combinations for `edition`, including alternates.
 /// LRFD uses factored loads to account for uncertainty in both
 /// loads and resistance.
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::asce7_lrfd_combinations;
+/// use calc_core::loads::{asce7_lrfd_combinations, CodeEdition};
 ///
-/// let combos = asce7_lrfd_combinations();
+/// let combos = asce7_lrfd_combinations(CodeEdition::default());
 /// let lrfd1 = combos.iter().find(|c| c.name == "LRFD-1").unwrap();
 /// assert_eq!(lrfd1.get_factor(calc_core::loads::LoadType::Dead), 1.4);
 /// ```
-pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
+pub fn asce7_lrfd_combinations(edition: CodeEdition) -> Vec<LoadCombination> {
+    let (s, s_eq) = edition.lrfd_snow();
+    let (s_c, s_c_eq) = edition.lrfd_companion_snow();
+    let (s_e, s_e_eq) = edition.lrfd_seismic_snow();
     vec![
         // 1. 1.4D
         LoadCombination::new("LRFD-1", "1.4D")
@@ -256,11 +384,11 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Live, 1.6)
             .with_factor(LoadType::LiveRoof, 0.5),
 
-        // 2b. 1.2D + 1.6L + 0.5S
-        LoadCombination::new("LRFD-2b", "1.2D + 1.6L + 0.5S")
+        // 2b. 1.2D + 1.6L + 0.5S (0.15S in 7-22)
+        LoadCombination::new("LRFD-2b", format!("1.2D + 1.6L + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.2)
             .with_factor(LoadType::Live, 1.6)
-            .with_factor(LoadType::Snow, 0.5),
+            .with_factor(LoadType::Snow, s_c),
 
         // 2c. 1.2D + 1.6L + 0.5R
         LoadCombination::new("LRFD-2c", "1.2D + 1.6L + 0.5R")
@@ -286,22 +414,22 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::LiveRoof, 1.6)
             .with_factor(LoadType::Wind, -0.5),
 
-        // 3c. 1.2D + 1.6S + L
-        LoadCombination::new("LRFD-3c", "1.2D + 1.6S + L")
+        // 3c. 1.2D + 1.6S + L (1.0S in 7-22)
+        LoadCombination::new("LRFD-3c", format!("1.2D + {} + L", s_eq))
             .with_factor(LoadType::Dead, 1.2)
-            .with_factor(LoadType::Snow, 1.6)
+            .with_factor(LoadType::Snow, s)
             .with_factor(LoadType::Live, 1.0),
 
         // 3d. 1.2D + 1.6S + 0.5W (downward wind)
-        LoadCombination::new("LRFD-3d", "1.2D + 1.6S + 0.5W")
+        LoadCombination::new("LRFD-3d", format!("1.2D + {} + 0.5W", s_eq))
             .with_factor(LoadType::Dead, 1.2)
-            .with_factor(LoadType::Snow, 1.6)
+            .with_factor(LoadType::Snow, s)
             .with_factor(LoadType::Wind, 0.5),
 
         // 3d'. 1.2D + 1.6S - 0.5W (wind uplift)
-        LoadCombination::new("LRFD-3d'", "1.2D + 1.6S - 0.5W")
+        LoadCombination::new("LRFD-3d'", format!("1.2D + {} - 0.5W", s_eq))
             .with_factor(LoadType::Dead, 1.2)
-            .with_factor(LoadType::Snow, 1.6)
+            .with_factor(LoadType::Snow, s)
             .with_factor(LoadType::Wind, -0.5),
 
         // 3e. 1.2D + 1.6R + L
@@ -337,18 +465,18 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::LiveRoof, 0.5),
 
         // 4b. 1.2D + 1.0W + L + 0.5S (downward wind)
-        LoadCombination::new("LRFD-4b", "1.2D + 1.0W + L + 0.5S")
+        LoadCombination::new("LRFD-4b", format!("1.2D + 1.0W + L + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.2)
             .with_factor(LoadType::Wind, 1.0)
             .with_factor(LoadType::Live, 1.0)
-            .with_factor(LoadType::Snow, 0.5),
+            .with_factor(LoadType::Snow, s_c),
 
         // 4b'. 1.2D - 1.0W + L + 0.5S (wind uplift)
-        LoadCombination::new("LRFD-4b'", "1.2D - 1.0W + L + 0.5S")
+        LoadCombination::new("LRFD-4b'", format!("1.2D - 1.0W + L + {}", s_c_eq))
             .with_factor(LoadType::Dead, 1.2)
             .with_factor(LoadType::Wind, -1.0)
             .with_factor(LoadType::Live, 1.0)
-            .with_factor(LoadType::Snow, 0.5),
+            .with_factor(LoadType::Snow, s_c),
 
         // 4c. 1.2D + 1.0W + L + 0.5R (downward wind)
         LoadCombination::new("LRFD-4c", "1.2D + 1.0W + L + 0.5R")
@@ -364,12 +492,12 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Live, 1.0)
             .with_factor(LoadType::Rain, 0.5),
 
-        // 5. 1.2D + 1.0E + L + 0.2S
-        LoadCombination::new("LRFD-5", "1.2D + 1.0E + L + 0.2S")
+        // 5. 1.2D + 1.0E + L + 0.2S (0.15S in 7-22)
+        LoadCombination::new("LRFD-5", format!("1.2D + 1.0E + L + {}", s_e_eq))
             .with_factor(LoadType::Dead, 1.2)
             .with_factor(LoadType::Seismic, 1.0)
             .with_factor(LoadType::Live, 1.0)
-            .with_factor(LoadType::Snow, 0.2),
+            .with_factor(LoadType::Snow, s_e),
 
         // 6. 0.9D + 1.0W (downward wind)
         LoadCombination::new("LRFD-6", "0.9D + 1.0W")
@@ -386,6 +514,66 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
             .with_factor(LoadType::Dead, 0.9)
             .with_factor(LoadType::Seismic, 1.0),
     ]
+    .into_iter()
+    .map(|c| c.for_method(DesignMethod::Lrfd))
+    .collect()
+}
+
+/// Check a user-defined set of load combinations
+///
+/// At least one combination is required, and every factor must be finite.
+pub fn validate_combinations(combinations: &[LoadCombination]) -> CalcResult<()> {
+    if combinations.is_empty() {
+        return Err(CalcError::invalid_input(
+            "custom_combinations",
+            "empty",
+            "At least one load combination is required",
+        ));
+    }
+    for (i, combo) in combinations.iter().enumerate() {
+        if let Some((load_type, factor)) = combo.factors.iter().find(|(_, f)| !f.is_finite()) {
+            return Err(CalcError::invalid_input(
+                format!("custom_combinations[{}].factors.{}", i, load_type.code()),
+                factor.to_string(),
+                format!("Load factor in {} must be finite", combo.name),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Load combinations to analyze under `method`
+///
+/// The custom combinations tagged for `method` replace the built-in set of
+/// `edition`; when none are, the built-in set is used.
+pub fn select_combinations(
+    edition: CodeEdition,
+    custom: Option<&[LoadCombination]>,
+    method: DesignMethod,
+) -> Vec<LoadCombination> {
+    let custom: Vec<_> = custom.into_iter().flatten().filter(|c| c.method == method).cloned().collect();
+    if custom.is_empty() {
+        method.combinations(edition)
+    } else {
+        custom
+    }
+}
+
+/// Describe the combination set in use under `method`, for reports
+///
+/// Follows [`select_combinations`]: only the custom combinations tagged for
+/// `method` count, and the edition is named when none are.
+pub fn combination_set_label(
+    edition: CodeEdition,
+    custom: Option<&[LoadCombination]>,
+    method: DesignMethod,
+) -> String {
+    let custom: Vec<_> = custom.into_iter().flatten().filter(|c| c.method == method).collect();
+    match custom.as_slice() {
+        [] => edition.display_name().to_string(),
+        [combo] => format!("Custom ({})", combo.name),
+        combos => format!("Custom ({} combinations)", combos.len()),
+    }
 }
 
 /// Find the governing (maximum) load combination result
@@ -395,13 +583,13 @@ pub fn asce7_lrfd_combinations() -> Vec<LoadCombination> {
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::{LoadCase, LoadType, asce7_asd_combinations, find_governing_combination};
+/// use calc_core::loads::{CodeEdition, LoadCase, LoadType, asce7_asd_combinations, find_governing_combination};
 ///
 /// let case = LoadCase::new("Floor")
 ///     .with_load(LoadType::Dead, 20.0)
 ///     .with_load(LoadType::Live, 50.0);
 ///
-/// let (max_load, combo_name) = find_governing_combination(&case, &asce7_asd_combinations());
+/// let (max_load, combo_name) = find_governing_combination(&case, &asce7_asd_combinations(CodeEdition::default()));
 /// assert!(max_load >= 70.0); // At least D + L
 /// ```
 pub fn find_governing_combination(
@@ -423,13 +611,13 @@ pub fn find_governing_combination(
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::{LoadCase, LoadType, asce7_asd_combinations, find_minimum_combination};
+/// use calc_core::loads::{CodeEdition, LoadCase, LoadType, asce7_asd_combinations, find_minimum_combination};
 ///
 /// let case = LoadCase::new("Roof")
 ///     .with_load(LoadType::Dead, 10.0)
 ///     .with_load(LoadType::Wind, 30.0);  // Wind uplift
 ///
-/// let (min_load, combo_name) = find_minimum_combination(&case, &asce7_asd_combinations());
+/// let (min_load, combo_name) = find_minimum_combination(&case, &asce7_asd_combinations(CodeEdition::default()));
 /// // ASD-8': 0.6D - 0.6W = 6 - 18 = -12 plf (net uplift!)
 /// assert!(min_load < 0.0, "Expected uplift with high wind");
 /// ```
@@ -464,13 +652,13 @@ pub struct GoverningResults {
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::{LoadCase, LoadType, asce7_asd_combinations, find_governing_min_max};
+/// use calc_core::loads::{CodeEdition, LoadCase, LoadType, asce7_asd_combinations, find_governing_min_max};
 ///
 /// let case = LoadCase::new("Roof")
 ///     .with_load(LoadType::Dead, 15.0)
 ///     .with_load(LoadType::Wind, 25.0);
 ///
-/// let results = find_governing_min_max(&case, &asce7_asd_combinations());
+/// let results = find_governing_min_max(&case, &asce7_asd_combinations(CodeEdition::default()));
 /// println!("Max: {:.1} plf ({})", results.max_load, results.max_combo);
 /// println!("Min: {:.1} plf ({})", results.min_load, results.min_combo);
 /// ```
@@ -509,21 +697,21 @@ mod tests {
 
     #[test]
     fn test_asd_combination_count() {
-        let combos = asce7_asd_combinations();
+        let combos = asce7_asd_combinations(CodeEdition::default());
         // 21 combinations (16 basic + 5 wind uplift variants)
         assert_eq!(combos.len(), 21);
     }
 
     #[test]
     fn test_lrfd_combination_count() {
-        let combos = asce7_lrfd_combinations();
+        let combos = asce7_lrfd_combinations(CodeEdition::default());
         // 23 combinations (16 basic + 7 wind uplift variants)
         assert_eq!(combos.len(), 23);
     }
 
     #[test]
     fn test_lrfd_dead_only_factor() {
-        let combos = asce7_lrfd_combinations();
+        let combos = asce7_lrfd_combinations(CodeEdition::default());
         let lrfd1 = combos.iter().find(|c| c.name == "LRFD-1").unwrap();
         assert_eq!(lrfd1.get_factor(LoadType::Dead), 1.4);
         assert_eq!(lrfd1.get_factor(LoadType::Live), 0.0);
@@ -554,7 +742,7 @@ mod tests {
             .with_load(LoadType::Dead, 20.0)
             .with_load(LoadType::Live, 50.0);
 
-        let combos = asce7_asd_combinations();
+        let combos = asce7_asd_combinations(CodeEdition::default());
         let (max_load, _name) = find_governing_combination(&case, &combos);
 
         // D + L should be 70, which should govern for these loads
@@ -567,7 +755,7 @@ mod tests {
             .with_load(LoadType::Dead, 20.0)
             .with_load(LoadType::Live, 50.0);
 
-        let combos = asce7_lrfd_combinations();
+        let combos = asce7_lrfd_combinations(CodeEdition::default());
         let (max_load, name) = find_governing_combination(&case, &combos);
 
         // 1.2D + 1.6L = 24 + 80 = 104 should govern
@@ -608,7 +796,7 @@ mod tests {
             .with_load(LoadType::Dead, 10.0)  // 10 plf dead
             .with_load(LoadType::Wind, 30.0);  // 30 plf wind (uplift)
 
-        let combos = asce7_asd_combinations();
+        let combos = asce7_asd_combinations(CodeEdition::default());
         let (min_load, name) = find_minimum_combination(&case, &combos);
 
         // ASD-8': 0.6D - 0.6W = 0.6*10 - 0.6*30 = 6 - 18 = -12 plf
@@ -623,7 +811,7 @@ mod tests {
             .with_load(LoadType::Dead, 10.0)  // 10 plf dead
             .with_load(LoadType::Wind, 30.0);  // 30 plf wind (uplift)
 
-        let combos = asce7_lrfd_combinations();
+        let combos = asce7_lrfd_combinations(CodeEdition::default());
         let (min_load, name) = find_minimum_combination(&case, &combos);
 
         // LRFD-6': 0.9D - 1.0W = 0.9*10 - 1.0*30 = 9 - 30 = -21 plf
@@ -639,7 +827,7 @@ mod tests {
             .with_load(LoadType::Live, 20.0)
             .with_load(LoadType::Wind, 25.0);
 
-        let combos = asce7_asd_combinations();
+        let combos = asce7_asd_combinations(CodeEdition::default());
         let results = find_governing_min_max(&case, &combos);
 
         // Max: ASD-6a = D + 0.75L + 0.45W = 15 + 15 + 11.25 = 41.25 plf
@@ -658,10 +846,60 @@ mod tests {
             .with_load(LoadType::Dead, 100.0)
             .with_load(LoadType::Wind, 20.0);
 
-        let combos = asce7_asd_combinations();
+        let combos = asce7_asd_combinations(CodeEdition::default());
         let (min_load, _) = find_minimum_combination(&case, &combos);
 
         // 0.6D - 0.6W = 60 - 12 = 48 plf (still positive, no net uplift)
         assert!(min_load > 0.0, "Expected no net uplift");
     }
+
+    #[test]
+    fn test_editions_differ_only_in_snow() {
+        for (asce7_16, asce7_22) in [
+            (asce7_asd_combinations(CodeEdition::Asce7_16), asce7_asd_combinations(CodeEdition::Asce7_22)),
+            (asce7_lrfd_combinations(CodeEdition::Asce7_16), asce7_lrfd_combinations(CodeEdition::Asce7_22)),
+        ] {
+            assert_eq!(asce7_16.len(), asce7_22.len());
+            for (old, new) in asce7_16.iter().zip(&asce7_22) {
+                assert_eq!(old.name, new.name);
+                for load_type in LoadType::ALL {
+                    if load_type == LoadType::Snow {
+                        assert!(new.get_factor(load_type) <= old.get_factor(load_type));
+                    } else {
+                        assert_eq!(old.get_factor(load_type), new.get_factor(load_type), "{}", old.name);
+                    }
+                }
+            }
+        }
+
+        let lrfd = asce7_lrfd_combinations(CodeEdition::Asce7_22);
+        let snow = lrfd.iter().find(|c| c.name == "LRFD-3c").unwrap();
+        assert_eq!(snow.equation, "1.2D + 1.0S + L");
+        assert_eq!(snow.get_factor(LoadType::Snow), 1.0);
+    }
+
+    #[test]
+    fn test_asd_reduced_dead_combinations() {
+        let combos = asce7_asd_combinations(CodeEdition::default());
+        let find = |eq: &str| combos.iter().find(|c| c.equation == eq);
+
+        let wind = find("0.6D + 0.6W").expect("0.6D + 0.6W missing");
+        assert_eq!(wind.get_factor(LoadType::Dead), 0.6);
+        assert_eq!(wind.get_factor(LoadType::Wind), 0.6);
+
+        let uplift = find("0.6D - 0.6W").expect("0.6D - 0.6W missing");
+        assert_eq!(uplift.get_factor(LoadType::Dead), 0.6);
+        assert_eq!(uplift.get_factor(LoadType::Wind), -0.6);
+
+        let seismic = find("0.6D + 0.7E").expect("0.6D + 0.7E missing");
+        assert_eq!(seismic.get_factor(LoadType::Dead), 0.6);
+        assert_eq!(seismic.get_factor(LoadType::Seismic), 0.7);
+
+        // Every combination with a reduced dead factor uses exactly 0.6D
+        for combo in &combos {
+            let d = combo.get_factor(LoadType::Dead);
+            assert!(d == 1.0 || d == 0.6, "{} has D = {}", combo.name, d);
+            assert!(combo.equation.starts_with(if d == 0.6 { "0.6D" } else { "D" }));
+        }
+    }
 }
@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::load_types::LoadType;
-use super::{DesignMethod, LoadCase};
+use super::{importance_factor, CodeEdition, DesignMethod, LiveLoadReduction, LoadCase, RiskCategory};
+use crate::errors::{CalcError, CalcResult};
 
 // ============================================================================
 // Load Distribution Types
@@ -16,6 +17,7 @@ use super::{DesignMethod, LoadCase};
 
 /// How a load is distributed along a member
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum LoadDistribution {
     /// Point load at a specific position
@@ -52,6 +54,15 @@ pub enum LoadDistribution {
         /// Distance from left support (ft)
         position_ft: f64,
     },
+
+    /// Horizontal (axial) point load at a specific position
+    ///
+    /// Positive magnitude acts left to right. Causes no bending; it is
+    /// resisted only by supports that restrain horizontal movement.
+    HorizontalPoint {
+        /// Distance from left support (ft)
+        position_ft: f64,
+    },
 }
 
 impl Default for LoadDistribution {
@@ -69,6 +80,7 @@ impl LoadDistribution {
             LoadDistribution::UniformPartial { .. } => "Partial Uniform",
             LoadDistribution::Trapezoidal { .. } => "Trapezoidal",
             LoadDistribution::Moment { .. } => "Moment",
+            LoadDistribution::HorizontalPoint { .. } => "Horizontal Point",
         }
     }
 
@@ -80,10 +92,30 @@ impl LoadDistribution {
                 | LoadDistribution::UniformPartial { .. }
                 | LoadDistribution::Trapezoidal { .. }
                 | LoadDistribution::Moment { .. }
+                | LoadDistribution::HorizontalPoint { .. }
         )
     }
 }
 
+// ============================================================================
+// Load Projection
+// ============================================================================
+
+/// Length a distributed load on a sloped member is measured along
+///
+/// Roof snow and roof live loads are usually given per foot of horizontal
+/// projection; the weight of roofing is given per foot along the member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum LoadProjection {
+    /// Per foot of member length
+    #[default]
+    AlongMember,
+
+    /// Per foot of horizontal projection
+    Horizontal,
+}
+
 // ============================================================================
 // Discrete Load
 // ============================================================================
@@ -93,6 +125,7 @@ impl LoadDistribution {
 /// Represents one load applied to a structural member, with its type,
 /// distribution pattern, magnitude, and optional tributary width.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DiscreteLoad {
     /// Unique identifier for this load (for UI row management)
     pub id: Uuid,
@@ -116,6 +149,12 @@ pub struct DiscreteLoad {
 
     /// User note/description for this load
     pub note: String,
+
+    /// Whether a distributed load is measured along the member or on plan
+    ///
+    /// Only matters on a sloped member.
+    #[serde(default)]
+    pub projection: LoadProjection,
 }
 
 impl DiscreteLoad {
@@ -128,6 +167,7 @@ impl DiscreteLoad {
             magnitude: magnitude_plf,
             tributary_width_ft: None,
             note: String::new(),
+            projection: LoadProjection::AlongMember,
         }
     }
 
@@ -140,6 +180,7 @@ impl DiscreteLoad {
             magnitude: magnitude_lbs,
             tributary_width_ft: None,
             note: String::new(),
+            projection: LoadProjection::AlongMember,
         }
     }
 
@@ -157,6 +198,34 @@ impl DiscreteLoad {
             magnitude: magnitude_plf,
             tributary_width_ft: None,
             note: String::new(),
+            projection: LoadProjection::AlongMember,
+        }
+    }
+
+    /// Create a new trapezoidal (linearly varying) load
+    ///
+    /// `magnitude` holds the start magnitude; the analysis uses the
+    /// magnitudes stored in the distribution.
+    pub fn trapezoidal(
+        load_type: LoadType,
+        start_magnitude_plf: f64,
+        end_magnitude_plf: f64,
+        start_ft: f64,
+        end_ft: f64,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            load_type,
+            distribution: LoadDistribution::Trapezoidal {
+                start_ft,
+                end_ft,
+                start_magnitude: start_magnitude_plf,
+                end_magnitude: end_magnitude_plf,
+            },
+            magnitude: start_magnitude_plf,
+            tributary_width_ft: None,
+            note: String::new(),
+            projection: LoadProjection::AlongMember,
         }
     }
 
@@ -169,6 +238,20 @@ impl DiscreteLoad {
             magnitude: magnitude_ftlbs,
             tributary_width_ft: None,
             note: String::new(),
+            projection: LoadProjection::AlongMember,
+        }
+    }
+
+    /// Create a new horizontal (axial) point load
+    pub fn horizontal_point(load_type: LoadType, magnitude_lbs: f64, position_ft: f64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            load_type,
+            distribution: LoadDistribution::HorizontalPoint { position_ft },
+            magnitude: magnitude_lbs,
+            tributary_width_ft: None,
+            note: String::new(),
+            projection: LoadProjection::AlongMember,
         }
     }
 
@@ -184,6 +267,33 @@ impl DiscreteLoad {
         self
     }
 
+    /// Measure this load on horizontal projection (builder pattern)
+    pub fn on_horizontal_projection(mut self) -> Self {
+        self.projection = LoadProjection::Horizontal;
+        self
+    }
+
+    /// Multipliers for the (perpendicular, downslope) components of this
+    /// load on a member sloped `slope_deg` from horizontal
+    ///
+    /// Both are per foot of member length, or per point load. A gravity load
+    /// w along the member splits into w·cosθ across it and w·sinθ along it.
+    /// On horizontal projection w is first spread over the longer member,
+    /// w·cosθ per foot, giving w·cos²θ and w·sinθ·cosθ. Other load types
+    /// (wind acts normal to a roof) and applied moments are taken as already
+    /// perpendicular to the member.
+    pub fn slope_factors(&self, slope_deg: f64) -> (f64, f64) {
+        let (sin, cos) = slope_deg.to_radians().sin_cos();
+        if !self.load_type.is_gravity() {
+            return (1.0, 0.0);
+        }
+        match (&self.distribution, self.projection) {
+            (LoadDistribution::Moment { .. } | LoadDistribution::HorizontalPoint { .. }, _) => (1.0, 0.0),
+            (LoadDistribution::Point { .. }, _) | (_, LoadProjection::AlongMember) => (cos, sin),
+            (_, LoadProjection::Horizontal) => (cos * cos, sin * cos),
+        }
+    }
+
     /// Get effective magnitude accounting for tributary width
     ///
     /// For area loads (psf) with tributary width, returns line load (plf).
@@ -194,6 +304,25 @@ impl DiscreteLoad {
             None => self.magnitude,
         }
     }
+
+    /// Get effective magnitudes at the start and end of the load
+    ///
+    /// For trapezoidal loads, returns the distribution's end magnitudes
+    /// scaled by the tributary width. Other loads are constant, so both
+    /// ends equal [`Self::effective_magnitude`].
+    pub fn effective_end_magnitudes(&self) -> (f64, f64) {
+        match self.distribution {
+            LoadDistribution::Trapezoidal {
+                start_magnitude,
+                end_magnitude,
+                ..
+            } => {
+                let tw = self.tributary_width_ft.unwrap_or(1.0);
+                (start_magnitude * tw, end_magnitude * tw)
+            }
+            _ => (self.effective_magnitude(), self.effective_magnitude()),
+        }
+    }
 }
 
 // ============================================================================
@@ -205,6 +334,7 @@ impl DiscreteLoad {
 /// This replaces the simple `uniform_load_plf` field in BeamInput,
 /// allowing for multiple loads of different types and distributions.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EnhancedLoadCase {
     /// Collection of discrete loads
     pub loads: Vec<DiscreteLoad>,
@@ -214,6 +344,36 @@ pub struct EnhancedLoadCase {
 
     /// User label for this load case
     pub label: String,
+
+    /// Risk category whose ASCE 7 importance factors scale the loads
+    ///
+    /// `None` applies loads as entered. When set, the solver multiplies
+    /// each load by [`importance_factor`] for the beam's code edition
+    /// (I_s on snow under ASCE 7-16, I_e on seismic).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk_category: Option<RiskCategory>,
+
+    /// ASCE 7 live load reduction applied by the solver
+    ///
+    /// Only uniform live loads (L, not Lr) with a tributary width are
+    /// reduced; see [`EnhancedLoadCase::live_load_reductions`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_load_reduction: Option<LiveLoadReduction>,
+}
+
+/// A live load reduced per ASCE 7-22 Section 4.7.2
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReducedLiveLoad {
+    /// ID of the reduced load
+    pub load_id: Uuid,
+    /// Tributary area A_T = tributary width × loaded length (ft²)
+    pub tributary_area_sqft: f64,
+    /// Reduction factor L/L0
+    pub factor: f64,
+    /// Line load before reduction (plf)
+    pub unreduced_plf: f64,
+    /// Line load after reduction (plf)
+    pub reduced_plf: f64,
 }
 
 impl EnhancedLoadCase {
@@ -223,6 +383,8 @@ impl EnhancedLoadCase {
             loads: Vec::new(),
             include_self_weight: true,
             label: label.into(),
+            risk_category: None,
+            live_load_reduction: None,
         }
     }
 
@@ -244,6 +406,153 @@ impl EnhancedLoadCase {
         self
     }
 
+    /// Scale loads by the importance factors of a risk category (builder pattern)
+    pub fn with_risk_category(mut self, risk_category: RiskCategory) -> Self {
+        self.risk_category = Some(risk_category);
+        self
+    }
+
+    /// Copy with the importance factors of `edition` folded into the load
+    /// magnitudes
+    ///
+    /// The copy has no risk category, so applying it twice is harmless.
+    pub fn with_importance_applied(&self, edition: CodeEdition) -> Self {
+        let Some(risk_category) = self.risk_category else {
+            return self.clone();
+        };
+        let loads = self
+            .loads
+            .iter()
+            .map(|load| {
+                let factor = importance_factor(load.load_type, risk_category, edition);
+                let distribution = match load.distribution {
+                    LoadDistribution::Trapezoidal {
+                        start_ft,
+                        end_ft,
+                        start_magnitude,
+                        end_magnitude,
+                    } => LoadDistribution::Trapezoidal {
+                        start_ft,
+                        end_ft,
+                        start_magnitude: start_magnitude * factor,
+                        end_magnitude: end_magnitude * factor,
+                    },
+                    ref other => other.clone(),
+                };
+                DiscreteLoad {
+                    magnitude: load.magnitude * factor,
+                    distribution,
+                    ..load.clone()
+                }
+            })
+            .collect();
+        Self {
+            loads,
+            risk_category: None,
+            ..self.clone()
+        }
+    }
+
+    /// Copy with each load reduced to its component perpendicular to a
+    /// member sloped `slope_deg`, see [`DiscreteLoad::slope_factors`]
+    ///
+    /// Not idempotent: apply it once, to the loads as entered.
+    pub fn with_slope_applied(&self, slope_deg: f64) -> Self {
+        let loads = self
+            .loads
+            .iter()
+            .map(|load| {
+                let (perpendicular, _) = load.slope_factors(slope_deg);
+                let distribution = match load.distribution {
+                    LoadDistribution::Trapezoidal {
+                        start_ft,
+                        end_ft,
+                        start_magnitude,
+                        end_magnitude,
+                    } => LoadDistribution::Trapezoidal {
+                        start_ft,
+                        end_ft,
+                        start_magnitude: start_magnitude * perpendicular,
+                        end_magnitude: end_magnitude * perpendicular,
+                    },
+                    ref other => other.clone(),
+                };
+                DiscreteLoad {
+                    magnitude: load.magnitude * perpendicular,
+                    distribution,
+                    ..load.clone()
+                }
+            })
+            .collect();
+        Self {
+            loads,
+            ..self.clone()
+        }
+    }
+
+    /// Reduce live loads per ASCE 7-22 Section 4.7.2 (builder pattern)
+    pub fn with_live_load_reduction(mut self, reduction: LiveLoadReduction) -> Self {
+        self.live_load_reduction = Some(reduction);
+        self
+    }
+
+    /// Live loads that qualify for reduction on a member `member_length_ft` long
+    ///
+    /// Uniform and partial uniform live loads with a tributary width qualify;
+    /// A_T is the tributary width times the loaded length. Roof live, snow,
+    /// and loads without tributary data are never reduced. Empty when no
+    /// reduction is set or none of the loads reach K_LL·A_T > 400 ft².
+    pub fn live_load_reductions(&self, member_length_ft: f64) -> Vec<ReducedLiveLoad> {
+        let Some(reduction) = self.live_load_reduction else {
+            return Vec::new();
+        };
+        self.loads
+            .iter()
+            .filter(|load| load.load_type == LoadType::Live)
+            .filter_map(|load| {
+                let width = load.tributary_width_ft?;
+                let loaded_length = match load.distribution {
+                    LoadDistribution::UniformFull => member_length_ft,
+                    LoadDistribution::UniformPartial { start_ft, end_ft } => end_ft - start_ft,
+                    _ => return None,
+                };
+                let tributary_area_sqft = width * loaded_length;
+                let factor = reduction.factor(tributary_area_sqft);
+                let unreduced_plf = load.effective_magnitude();
+                (factor < 1.0).then_some(ReducedLiveLoad {
+                    load_id: load.id,
+                    tributary_area_sqft,
+                    factor,
+                    unreduced_plf,
+                    reduced_plf: unreduced_plf * factor,
+                })
+            })
+            .collect()
+    }
+
+    /// Copy with live load reduction folded into the load magnitudes
+    ///
+    /// The copy has no reduction set, so applying it twice is harmless.
+    pub fn with_live_load_reduction_applied(&self, member_length_ft: f64) -> Self {
+        let reductions = self.live_load_reductions(member_length_ft);
+        let loads = self
+            .loads
+            .iter()
+            .map(|load| match reductions.iter().find(|r| r.load_id == load.id) {
+                Some(r) => DiscreteLoad {
+                    magnitude: load.magnitude * r.factor,
+                    ..load.clone()
+                },
+                None => load.clone(),
+            })
+            .collect();
+        Self {
+            loads,
+            live_load_reduction: None,
+            ..self.clone()
+        }
+    }
+
     /// Add a load to this case
     pub fn add_load(&mut self, load: DiscreteLoad) {
         self.loads.push(load);
@@ -311,14 +620,80 @@ impl EnhancedLoadCase {
 
     /// Get governing factored uniform load (plf)
     ///
-    /// Applies ASCE 7 load combinations and returns the maximum factored load.
-    /// Does not include self-weight - caller should add that separately.
-    pub fn governing_uniform_plf(&self, method: DesignMethod) -> f64 {
+    /// Applies the ASCE 7 load combinations of `edition` and returns the maximum
+    /// factored load. Does not include self-weight - caller should add that separately.
+    pub fn governing_uniform_plf(&self, method: DesignMethod, edition: CodeEdition) -> f64 {
         let load_case = self.to_load_case();
-        let (governing, _name) = load_case.governing_load(method);
+        let (governing, _name) = load_case.governing_load(method, edition);
         governing
     }
 
+    /// Check that every load lies within a beam of `total_length_ft`
+    ///
+    /// Positions, starts, and ends must fall in `[0, total_length_ft]`, and
+    /// distributed loads must start before they end. Otherwise the
+    /// span-membership tests would drop the load silently. Gravity loads
+    /// (D, L, Lr, S, R) cannot be negative; moments and lateral loads carry
+    /// their direction in the sign.
+    pub fn validate(&self, total_length_ft: f64) -> CalcResult<()> {
+        for (i, load) in self.loads.iter().enumerate() {
+            let name = || {
+                if load.note.is_empty() {
+                    format!("{} {} load #{}", load.load_type.code(), load.distribution.display_name(), i + 1)
+                } else {
+                    format!("\"{}\"", load.note)
+                }
+            };
+            let (positions, magnitudes) = match &load.distribution {
+                LoadDistribution::Point { position_ft } => (vec![*position_ft], vec![load.magnitude]),
+                LoadDistribution::Moment { position_ft } | LoadDistribution::HorizontalPoint { position_ft } => {
+                    (vec![*position_ft], vec![])
+                }
+                LoadDistribution::UniformPartial { start_ft, end_ft } => {
+                    (vec![*start_ft, *end_ft], vec![load.magnitude])
+                }
+                LoadDistribution::Trapezoidal {
+                    start_ft,
+                    end_ft,
+                    start_magnitude,
+                    end_magnitude,
+                } => (vec![*start_ft, *end_ft], vec![*start_magnitude, *end_magnitude]),
+                LoadDistribution::UniformFull => (vec![], vec![load.magnitude]),
+            };
+            if let Some(pos) = positions.iter().find(|p| **p < 0.0 || **p > total_length_ft) {
+                return Err(CalcError::invalid_input(
+                    format!("loads[{}]", i),
+                    pos.to_string(),
+                    format!(
+                        "{} at {} ft is outside the beam (0 to {} ft)",
+                        name(),
+                        pos,
+                        total_length_ft
+                    ),
+                ));
+            }
+            if let [start_ft, end_ft] = positions[..] {
+                if start_ft >= end_ft {
+                    return Err(CalcError::invalid_input(
+                        format!("loads[{}]", i),
+                        format!("{} to {}", start_ft, end_ft),
+                        format!("{} must start before it ends", name()),
+                    ));
+                }
+            }
+            if load.load_type.is_gravity() {
+                if let Some(value) = magnitudes.iter().find(|m| **m < 0.0) {
+                    return Err(CalcError::invalid_input(
+                        format!("loads[{}]", i),
+                        value.to_string(),
+                        format!("{} cannot be negative", name()),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Check if there are any loads defined
     pub fn is_empty(&self) -> bool {
         self.loads.is_empty()
@@ -420,7 +795,7 @@ mod tests {
             .with_load(DiscreteLoad::uniform(LoadType::Dead, 20.0))
             .with_load(DiscreteLoad::uniform(LoadType::Live, 50.0));
 
-        let governing = case.governing_uniform_plf(DesignMethod::Asd);
+        let governing = case.governing_uniform_plf(DesignMethod::Asd, CodeEdition::default());
         // ASD combo D + L = 20 + 50 = 70 plf
         assert_eq!(governing, 70.0);
     }
@@ -473,4 +848,117 @@ mod tests {
         let points: Vec<_> = case.point_loads().collect();
         assert_eq!(points.len(), 2);
     }
+
+    #[test]
+    fn test_validate_load_positions() {
+        let case = EnhancedLoadCase::new("Test")
+            .with_load(DiscreteLoad::point(LoadType::Live, 1000.0, 12.0))
+            .with_load(DiscreteLoad::partial_uniform(LoadType::Dead, 50.0, 0.0, 6.0));
+        assert!(case.validate(12.0).is_ok());
+
+        let past_end = case.clone().with_load(DiscreteLoad::point(LoadType::Live, 1000.0, 15.0));
+        assert!(past_end.validate(12.0).is_err());
+
+        let negative = case.clone().with_load(DiscreteLoad::partial_uniform(LoadType::Dead, 50.0, -1.0, 4.0));
+        assert!(negative.validate(12.0).is_err());
+
+        let reversed = case.clone().with_load(DiscreteLoad::partial_uniform(LoadType::Live, 50.0, 8.0, 4.0));
+        assert!(reversed.validate(12.0).is_err());
+        let empty = case.clone().with_load(DiscreteLoad::trapezoidal(LoadType::Snow, 10.0, 40.0, 6.0, 6.0));
+        assert!(empty.validate(12.0).is_err());
+        let trapezoid_past_end = case.clone().with_load(DiscreteLoad::trapezoidal(LoadType::Snow, 10.0, 40.0, 6.0, 13.0));
+        assert!(trapezoid_past_end.validate(12.0).is_err());
+    }
+
+    #[test]
+    fn test_slope_factors() {
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let dead = DiscreteLoad::uniform(LoadType::Dead, 20.0);
+        let (perpendicular, axial) = dead.slope_factors(30.0);
+        assert!((perpendicular - cos).abs() < 1e-12 && (axial - sin).abs() < 1e-12);
+
+        let snow = DiscreteLoad::uniform(LoadType::Snow, 30.0).on_horizontal_projection();
+        let (perpendicular, axial) = snow.slope_factors(30.0);
+        assert!((perpendicular - cos * cos).abs() < 1e-12 && (axial - sin * cos).abs() < 1e-12);
+
+        // Wind is normal to the roof; a level member is unchanged
+        assert_eq!(DiscreteLoad::uniform(LoadType::Wind, 20.0).slope_factors(30.0), (1.0, 0.0));
+        assert_eq!(snow.slope_factors(0.0), (1.0, 0.0));
+
+        let case = EnhancedLoadCase::new("Roof")
+            .with_load(snow)
+            .with_load(DiscreteLoad::trapezoidal(LoadType::Dead, 10.0, 20.0, 0.0, 6.0));
+        let applied = case.with_slope_applied(30.0);
+        assert!((applied.loads[0].magnitude - 30.0 * cos * cos).abs() < 1e-12);
+        assert_eq!(applied.loads[1].effective_end_magnitudes(), (10.0 * cos, 20.0 * cos));
+    }
+
+    #[test]
+    fn test_validate_negative_gravity_magnitudes() {
+        let case = EnhancedLoadCase::new("Test").with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0));
+        let err = case
+            .clone()
+            .with_load(DiscreteLoad::point(LoadType::Live, -500.0, 6.0).with_note("Hoist"))
+            .validate(12.0)
+            .unwrap_err();
+        assert!(err.to_string().contains("\"Hoist\" cannot be negative"), "{}", err);
+
+        let uplift_trapezoid = case.clone().with_load(DiscreteLoad::trapezoidal(LoadType::Snow, 0.0, -20.0, 0.0, 6.0));
+        assert!(uplift_trapezoid.validate(12.0).is_err());
+        assert!(case.clone().with_load(DiscreteLoad::uniform(LoadType::Dead, -5.0)).validate(12.0).is_err());
+
+        // Wind uplift and applied moments carry direction in their sign
+        let signed = case
+            .with_load(DiscreteLoad::uniform(LoadType::Wind, -30.0))
+            .with_load(DiscreteLoad::moment(LoadType::Dead, -1200.0, 3.0));
+        assert!(signed.validate(12.0).is_ok());
+    }
+
+    #[test]
+    fn test_risk_category_scales_snow() {
+        let case = EnhancedLoadCase::new("Roof")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Snow, 30.0).with_tributary_width(2.0))
+            .with_risk_category(RiskCategory::IV);
+
+        let applied = case.with_importance_applied(CodeEdition::Asce7_16);
+        assert!(applied.risk_category.is_none());
+        assert_eq!(applied.total_uniform_by_type(LoadType::Dead), 15.0);
+        assert!((applied.total_uniform_by_type(LoadType::Snow) - 72.0).abs() < 1e-9); // 30 psf * 2 ft * 1.2
+
+        // ASCE 7-22 has no I_s; p_g already reflects the risk category
+        let applied = case.with_importance_applied(CodeEdition::Asce7_22);
+        assert_eq!(applied.total_uniform_by_type(LoadType::Snow), 60.0);
+
+        // Not opted in: loads as entered
+        let raw = EnhancedLoadCase { risk_category: None, ..case };
+        assert_eq!(raw.with_importance_applied(CodeEdition::Asce7_16).total_uniform_by_type(LoadType::Snow), 60.0);
+    }
+
+    #[test]
+    fn test_live_load_reduction() {
+        // 40 psf over 10 ft tributary on a 30 ft beam: K_LL·A_T = 2 × 300 = 600 ft²
+        let case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 40.0).with_tributary_width(10.0))
+            .with_load(DiscreteLoad::uniform(LoadType::LiveRoof, 20.0).with_tributary_width(10.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 50.0))
+            .with_live_load_reduction(LiveLoadReduction::default());
+
+        let reductions = case.live_load_reductions(30.0);
+        assert_eq!(reductions.len(), 1);
+        let factor = 0.25 + 15.0 / 600.0_f64.sqrt();
+        assert!((reductions[0].factor - factor).abs() < 1e-9);
+        assert_eq!(reductions[0].unreduced_plf, 400.0);
+
+        let applied = case.with_live_load_reduction_applied(30.0);
+        assert!(applied.live_load_reduction.is_none());
+        assert!((applied.total_uniform_by_type(LoadType::Live) - (400.0 * factor + 50.0)).abs() < 1e-9);
+        assert_eq!(applied.total_uniform_by_type(LoadType::LiveRoof), 200.0);
+
+        // Small tributary area: no reduction
+        assert!(case.live_load_reductions(15.0).is_empty());
+
+        // Very large area hits the 50% floor
+        assert_eq!(case.live_load_reductions(1000.0)[0].factor, 0.5);
+    }
 }
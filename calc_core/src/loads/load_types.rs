@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(dead.description(), "Dead load");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum LoadType {
     /// D - Dead load (self-weight of structure and permanent attachments)
     Dead,
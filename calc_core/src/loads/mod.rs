@@ -1,7 +1,8 @@
-//! Load combinations and load case management per ASCE 7-22
+//! Load combinations and load case management per ASCE 7
 //!
 //! This module provides types and functions for working with structural loads
-//! and load combinations according to ASCE 7-22.
+//! and load combinations according to ASCE 7-22 (or ASCE 7-16, see
+//! [`CodeEdition`]).
 //!
 //! # Overview
 //!
@@ -9,11 +10,13 @@
 //! - [`LoadCase`] - A collection of load values for a specific scenario
 //! - [`LoadCombination`] - Factors to apply for code-compliant load combinations
 //! - [`DesignMethod`] - ASD vs LRFD design methodology
+//! - [`CodeEdition`] - ASCE 7 edition the combinations follow
+//! - [`SnowDrift`] - Drift surcharge at a roof step or parapet
 //!
 //! # Example
 //!
 //! ```
-//! use calc_core::loads::{LoadType, LoadCase, DesignMethod, asce7_asd_combinations};
+//! use calc_core::loads::{LoadType, LoadCase, CodeEdition, asce7_asd_combinations};
 //!
 //! // Define loads for a floor beam
 //! let floor_loads = LoadCase::new("Second Floor")
@@ -21,7 +24,7 @@
 //!     .with_load(LoadType::Live, 40.0);  // psf occupancy
 //!
 //! // Get ASD combinations and find governing
-//! let combos = asce7_asd_combinations();
+//! let combos = asce7_asd_combinations(CodeEdition::Asce7_22);
 //! let max_load = combos.iter()
 //!     .map(|c| c.apply(&floor_loads))
 //!     .fold(0.0f64, f64::max);
@@ -32,22 +35,30 @@
 pub mod load_types;
 pub mod combinations;
 pub mod discrete;
+pub mod snow_drift;
 
 pub use load_types::LoadType;
 pub use combinations::{
     LoadCombination,
+    CodeEdition,
     GoverningResults,
     asce7_asd_combinations,
     asce7_lrfd_combinations,
+    combination_set_label,
+    select_combinations,
+    validate_combinations,
     find_governing_combination,
     find_minimum_combination,
     find_governing_min_max,
 };
 pub use discrete::{
     LoadDistribution,
+    LoadProjection,
     DiscreteLoad,
     EnhancedLoadCase,
+    ReducedLiveLoad,
 };
+pub use snow_drift::{SnowDrift, SnowDriftResult, calculate_drift, drift_surcharge};
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
@@ -61,15 +72,16 @@ use crate::errors::{CalcError, CalcResult};
 ///
 /// # Example
 /// ```
-/// use calc_core::loads::{DesignMethod, asce7_asd_combinations, asce7_lrfd_combinations};
+/// use calc_core::loads::{CodeEdition, DesignMethod, asce7_asd_combinations, asce7_lrfd_combinations};
 ///
 /// let method = DesignMethod::Asd;
 /// let combos = match method {
-///     DesignMethod::Asd => asce7_asd_combinations(),
-///     DesignMethod::Lrfd => asce7_lrfd_combinations(),
+///     DesignMethod::Asd => asce7_asd_combinations(CodeEdition::default()),
+///     DesignMethod::Lrfd => asce7_lrfd_combinations(CodeEdition::default()),
 /// };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum DesignMethod {
     /// Allowable Stress Design - uses service loads with safety factors on capacity
     #[default]
@@ -95,11 +107,16 @@ impl DesignMethod {
         }
     }
 
-    /// Get the appropriate load combinations for this design method
-    pub fn combinations(&self) -> Vec<LoadCombination> {
+    /// Get the built-in load combinations of `edition` for this design method
+    ///
+    /// Calculations use [`ContinuousBeamInput::load_combinations`], which
+    /// returns a custom set in place of these when one is given.
+    ///
+    /// [`ContinuousBeamInput::load_combinations`]: crate::calculations::ContinuousBeamInput::load_combinations
+    pub fn combinations(&self, edition: CodeEdition) -> Vec<LoadCombination> {
         match self {
-            DesignMethod::Asd => asce7_asd_combinations(),
-            DesignMethod::Lrfd => asce7_lrfd_combinations(),
+            DesignMethod::Asd => asce7_asd_combinations(edition),
+            DesignMethod::Lrfd => asce7_lrfd_combinations(edition),
         }
     }
 }
@@ -110,6 +127,98 @@ impl std::fmt::Display for DesignMethod {
     }
 }
 
+/// Risk category per ASCE 7 Table 1.5-1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum RiskCategory {
+    /// Low risk to human life (agricultural, minor storage)
+    I,
+    /// Typical buildings not in I, III, or IV
+    #[default]
+    II,
+    /// Substantial risk to human life (assembly, schools)
+    III,
+    /// Essential facilities (hospitals, emergency response)
+    IV,
+}
+
+/// ASCE 7 importance factor for a load type (Table 1.5-2)
+///
+/// Seismic returns I_e. Snow returns I_s under ASCE 7-16 only: ASCE 7-22
+/// dropped I_s and maps ground snow loads p_g by risk category instead, so
+/// snow returns 1.0 there. Other load types have no importance factor and
+/// return 1.0.
+///
+/// # Example
+/// ```
+/// use calc_core::loads::{importance_factor, CodeEdition, LoadType, RiskCategory};
+///
+/// assert_eq!(importance_factor(LoadType::Snow, RiskCategory::IV, CodeEdition::Asce7_16), 1.2);
+/// assert_eq!(importance_factor(LoadType::Snow, RiskCategory::IV, CodeEdition::Asce7_22), 1.0);
+/// assert_eq!(importance_factor(LoadType::Seismic, RiskCategory::IV, CodeEdition::Asce7_22), 1.5);
+/// assert_eq!(importance_factor(LoadType::Live, RiskCategory::IV, CodeEdition::Asce7_16), 1.0);
+/// ```
+pub fn importance_factor(load_type: LoadType, risk_category: RiskCategory, edition: CodeEdition) -> f64 {
+    match (load_type, risk_category) {
+        (LoadType::Snow, _) if edition == CodeEdition::Asce7_22 => 1.0,
+        (LoadType::Snow, RiskCategory::I) => 0.8,
+        (LoadType::Snow, RiskCategory::II) => 1.0,
+        (LoadType::Snow, RiskCategory::III) => 1.1,
+        (LoadType::Snow, RiskCategory::IV) => 1.2,
+        (LoadType::Seismic, RiskCategory::I | RiskCategory::II) => 1.0,
+        (LoadType::Seismic, RiskCategory::III) => 1.25,
+        (LoadType::Seismic, RiskCategory::IV) => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Live load reduction per ASCE 7-22 Section 4.7.2
+///
+/// # Example
+/// ```
+/// use calc_core::loads::LiveLoadReduction;
+///
+/// let reduction = LiveLoadReduction::default();
+/// // K_LL·A_T = 2 × 150 = 300 ft², below the 400 ft² threshold
+/// assert_eq!(reduction.factor(150.0), 1.0);
+/// // 0.25 + 15/√800 = 0.78
+/// assert!((reduction.factor(400.0) - 0.780).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LiveLoadReduction {
+    /// Live load element factor K_LL (Table 4.7-1); 2 for interior and edge beams
+    pub k_ll: f64,
+
+    /// Member supports two or more floors (limit 0.40 L0 instead of 0.50 L0)
+    #[serde(default)]
+    pub multiple_floors: bool,
+}
+
+impl Default for LiveLoadReduction {
+    fn default() -> Self {
+        Self {
+            k_ll: 2.0,
+            multiple_floors: false,
+        }
+    }
+}
+
+impl LiveLoadReduction {
+    /// Factor L/L0 for a tributary area A_T (ft²), per ASCE 7-22 Eq. 4.7-1
+    ///
+    /// 1.0 when K_LL·A_T ≤ 400 ft²; otherwise 0.25 + 15/√(K_LL·A_T), but
+    /// not less than 0.50 (0.40 for members supporting two or more floors).
+    pub fn factor(&self, tributary_area_sqft: f64) -> f64 {
+        let influence_area = self.k_ll * tributary_area_sqft;
+        if influence_area <= 400.0 {
+            return 1.0;
+        }
+        let limit = if self.multiple_floors { 0.40 } else { 0.50 };
+        (0.25 + 15.0 / influence_area.sqrt()).clamp(limit, 1.0)
+    }
+}
+
 /// A collection of load values by type for a specific loading scenario
 ///
 /// LoadCase stores unfactored (service) load values. These are combined
@@ -206,15 +315,15 @@ impl LoadCase {
             .sum()
     }
 
-    /// Apply all combinations and find the governing (maximum) result
-    pub fn governing_load(&self, method: DesignMethod) -> (f64, String) {
-        find_governing_combination(self, &method.combinations())
+    /// Apply all combinations of `edition` and find the governing (maximum) result
+    pub fn governing_load(&self, method: DesignMethod, edition: CodeEdition) -> (f64, String) {
+        find_governing_combination(self, &method.combinations(edition))
     }
 
-    /// Apply all combinations and return all results
-    pub fn all_combination_results(&self, method: DesignMethod) -> Vec<(String, f64)> {
+    /// Apply all combinations of `edition` and return all results
+    pub fn all_combination_results(&self, method: DesignMethod, edition: CodeEdition) -> Vec<(String, f64)> {
         method
-            .combinations()
+            .combinations(edition)
             .iter()
             .map(|combo| (combo.name.clone(), combo.apply(self)))
             .collect()
@@ -239,8 +348,8 @@ mod tests {
 
     #[test]
     fn test_design_method_combinations() {
-        let asd = DesignMethod::Asd.combinations();
-        let lrfd = DesignMethod::Lrfd.combinations();
+        let asd = DesignMethod::Asd.combinations(CodeEdition::default());
+        let lrfd = DesignMethod::Lrfd.combinations(CodeEdition::default());
 
         assert!(!asd.is_empty());
         assert!(!lrfd.is_empty());
@@ -310,7 +419,7 @@ mod tests {
             .with_load(LoadType::Dead, 20.0)
             .with_load(LoadType::Live, 40.0);
 
-        let (load, _name) = case.governing_load(DesignMethod::Asd);
+        let (load, _name) = case.governing_load(DesignMethod::Asd, CodeEdition::default());
         // D + L = 60 is max for ASD with these loads
         assert!((load - 60.0).abs() < 0.001);
     }
@@ -321,7 +430,7 @@ mod tests {
             .with_load(LoadType::Dead, 20.0)
             .with_load(LoadType::Live, 40.0);
 
-        let (load, _name) = case.governing_load(DesignMethod::Lrfd);
+        let (load, _name) = case.governing_load(DesignMethod::Lrfd, CodeEdition::default());
         // 1.2D + 1.6L = 24 + 64 = 88 should govern
         assert!((load - 88.0).abs() < 0.001);
     }
@@ -345,7 +454,7 @@ mod tests {
         let case = LoadCase::new("Test")
             .with_load(LoadType::Dead, 10.0);
 
-        let results = case.all_combination_results(DesignMethod::Asd);
+        let results = case.all_combination_results(DesignMethod::Asd, CodeEdition::default());
         assert!(!results.is_empty());
 
         // ASD-1 should be just D = 10
@@ -0,0 +1,347 @@
+//! Snow Drift Surcharge (ASCE 7 Section 7.7)
+//!
+//! Generates the triangular drift surcharge on a lower roof, against a roof
+//! step or a parapet, as a trapezoidal [`DiscreteLoad`] ready to add to a
+//! beam's load case.
+//!
+//! ```text
+//! γ  = 0.13 pg + 14 ≤ 30 pcf
+//! hb = ps / γ,  hc = h - hb
+//! hd = 0.43 ∛lu ∜(pg + 10) - 1.5                  (ASCE 7-16)
+//! hd = 1.5 √(pg^0.74 lu^0.70 W2^1.7 / γ)          (ASCE 7-22)
+//! w  = 4 hd,  or 4 hd² / hc ≤ 8 hc with hd = hc when hd > hc
+//! pd = hd γ
+//! ```
+//!
+//! Leeward drift uses the upper roof length; windward drift uses the lower
+//! roof length (or the roof upwind of a parapet) at 0.75 hd. The larger
+//! governs. No drift is needed when hc/hb < 0.2.
+//!
+//! # Example
+//!
+//! ```
+//! use calc_core::loads::snow_drift::{drift_surcharge, SnowDrift};
+//! use calc_core::loads::CodeEdition;
+//!
+//! let drift = SnowDrift::roof_step(30.0, 21.0, 100.0, 50.0, 8.0).with_code_edition(CodeEdition::Asce7_16);
+//! let (load, result) = drift_surcharge(&drift, 12.0, 4.0).unwrap();
+//! assert!((result.drift_height_ft - 3.52).abs() < 0.01);
+//! assert_eq!(load.effective_end_magnitudes().0, result.surcharge_psf * 4.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::combinations::CodeEdition;
+use super::discrete::DiscreteLoad;
+use super::load_types::LoadType;
+use crate::errors::{CalcError, CalcResult};
+
+/// Shortest upwind fetch used in the drift height equations (ft)
+const MIN_FETCH_FT: f64 = 20.0;
+
+/// Roof geometry and snow loads for a drift against a roof step or parapet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SnowDrift {
+    /// Ground snow load pg (psf)
+    pub ground_snow_psf: f64,
+
+    /// Balanced snow load ps on the lower roof (psf)
+    pub balanced_snow_psf: f64,
+
+    /// Height of the roof step or parapet above the lower roof (ft)
+    pub step_height_ft: f64,
+
+    /// Upper roof length lu for leeward drift (ft); zero for a parapet
+    pub leeward_fetch_ft: f64,
+
+    /// Roof length upwind of the step or parapet for windward drift (ft)
+    pub windward_fetch_ft: f64,
+
+    /// Winter wind parameter W2 from ASCE 7-22 Fig. 7.6-1, required for 7-22
+    #[serde(default)]
+    pub winter_wind_parameter: f64,
+
+    /// ASCE 7 edition of the drift height equation
+    #[serde(default)]
+    pub code_edition: CodeEdition,
+}
+
+impl SnowDrift {
+    /// Drift at a roof step, from the upper roof (leeward) and lower roof (windward)
+    pub fn roof_step(
+        ground_snow_psf: f64,
+        balanced_snow_psf: f64,
+        upper_roof_length_ft: f64,
+        lower_roof_length_ft: f64,
+        step_height_ft: f64,
+    ) -> Self {
+        Self {
+            ground_snow_psf,
+            balanced_snow_psf,
+            step_height_ft,
+            leeward_fetch_ft: upper_roof_length_ft,
+            windward_fetch_ft: lower_roof_length_ft,
+            winter_wind_parameter: 0.0,
+            code_edition: CodeEdition::default(),
+        }
+    }
+
+    /// Windward drift against a parapet of `parapet_height_ft` (ASCE 7.8)
+    pub fn parapet(ground_snow_psf: f64, balanced_snow_psf: f64, roof_length_ft: f64, parapet_height_ft: f64) -> Self {
+        Self {
+            leeward_fetch_ft: 0.0,
+            ..Self::roof_step(ground_snow_psf, balanced_snow_psf, 0.0, roof_length_ft, parapet_height_ft)
+        }
+    }
+
+    /// Set the ASCE 7 edition (builder pattern)
+    pub fn with_code_edition(mut self, edition: CodeEdition) -> Self {
+        self.code_edition = edition;
+        self
+    }
+
+    /// Set the ASCE 7-22 winter wind parameter W2 (builder pattern)
+    pub fn with_winter_wind_parameter(mut self, w2: f64) -> Self {
+        self.winter_wind_parameter = w2;
+        self
+    }
+
+    /// Snow density γ = 0.13 pg + 14 ≤ 30 pcf (ASCE 7 Eq. 7.7-1)
+    pub fn density_pcf(&self) -> f64 {
+        (0.13 * self.ground_snow_psf + 14.0).min(30.0)
+    }
+
+    /// Drift height hd for an upwind fetch of `fetch_ft` (ft)
+    ///
+    /// Fetches shorter than 20 ft are taken as 20 ft.
+    pub fn drift_height_ft(&self, fetch_ft: f64) -> f64 {
+        let lu = fetch_ft.max(MIN_FETCH_FT);
+        let pg = self.ground_snow_psf;
+        let hd = match self.code_edition {
+            CodeEdition::Asce7_16 => 0.43 * lu.cbrt() * (pg + 10.0).powf(0.25) - 1.5,
+            CodeEdition::Asce7_22 => {
+                1.5 * (pg.powf(0.74) * lu.powf(0.70) * self.winter_wind_parameter.powf(1.7) / self.density_pcf()).sqrt()
+            }
+        };
+        hd.max(0.0)
+    }
+
+    /// Validate input parameters.
+    pub fn validate(&self) -> CalcResult<()> {
+        if self.ground_snow_psf <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "ground_snow_psf",
+                self.ground_snow_psf.to_string(),
+                "Ground snow load must be positive",
+            ));
+        }
+        if self.balanced_snow_psf < 0.0 {
+            return Err(CalcError::invalid_input(
+                "balanced_snow_psf",
+                self.balanced_snow_psf.to_string(),
+                "Balanced snow load cannot be negative",
+            ));
+        }
+        if self.step_height_ft <= 0.0 {
+            return Err(CalcError::invalid_input(
+                "step_height_ft",
+                self.step_height_ft.to_string(),
+                "Step or parapet height must be positive",
+            ));
+        }
+        if self.leeward_fetch_ft < 0.0 || self.windward_fetch_ft < 0.0 {
+            return Err(CalcError::invalid_input(
+                "windward_fetch_ft",
+                format!("{}, {}", self.leeward_fetch_ft, self.windward_fetch_ft),
+                "Roof lengths cannot be negative",
+            ));
+        }
+        if self.code_edition == CodeEdition::Asce7_22
+            && !(self.winter_wind_parameter > 0.0 && self.winter_wind_parameter <= 1.0)
+        {
+            return Err(CalcError::invalid_input(
+                "winter_wind_parameter",
+                self.winter_wind_parameter.to_string(),
+                "ASCE 7-22 drifts need the winter wind parameter W2 (0 to 1) from Fig. 7.6-1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Computed drift geometry for reporting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnowDriftResult {
+    /// Snow density γ (pcf)
+    pub density_pcf: f64,
+
+    /// Balanced snow height hb = ps / γ (ft)
+    pub balanced_height_ft: f64,
+
+    /// Clear height hc from the balanced snow to the top of the step (ft)
+    pub clear_height_ft: f64,
+
+    /// Governing drift height hd, capped at hc (ft)
+    pub drift_height_ft: f64,
+
+    /// Drift width w (ft)
+    pub drift_width_ft: f64,
+
+    /// Peak surcharge pd = hd γ at the step (psf)
+    pub surcharge_psf: f64,
+
+    /// Whether the leeward drift governs over the windward drift
+    pub leeward_governs: bool,
+}
+
+impl SnowDriftResult {
+    /// Whether any drift surcharge applies (hc/hb ≥ 0.2)
+    pub fn has_drift(&self) -> bool {
+        self.surcharge_psf > 0.0
+    }
+
+    /// Surcharge on a member running away from the step, with the step at
+    /// its left end, as a snow load over `tributary_width_ft`
+    ///
+    /// The triangle falls from pd at the step to zero at w, cut off at the
+    /// member end when the drift is wider than the member.
+    pub fn surcharge_load(&self, member_length_ft: f64, tributary_width_ft: f64) -> DiscreteLoad {
+        let end_ft = self.drift_width_ft.min(member_length_ft);
+        let end_psf = if self.drift_width_ft > 0.0 {
+            self.surcharge_psf * (1.0 - end_ft / self.drift_width_ft)
+        } else {
+            0.0
+        };
+        DiscreteLoad::trapezoidal(LoadType::Snow, self.surcharge_psf, end_psf, 0.0, end_ft)
+            .with_tributary_width(tributary_width_ft)
+            .with_note(format!(
+                "Snow drift: hd = {:.2} ft, w = {:.2} ft, pd = {:.1} psf",
+                self.drift_height_ft, self.drift_width_ft, self.surcharge_psf
+            ))
+    }
+}
+
+/// Compute the drift geometry per ASCE 7 Section 7.7.
+///
+/// # Returns
+///
+/// * `Ok(SnowDriftResult)` - Drift height, width, and peak surcharge; all
+///   zero when hc/hb < 0.2
+/// * `Err(CalcError)` - If inputs are invalid
+pub fn calculate_drift(drift: &SnowDrift) -> CalcResult<SnowDriftResult> {
+    drift.validate()?;
+
+    let density_pcf = drift.density_pcf();
+    let balanced_height_ft = drift.balanced_snow_psf / density_pcf;
+    let clear_height_ft = (drift.step_height_ft - balanced_height_ft).max(0.0);
+    let mut result = SnowDriftResult {
+        density_pcf,
+        balanced_height_ft,
+        clear_height_ft,
+        drift_height_ft: 0.0,
+        drift_width_ft: 0.0,
+        surcharge_psf: 0.0,
+        leeward_governs: false,
+    };
+    if balanced_height_ft > 0.0 && clear_height_ft / balanced_height_ft < 0.2 {
+        return Ok(result);
+    }
+
+    let leeward = if drift.leeward_fetch_ft > 0.0 {
+        drift.drift_height_ft(drift.leeward_fetch_ft)
+    } else {
+        0.0
+    };
+    let windward = 0.75 * drift.drift_height_ft(drift.windward_fetch_ft);
+    let hd = leeward.max(windward);
+    result.leeward_governs = leeward >= windward && leeward > 0.0;
+
+    (result.drift_height_ft, result.drift_width_ft) = if hd <= clear_height_ft {
+        (hd, 4.0 * hd)
+    } else {
+        (clear_height_ft, (4.0 * hd * hd / clear_height_ft).min(8.0 * clear_height_ft))
+    };
+    result.surcharge_psf = result.drift_height_ft * density_pcf;
+    Ok(result)
+}
+
+/// Drift surcharge on a member with the step at its left end, plus the
+/// drift geometry behind it.
+pub fn drift_surcharge(
+    drift: &SnowDrift,
+    member_length_ft: f64,
+    tributary_width_ft: f64,
+) -> CalcResult<(DiscreteLoad, SnowDriftResult)> {
+    let result = calculate_drift(drift)?;
+    Ok((result.surcharge_load(member_length_ft, tributary_width_ft), result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loads::LoadDistribution;
+
+    /// pg = 30 psf, ps = 21 psf, 100 ft upper roof, 50 ft lower roof, 8 ft step
+    fn worked_example() -> SnowDrift {
+        SnowDrift::roof_step(30.0, 21.0, 100.0, 50.0, 8.0).with_code_edition(CodeEdition::Asce7_16)
+    }
+
+    #[test]
+    fn test_roof_step_drift_worked_example() {
+        // γ = 0.13(30) + 14 = 17.9 pcf, hb = 21/17.9 = 1.17 ft, hc = 6.83 ft
+        // Leeward hd = 0.43 ∛100 ∜40 - 1.5 = 3.52 ft governs over
+        // windward 0.75 (0.43 ∛50 ∜40 - 1.5) = 1.86 ft
+        let result = calculate_drift(&worked_example()).unwrap();
+        assert!((result.density_pcf - 17.9).abs() < 1e-9);
+        assert!((result.clear_height_ft - 6.827).abs() < 1e-3);
+        assert!((result.drift_height_ft - 3.52).abs() < 0.005);
+        assert!(result.leeward_governs);
+        assert!((result.drift_width_ft - 14.08).abs() < 0.02);
+        assert!((result.surcharge_psf - 63.0).abs() < 0.1);
+
+        // On a 10 ft joist at 4 ft o.c.: 252 plf at the step, 73 plf at the far end
+        let (load, _) = drift_surcharge(&worked_example(), 10.0, 4.0).unwrap();
+        assert_eq!(load.load_type, LoadType::Snow);
+        let (start, end) = load.effective_end_magnitudes();
+        assert!((start - 4.0 * result.surcharge_psf).abs() < 1e-9);
+        assert!((end - start * (1.0 - 10.0 / result.drift_width_ft)).abs() < 1e-9);
+        assert!(matches!(load.distribution, LoadDistribution::Trapezoidal { end_ft, .. } if end_ft == 10.0));
+    }
+
+    #[test]
+    fn test_drift_capped_at_clear_height() {
+        // 4 ft step: hc = 2.83 ft < hd, so hd = hc and w = 4 hd²/hc
+        let drift = SnowDrift {
+            step_height_ft: 4.0,
+            ..worked_example()
+        };
+        let result = calculate_drift(&drift).unwrap();
+        let hd = 0.43 * 100.0_f64.cbrt() * 40.0_f64.powf(0.25) - 1.5;
+        assert!((result.drift_height_ft - result.clear_height_ft).abs() < 1e-12);
+        assert!((result.drift_width_ft - 4.0 * hd * hd / result.clear_height_ft).abs() < 1e-9);
+
+        // A step barely above the balanced snow needs no drift
+        let low = SnowDrift {
+            step_height_ft: 1.3,
+            ..worked_example()
+        };
+        assert!(!calculate_drift(&low).unwrap().has_drift());
+    }
+
+    #[test]
+    fn test_asce7_22_needs_winter_wind_parameter() {
+        let drift = worked_example().with_code_edition(CodeEdition::Asce7_22);
+        assert!(calculate_drift(&drift).is_err());
+
+        let result = calculate_drift(&drift.with_winter_wind_parameter(0.55)).unwrap();
+        let expected = 1.5 * (30.0_f64.powf(0.74) * 100.0_f64.powf(0.70) * 0.55_f64.powf(1.7) / 17.9).sqrt();
+        assert!((result.drift_height_ft - expected).abs() < 1e-9);
+
+        // Parapets drift windward only
+        let parapet = SnowDrift::parapet(30.0, 21.0, 100.0, 8.0).with_code_edition(CodeEdition::Asce7_16);
+        let result = calculate_drift(&parapet).unwrap();
+        assert!(!result.leeward_governs);
+        assert!((result.drift_height_ft - 0.75 * 3.52).abs() < 0.005);
+    }
+}
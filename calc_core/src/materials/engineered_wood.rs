@@ -20,6 +20,7 @@ use crate::generated::engineered_wood_data;
 /// The naming convention: FbValue-EValue (e.g., 24F-1.8E means Fb=2400 psi, E=1.8 million psi)
 /// V-grades (e.g., 24F-V4) are for visually graded tension laminations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[allow(non_camel_case_types)] // Industry standard naming
 pub enum GlulamStressClass {
     /// 16F-1.3E - Economy grade
@@ -84,6 +85,7 @@ impl std::fmt::Display for GlulamStressClass {
 
 /// Glulam layup orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum GlulamLayup {
     /// Balanced - tension and compression lams are equal (same Fb+ and Fb-)
     #[default]
@@ -172,6 +174,7 @@ impl GlulamProperties {
 
 /// Glulam material specification
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GlulamMaterial {
     /// Stress class (e.g., 24F-V4)
     pub stress_class: GlulamStressClass,
@@ -219,6 +222,7 @@ impl std::fmt::Display for GlulamMaterial {
 /// LVL properties vary by manufacturer. These grades represent common
 /// property ranges available in the market.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum LvlGrade {
     /// Standard structural LVL (E ~2.0 million psi, Fb ~2600 psi)
     #[default]
@@ -314,6 +318,7 @@ impl LvlProperties {
 
 /// LVL material specification
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LvlMaterial {
     /// Grade designation
     pub grade: LvlGrade,
@@ -355,6 +360,7 @@ impl std::fmt::Display for LvlMaterial {
 
 /// PSL grade designations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum PslGrade {
     /// Standard PSL (E ~2.0 million psi)
     #[default]
@@ -442,6 +448,7 @@ impl PslProperties {
 
 /// PSL material specification
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PslMaterial {
     /// Grade designation
     pub grade: PslGrade,
@@ -234,6 +234,39 @@ impl LumberSize {
         }
         LumberSize::Custom
     }
+
+    /// Smallest standard size of the same width with actual depth >= `depth_in`
+    ///
+    /// Returns `Custom` if no size in this width family is deep enough.
+    ///
+    /// ```
+    /// use calc_core::materials::LumberSize;
+    ///
+    /// assert_eq!(LumberSize::L2x8.round_up_depth(9.1), LumberSize::L2x10);
+    /// ```
+    pub fn round_up_depth(&self, depth_in: f64) -> LumberSize {
+        Self::same_width(self.width_in())
+            .filter(|s| s.depth_in() >= depth_in - 1e-9)
+            .min_by(|a, b| a.depth_in().total_cmp(&b.depth_in()))
+            .unwrap_or(LumberSize::Custom)
+    }
+
+    /// Standard sizes whose actual width matches `width_in`
+    fn same_width(width_in: f64) -> impl Iterator<Item = LumberSize> {
+        Self::ALL
+            .into_iter()
+            .filter(move |s| !s.is_custom() && (s.width_in() - width_in).abs() < 0.01)
+    }
+}
+
+/// Snap a computed section to the nearest standard size
+///
+/// Matches the actual width exactly (e.g. 1.5 for 2x members) and picks the
+/// standard depth closest to `depth_in`. Returns `None` if the width is not
+/// a standard actual width.
+pub fn nearest_standard(width_in: f64, depth_in: f64) -> Option<LumberSize> {
+    LumberSize::same_width(width_in)
+        .min_by(|a, b| (a.depth_in() - depth_in).abs().total_cmp(&(b.depth_in() - depth_in).abs()))
 }
 
 impl std::fmt::Display for LumberSize {
@@ -250,6 +283,7 @@ impl std::fmt::Display for LumberSize {
 ///
 /// Standard configurations for multi-ply beams (e.g., 2-2x10, 3-2x12).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum PlyCount {
     /// Single member (1 ply)
     #[default]
@@ -383,9 +417,11 @@ impl BeamDesignation {
     }
 
     /// Self-weight per linear foot (plf), assuming 35 pcf wood density
+    ///
+    /// A designation has no species; use [`super::Material::density_pcf`]
+    /// when the material is known.
     pub fn self_weight_plf(&self) -> f64 {
-        const WOOD_DENSITY_PCF: f64 = 35.0;
-        self.total_area_in2() * WOOD_DENSITY_PCF / 144.0
+        self.total_area_in2() * super::DEFAULT_WOOD_DENSITY_PCF / 144.0
     }
 }
 
@@ -498,4 +534,18 @@ mod tests {
         let parsed: BeamDesignation = serde_json::from_str(&json).unwrap();
         assert_eq!(beam, parsed);
     }
+
+    #[test]
+    fn test_round_to_standard_depth() {
+        assert_eq!(LumberSize::L2x4.round_up_depth(9.1), LumberSize::L2x10);
+        assert_eq!(LumberSize::L2x4.round_up_depth(9.3), LumberSize::L2x12);
+        assert_eq!(LumberSize::L2x4.round_up_depth(9.25), LumberSize::L2x10);
+        assert_eq!(LumberSize::L4x4.round_up_depth(9.3), LumberSize::L4x12);
+        assert_eq!(LumberSize::L2x4.round_up_depth(14.0), LumberSize::Custom);
+
+        assert_eq!(nearest_standard(1.5, 9.1), Some(LumberSize::L2x10));
+        assert_eq!(nearest_standard(1.5, 10.5), Some(LumberSize::L2x12));
+        assert_eq!(nearest_standard(5.5, 9.0), Some(LumberSize::L6x10));
+        assert_eq!(nearest_standard(2.0, 9.25), None);
+    }
 }
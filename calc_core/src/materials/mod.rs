@@ -9,6 +9,7 @@
 //! - **Glulam**: Structural glued laminated timber per NDS-S
 //! - **LVL**: Laminated Veneer Lumber
 //! - **PSL**: Parallel Strand Lumber
+//! - **Steel**: AISC rolled shapes (see [`steel`])
 //!
 //! ## Example
 //!
@@ -35,7 +36,7 @@ pub mod steel;
 pub use sawn_lumber::{WoodSpecies, WoodGrade, WoodProperties, WoodMaterial};
 
 // Re-export lumber size types
-pub use lumber_sizes::{LumberSize, PlyCount, BeamDesignation};
+pub use lumber_sizes::{nearest_standard, LumberSize, PlyCount, BeamDesignation};
 
 // Re-export engineered wood types
 pub use engineered_wood::{
@@ -45,10 +46,16 @@ pub use engineered_wood::{
 };
 
 // Re-export steel types
-pub use steel::{ShapeType, SteelShape, SteelShapeDb, builtin_common_shapes};
+pub use steel::{ShapeType, SteelShape, SteelShapeDb, builtin_common_shapes, STEEL_E_KSI};
 
 use serde::{Deserialize, Serialize};
 
+/// Unit weight of water (pcf)
+pub const WATER_UNIT_WEIGHT_PCF: f64 = 62.4;
+
+/// Wood density for self-weight when no specific gravity is known (pcf)
+pub const DEFAULT_WOOD_DENSITY_PCF: f64 = 35.0;
+
 /// Unified material properties for all wood types
 ///
 /// This provides a common interface for calculations that need to work
@@ -73,6 +80,21 @@ pub struct UnifiedWoodProperties {
     pub specific_gravity: f64,
 }
 
+impl UnifiedWoodProperties {
+    /// Density at a moisture content in percent (pcf)
+    ///
+    /// ρ = 62.4·G / (1 + 0.009·G·m) · (1 + m/100), with G on the oven-dry
+    /// basis tabulated by NDS. Falls back to [`DEFAULT_WOOD_DENSITY_PCF`]
+    /// when the specific gravity is unknown.
+    pub fn density_pcf(&self, moisture_content_pct: f64) -> f64 {
+        let g = self.specific_gravity;
+        if g <= 0.0 {
+            return DEFAULT_WOOD_DENSITY_PCF;
+        }
+        WATER_UNIT_WEIGHT_PCF * g / (1.0 + 0.009 * g * moisture_content_pct) * (1.0 + moisture_content_pct / 100.0)
+    }
+}
+
 /// Unified material enum for all structural materials
 ///
 /// This enum allows beam and column calculations to work with any
@@ -96,6 +118,7 @@ pub struct UnifiedWoodProperties {
 /// { "type": "Psl", "grade": "PSL-2.0E" }
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum Material {
     /// Sawn dimensional lumber (NDS Table 4A)
@@ -183,6 +206,17 @@ impl Material {
         }
     }
 
+    /// Get Fb for negative (hogging) moment adjusted for member depth
+    ///
+    /// Unbalanced glulam layups use the lower Fb-; all other materials
+    /// return the same value as `fb_for_depth`.
+    pub fn fb_negative_for_depth(&self, depth_in: f64) -> f64 {
+        match self {
+            Material::Glulam(mat) => mat.properties().fb_for_moment(false, mat.layup),
+            _ => self.fb_for_depth(depth_in),
+        }
+    }
+
     /// Get display name for this material
     pub fn display_name(&self) -> String {
         match self {
@@ -207,6 +241,15 @@ impl Material {
     pub fn is_engineered(&self) -> bool {
         !matches!(self, Material::SawnLumber(_))
     }
+
+    /// Density for self-weight (pcf), from the specific gravity
+    ///
+    /// Sawn lumber is taken at 19% moisture, the dry service limit; glulam
+    /// and SCL are manufactured dry and taken at 12%.
+    pub fn density_pcf(&self) -> f64 {
+        let moisture_content_pct = if self.is_engineered() { 12.0 } else { 19.0 };
+        self.base_properties().density_pcf(moisture_content_pct)
+    }
 }
 
 impl Default for Material {
@@ -249,6 +292,50 @@ impl From<PslMaterial> for Material {
     }
 }
 
+/// Rank candidate materials by how closely they match a target material.
+///
+/// Compares depth-adjusted Fb (`fb_for_depth`) and E at the given member depth.
+/// The similarity score is `1 / (1 + |dFb|/Fb + |dE|/E)` using the target's values
+/// as the reference, so an identical material scores 1.0 and the score falls
+/// toward 0 as either property diverges. Results are sorted best match first.
+///
+/// # Example
+///
+/// ```rust
+/// use calc_core::materials::{find_equivalent, Material, WoodGrade, WoodMaterial, WoodSpecies};
+///
+/// let target = Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No1));
+/// let candidates = vec![
+///     Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2)),
+///     Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::SelectStructural)),
+/// ];
+/// let ranked = find_equivalent(&target, &candidates, 9.25);
+/// assert_eq!(ranked.len(), 2);
+/// assert!(ranked[0].1 >= ranked[1].1);
+/// ```
+pub fn find_equivalent(
+    target: &Material,
+    candidates: &[Material],
+    depth_in: f64,
+) -> Vec<(Material, f64)> {
+    let target_fb = target.fb_for_depth(depth_in);
+    let target_e = target.base_properties().e_psi;
+
+    let mut ranked: Vec<(Material, f64)> = candidates
+        .iter()
+        .map(|candidate| {
+            let fb = candidate.fb_for_depth(depth_in);
+            let e = candidate.base_properties().e_psi;
+            let fb_diff = (fb - target_fb).abs() / target_fb;
+            let e_diff = (e - target_e).abs() / target_e;
+            (candidate.clone(), 1.0 / (1.0 + fb_diff + e_diff))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    ranked
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,4 +471,25 @@ mod tests {
             assert_eq!(wood.grade, WoodGrade::No2);
         }
     }
+
+    #[test]
+    fn test_find_equivalent_ranks_psl_above_low_grade_lumber() {
+        let target = Material::Lvl(LvlMaterial::new(LvlGrade::Standard));
+        let low_grade = Material::SawnLumber(WoodMaterial::new(
+            WoodSpecies::DouglasFirLarch,
+            WoodGrade::No3,
+        ));
+        let psl = Material::Psl(PslMaterial::new(PslGrade::Standard));
+
+        let ranked = find_equivalent(&target, &[low_grade.clone(), psl.clone()], 11.875);
+
+        assert_eq!(ranked[0].0, psl);
+        assert_eq!(ranked[1].0, low_grade);
+        assert!(ranked[0].1 > ranked[1].1);
+        assert!(ranked.iter().all(|(_, score)| *score > 0.0 && *score <= 1.0));
+
+        // An identical material is a perfect match
+        let same = find_equivalent(&target, std::slice::from_ref(&target), 11.875);
+        assert!((same[0].1 - 1.0).abs() < 1e-12);
+    }
 }
@@ -14,6 +14,7 @@ use crate::units::Psi;
 
 /// Wood species groups per NDS
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "SCREAMING-KEBAB-CASE")]
 pub enum WoodSpecies {
     /// Douglas Fir-Larch
@@ -86,6 +87,7 @@ impl std::fmt::Display for WoodSpecies {
 
 /// Wood grades per NDS
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum WoodGrade {
     /// Select Structural
     #[serde(rename = "SS")]
@@ -300,6 +302,7 @@ impl WoodProperties {
 
 /// Combined wood material identifier for serialization
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct WoodMaterial {
     pub species: WoodSpecies,
     pub grade: WoodGrade,
@@ -38,6 +38,9 @@ use std::str::FromStr;
 
 use crate::errors::{CalcError, CalcResult};
 
+/// Modulus of elasticity of structural steel E (ksi)
+pub const STEEL_E_KSI: f64 = 29_000.0;
+
 /// Steel shape type classification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ShapeType {
@@ -618,6 +621,10 @@ fn parse_optional_f64(s: &str) -> Option<f64> {
 ///
 /// This provides a minimal set of shapes for testing and demos without
 /// requiring the full AISC CSV file.
+///
+/// The warping constant, rts, and ho are derived from the tabulated
+/// properties for a doubly symmetric I-shape (ho = d - tf, Cw = Iy·ho²/4).
+/// h/tw takes h = d - 2tf, slightly overstating the AISC value.
 pub fn builtin_common_shapes() -> SteelShapeDb {
     let mut db = SteelShapeDb::new();
 
@@ -672,6 +679,8 @@ pub fn builtin_common_shapes() -> SteelShapeDb {
     ];
 
     for (label, w, a, d, bf, tf, tw, ix, sx, rx, zx, iy, sy, ry, zy, j) in common_w_shapes {
+        let ho = d - tf;
+        let cw = iy * ho * ho / 4.0;
         db.insert(SteelShape {
             shape_type: ShapeType::W,
             label: label.to_string(),
@@ -699,11 +708,11 @@ pub fn builtin_common_shapes() -> SteelShapeDb {
             ry_in: ry,
             zy_in3: zy,
             j_in4: j,
-            cw_in6: None,
-            rts_in: None,
-            ho_in: None,
+            cw_in6: Some(cw),
+            rts_in: Some(((iy * cw).sqrt() / sx).sqrt()),
+            ho_in: Some(ho),
             bf_2tf: Some(bf / (2.0 * tf)),
-            h_tw: None,
+            h_tw: Some((d - 2.0 * tf) / tw),
             d_t: None,
         });
     }
@@ -775,6 +784,16 @@ mod tests {
         assert!(slenderness > 0.0);
     }
 
+    #[test]
+    fn test_builtin_flexural_torsional_properties() {
+        // AISC Manual: W18X35 rts = 1.51 in, ho = 17.3 in
+        let db = builtin_common_shapes();
+        let shape = db.lookup("W18X35").unwrap();
+        assert!((shape.rts_in.unwrap() - 1.51).abs() < 0.01);
+        assert!((shape.ho_in.unwrap() - 17.3).abs() < 0.05);
+        assert!(shape.h_tw.unwrap() > 53.5);
+    }
+
     #[test]
     fn test_shape_display() {
         let db = builtin_common_shapes();
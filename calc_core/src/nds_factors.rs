@@ -10,7 +10,9 @@
 //! ```text
 //! Fb' = Fb × C_D × C_M × C_t × C_L × C_F × C_fu × C_i × C_r
 //! Fv' = Fv × C_D × C_M × C_t × C_i
+//! Ft' = Ft × C_D × C_M × C_t × C_F × C_i
 //! E'  = E  × C_M × C_t × C_i
+//! Fc⊥' = Fc⊥ × C_M × C_t × C_i × C_b
 //! ```
 //!
 //! ## Factor Summary
@@ -24,7 +26,11 @@
 //! | C_F    | Size factor              | 0.9 - 1.5         |
 //! | C_fu   | Flat use                 | 1.0 - 1.2         |
 //! | C_i    | Incising                 | 0.80 - 1.0        |
-//! | C_r    | Repetitive member        | 1.0 or 1.15       |
+//! | C_r    | Repetitive member        | 1.0, 1.04 or 1.15 |
+//! | C_b    | Bearing area             | 1.0 - 1.75        |
+//!
+//! C_F, C_i and C_r differ between sawn lumber, glulam and structural
+//! composite lumber; see [`FbFactors`].
 //!
 //! ## Reference
 //!
@@ -32,6 +38,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::loads::LoadType;
+use crate::materials::Material;
+
 // ============================================================================
 // NDS Code Section References
 // ============================================================================
@@ -66,6 +75,8 @@ pub mod nds_ref {
     pub const C_I: &str = "NDS 4.3.8";
     /// Repetitive member factor C_r
     pub const C_R: &str = "NDS 4.3.9";
+    /// Bearing area factor C_b
+    pub const C_B: &str = "NDS 3.10.4";
     /// Modulus of elasticity adjustment
     pub const E_ADJUSTMENT: &str = "NDS 5.4.2";
 
@@ -83,6 +94,7 @@ pub mod nds_ref {
 /// Accounts for the cumulative effect of load duration on wood strength.
 /// Wood can sustain higher stresses for short durations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum LoadDuration {
     /// Permanent loads (> 10 years): C_D = 0.9
     /// Examples: Dead load only
@@ -121,6 +133,20 @@ impl LoadDuration {
         LoadDuration::Impact,
     ];
 
+    /// Duration of a load type per NDS Table 2.3.2
+    ///
+    /// Roof live load is treated as construction load (7 days). Rain and
+    /// self-straining forces take the normal 10-year duration.
+    pub fn for_load_type(load_type: LoadType) -> Self {
+        match load_type {
+            LoadType::Dead | LoadType::SoilLateral | LoadType::Fluid => LoadDuration::Permanent,
+            LoadType::Live | LoadType::Rain | LoadType::SelfStraining => LoadDuration::Normal,
+            LoadType::LiveRoof => LoadDuration::Construction,
+            LoadType::Snow => LoadDuration::Snow,
+            LoadType::Wind | LoadType::Seismic => LoadDuration::WindSeismic,
+        }
+    }
+
     /// Get the C_D factor value
     pub fn factor(&self) -> f64 {
         match self {
@@ -154,6 +180,7 @@ impl std::fmt::Display for LoadDuration {
 
 /// Wet service condition for C_M factor
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum WetService {
     /// Dry conditions (MC ≤ 19%): C_M = 1.0
     /// Most interior applications
@@ -219,6 +246,16 @@ impl WetService {
         }
     }
 
+    /// Creep factor K_cr for long-term deflection (NDS 3.5.2)
+    ///
+    /// 1.5 for seasoned lumber, glulam, and SCL in dry service; 2.0 in wet service.
+    pub fn creep_factor(&self) -> f64 {
+        match self {
+            WetService::Dry => 1.5,
+            WetService::Wet => 2.0,
+        }
+    }
+
     /// Display name for UI
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -234,8 +271,16 @@ impl std::fmt::Display for WetService {
     }
 }
 
+/// Highest sustained temperature covered by NDS Table 2.3.3 (°F)
+pub const MAX_TEMPERATURE_F: f64 = 150.0;
+
 /// Temperature condition for C_t factor
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+///
+/// The named conditions stand for the top of their NDS Table 2.3.3 range
+/// (100, 125, and 150°F) and give the tabulated factors. `Custom` takes a
+/// sustained temperature and interpolates between those breakpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Temperature {
     /// Normal temperature (T ≤ 100°F): C_t = 1.0
     #[default]
@@ -246,6 +291,9 @@ pub enum Temperature {
 
     /// High temperature (125°F < T ≤ 150°F)
     High,
+
+    /// Sustained temperature in °F, e.g. near mechanical equipment
+    Custom(f64),
 }
 
 impl Temperature {
@@ -256,31 +304,52 @@ impl Temperature {
         Temperature::High,
     ];
 
-    /// Get C_t factor for Fb, Fv, Fc, E (dry conditions)
-    /// NDS Table 2.3.3
-    pub fn factor_dry(&self) -> f64 {
+    /// Sustained temperature this condition represents (°F)
+    pub fn degrees_f(&self) -> f64 {
         match self {
-            Temperature::Normal => 1.0,
-            Temperature::Elevated => 0.8,
-            Temperature::High => 0.7,
+            Temperature::Normal => 100.0,
+            Temperature::Elevated => 125.0,
+            Temperature::High => MAX_TEMPERATURE_F,
+            Temperature::Custom(t) => *t,
         }
     }
 
-    /// Get C_t factor for Fb, Fv, Fc, E (wet conditions)
+    /// Get C_t factor for Fb, Fv, Fc, Fc⊥ (dry conditions)
+    /// NDS Table 2.3.3
+    pub fn factor_dry(&self) -> f64 {
+        self.factor(WetService::Dry)
+    }
+
+    /// Get C_t factor for Fb, Fv, Fc, Fc⊥ (wet conditions)
     /// NDS Table 2.3.3
     pub fn factor_wet(&self) -> f64 {
-        match self {
-            Temperature::Normal => 1.0,
-            Temperature::Elevated => 0.7,
-            Temperature::High => 0.5,
-        }
+        self.factor(WetService::Wet)
     }
 
-    /// Get C_t factor based on moisture condition
+    /// Get C_t factor for Fb, Fv, Fc, Fc⊥ based on moisture condition
+    ///
+    /// Between the table breakpoints the factor is interpolated linearly,
+    /// which is less conservative than the stepped table inside a range.
     pub fn factor(&self, wet_service: WetService) -> f64 {
         match wet_service {
-            WetService::Dry => self.factor_dry(),
-            WetService::Wet => self.factor_wet(),
+            WetService::Dry => self.interpolate(0.8, 0.7),
+            WetService::Wet => self.interpolate(0.7, 0.5),
+        }
+    }
+
+    /// Get C_t factor for Ft, E, Emin (the same wet or dry)
+    /// NDS Table 2.3.3
+    pub fn factor_e(&self) -> f64 {
+        self.interpolate(0.9, 0.9)
+    }
+
+    /// Linear through 1.0 at 100°F, `at_125` at 125°F, and `at_150` at 150°F
+    fn interpolate(&self, at_125: f64, at_150: f64) -> f64 {
+        let t = self.degrees_f().clamp(100.0, MAX_TEMPERATURE_F);
+        if t <= 125.0 {
+            1.0 + (at_125 - 1.0) * (t - 100.0) / 25.0
+        } else {
+            at_125 + (at_150 - at_125) * (t - 125.0) / 25.0
         }
     }
 
@@ -290,18 +359,23 @@ impl Temperature {
             Temperature::Normal => "Normal (≤ 100°F)",
             Temperature::Elevated => "Elevated (100-125°F)",
             Temperature::High => "High (125-150°F)",
+            Temperature::Custom(_) => "Custom",
         }
     }
 }
 
 impl std::fmt::Display for Temperature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display_name())
+        match self {
+            Temperature::Custom(t) => write!(f, "Custom ({:.0}°F)", t),
+            _ => write!(f, "{}", self.display_name()),
+        }
     }
 }
 
 /// Incising treatment condition for C_i factor
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Incising {
     /// Not incised: C_i = 1.0
     #[default]
@@ -332,6 +406,15 @@ impl Incising {
         }
     }
 
+    /// Get C_i factor for compression perpendicular to grain (Fc⊥)
+    ///
+    /// NDS Table 4.3.8 does not reduce Fc⊥ for incising.
+    pub fn factor_fc_perp(&self) -> f64 {
+        match self {
+            Incising::None | Incising::Incised => 1.0,
+        }
+    }
+
     /// Display name for UI
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -352,6 +435,7 @@ impl std::fmt::Display for Incising {
 /// Per NDS 4.3.9: Applies when 3 or more members spaced ≤ 24" OC
 /// are joined by floor, roof, or other load-distributing elements.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum RepetitiveMember {
     /// Single member or > 24" spacing: C_r = 1.0
     #[default]
@@ -373,6 +457,14 @@ impl RepetitiveMember {
         }
     }
 
+    /// Get C_r factor for structural composite lumber (NDS 8.3.7)
+    pub fn factor_scl(&self) -> f64 {
+        match self {
+            RepetitiveMember::Single => 1.0,
+            RepetitiveMember::Repetitive => 1.04,
+        }
+    }
+
     /// Display name for UI
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -473,11 +565,55 @@ impl SizeFactor {
     }
 }
 
+/// Fb adjustment factors that depend on the material
+///
+/// | Material  | C_F      | C_fu | C_i | C_r  |
+/// |-----------|----------|------|-----|------|
+/// | Sawn      | Table 4A | yes  | yes | 1.15 |
+/// | Glulam    | -        | yes  | -   | -    |
+/// | LVL / PSL | -        | yes  | -   | 1.04 |
+///
+/// LVL and PSL take their depth effect from the manufacturer's depth
+/// factor in [`Material::fb_for_depth`], so C_F would count it twice.
+/// Glulam's volume factor C_V is not modeled. Incising applies to sawn
+/// lumber only (NDS 4.3.8), and NDS Table 5.3.1 has no C_r for glulam.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FbFactors {
+    /// Size factor C_F
+    pub c_f: f64,
+    /// Flat use factor C_fu
+    pub c_fu: f64,
+    /// Incising factor C_i
+    pub c_i: f64,
+    /// Repetitive member factor C_r
+    pub c_r: f64,
+}
+
+impl FbFactors {
+    /// C_F × C_fu × C_i × C_r
+    pub fn product(&self) -> f64 {
+        self.c_f * self.c_fu * self.c_i * self.c_r
+    }
+}
+
+/// Bearing area factor C_b per NDS 3.10.4
+///
+/// For bearings shorter than 6 in and not nearer than 3 in to the end of
+/// the member, C_b = (lb + 0.375)/lb. Bearings at a member end take 1.0.
+pub fn bearing_area_factor(bearing_length_in: f64, at_member_end: bool) -> f64 {
+    if at_member_end || bearing_length_in >= 6.0 {
+        1.0
+    } else {
+        (bearing_length_in + 0.375) / bearing_length_in
+    }
+}
+
 /// Flat use factor (C_fu) per NDS Table 4.3.7
 ///
 /// Applies when lumber is loaded on wide face (bending about weak axis).
 /// Only applies to bending design value Fb.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum FlatUse {
     /// Normal use (loaded on narrow face): C_fu = 1.0
     #[default]
@@ -619,6 +755,7 @@ impl BeamStability {
 /// This struct collects all the factors that affect Fb', Fv', E', etc.
 /// and provides methods to calculate adjusted design values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AdjustmentFactors {
     /// Load duration factor selection
     pub load_duration: LoadDuration,
@@ -645,6 +782,21 @@ pub struct AdjustmentFactors {
     /// Unbraced length for beam stability (le) in inches
     /// Only used if compression_edge_braced is false
     pub unbraced_length_in: Option<f64>,
+
+    /// Compression-edge brace points along the beam (ft from the left end)
+    ///
+    /// Only used if compression_edge_braced is false. Each span is split at
+    /// its brace points and C_L taken from the least stable segment, in
+    /// place of `unbraced_length_in`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub brace_points_ft: Vec<f64>,
+
+    /// Take C_D from the shortest-duration load in each combination
+    ///
+    /// When set, `load_duration` is ignored and the governing combination is
+    /// the one with the highest unity ratio rather than the largest moment.
+    #[serde(default)]
+    pub load_duration_by_combination: bool,
 }
 
 impl Default for AdjustmentFactors {
@@ -658,6 +810,8 @@ impl Default for AdjustmentFactors {
             flat_use: FlatUse::default(),
             compression_edge_braced: true, // Conservative default: assume braced
             unbraced_length_in: None,
+            brace_points_ft: Vec::new(),
+            load_duration_by_combination: false,
         }
     }
 }
@@ -674,6 +828,12 @@ impl AdjustmentFactors {
         self
     }
 
+    /// Take C_D from the loads in each combination (NDS 2.3.2)
+    pub fn with_load_duration_by_combination(mut self, by_combination: bool) -> Self {
+        self.load_duration_by_combination = by_combination;
+        self
+    }
+
     /// Set wet service condition
     pub fn with_wet_service(mut self, wet: WetService) -> Self {
         self.wet_service = wet;
@@ -711,6 +871,31 @@ impl AdjustmentFactors {
         self
     }
 
+    /// Brace the compression edge at discrete points (builder pattern)
+    pub fn with_brace_points(mut self, brace_points_ft: Vec<f64>) -> Self {
+        self.compression_edge_braced = false;
+        self.brace_points_ft = brace_points_ft;
+        self
+    }
+
+    /// Unbraced segments between `start_ft` and `end_ft` along the beam
+    ///
+    /// The range is split at the brace points strictly inside it; its ends
+    /// are taken as braced. Returns (start, end) pairs in ft.
+    pub fn unbraced_segments_ft(&self, start_ft: f64, end_ft: f64) -> Vec<(f64, f64)> {
+        let mut bounds: Vec<f64> = self
+            .brace_points_ft
+            .iter()
+            .copied()
+            .filter(|x| *x > start_ft && *x < end_ft)
+            .collect();
+        bounds.sort_by(f64::total_cmp);
+        bounds.dedup();
+        bounds.insert(0, start_ft);
+        bounds.push(end_ft);
+        bounds.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
     /// Get C_D factor
     pub fn c_d(&self) -> f64 {
         self.load_duration.factor()
@@ -726,16 +911,36 @@ impl AdjustmentFactors {
         self.wet_service.factor_fv()
     }
 
+    /// Get C_M factor for tension parallel to grain
+    pub fn c_m_ft(&self) -> f64 {
+        self.wet_service.factor_ft()
+    }
+
     /// Get C_M factor for modulus of elasticity
     pub fn c_m_e(&self) -> f64 {
         self.wet_service.factor_e()
     }
 
-    /// Get C_t factor
+    /// Get C_M factor for compression perpendicular to grain
+    pub fn c_m_fc_perp(&self) -> f64 {
+        self.wet_service.factor_fc_perp()
+    }
+
+    /// Get K_cr creep factor for long-term deflection
+    pub fn k_cr(&self) -> f64 {
+        self.wet_service.creep_factor()
+    }
+
+    /// Get C_t factor for Fb, Fv, Fc, Fc⊥
     pub fn c_t(&self) -> f64 {
         self.temperature.factor(self.wet_service)
     }
 
+    /// Get C_t factor for Ft, E, Emin
+    pub fn c_t_e(&self) -> f64 {
+        self.temperature.factor_e()
+    }
+
     /// Get C_i factor for strength
     pub fn c_i_strength(&self) -> f64 {
         self.incising.factor_strength()
@@ -746,6 +951,18 @@ impl AdjustmentFactors {
         self.incising.factor_e()
     }
 
+    /// Get C_i factor for Fc⊥
+    pub fn c_i_fc_perp(&self) -> f64 {
+        self.incising.factor_fc_perp()
+    }
+
+    /// Get C_b factor for a bearing not nearer than 3 in to the member end
+    ///
+    /// See [`bearing_area_factor`]; bearings at a member end take 1.0.
+    pub fn c_b(&self, bearing_length_in: f64) -> f64 {
+        bearing_area_factor(bearing_length_in, false)
+    }
+
     /// Get C_r factor
     pub fn c_r(&self) -> f64 {
         self.repetitive_member.factor()
@@ -780,6 +997,38 @@ impl AdjustmentFactors {
             * self.c_r()
     }
 
+    /// The Fb factors that apply to `material`, per [`FbFactors`]
+    pub fn fb_factors(&self, material: &Material, depth_in: f64, width_in: f64) -> FbFactors {
+        let c_fu = self.c_fu(width_in);
+        match material {
+            Material::SawnLumber(_) => FbFactors {
+                c_f: SizeFactor::new(depth_in, width_in).factor_fb(),
+                c_fu,
+                c_i: self.c_i_strength(),
+                c_r: self.c_r(),
+            },
+            Material::Glulam(_) => FbFactors {
+                c_f: 1.0,
+                c_fu,
+                c_i: 1.0,
+                c_r: 1.0,
+            },
+            Material::Lvl(_) | Material::Psl(_) => FbFactors {
+                c_f: 1.0,
+                c_fu,
+                c_i: 1.0,
+                c_r: self.repetitive_member.factor_scl(),
+            },
+        }
+    }
+
+    /// Calculate adjusted bending stress Fb' from material-specific factors
+    ///
+    /// Fb' = Fb × C_D × C_M × C_t × C_L × (C_F × C_fu × C_i × C_r)
+    pub fn adjusted_fb_with(&self, fb_reference: f64, fb_factors: FbFactors, c_l: f64) -> f64 {
+        fb_reference * self.c_d() * self.c_m_fb() * self.c_t() * c_l * fb_factors.product()
+    }
+
     /// Calculate adjusted shear stress Fv'
     ///
     /// Fv' = Fv × C_D × C_M × C_t × C_i
@@ -791,18 +1040,43 @@ impl AdjustmentFactors {
             * self.c_i_strength()
     }
 
+    /// Calculate adjusted tension parallel to grain Ft'
+    ///
+    /// Ft' = Ft × C_D × C_M × C_t × C_F × C_i
+    pub fn adjusted_ft(&self, ft_reference: f64, c_f: f64) -> f64 {
+        ft_reference
+            * self.c_d()
+            * self.c_m_ft()
+            * self.c_t_e()
+            * c_f
+            * self.c_i_strength()
+    }
+
     /// Calculate adjusted modulus of elasticity E'
     ///
     /// E' = E × C_M × C_t × C_i
     pub fn adjusted_e(&self, e_reference: f64) -> f64 {
-        e_reference * self.c_m_e() * self.c_t() * self.c_i_e()
+        e_reference * self.c_m_e() * self.c_t_e() * self.c_i_e()
+    }
+
+    /// Calculate adjusted compression perpendicular to grain Fc⊥'
+    ///
+    /// Fc⊥' = Fc⊥ × C_M × C_t × C_i × C_b
+    ///
+    /// Pass `None` for a bearing at the member end, where C_b = 1.0.
+    pub fn adjusted_fc_perp(&self, fc_perp_reference: f64, bearing_length_in: Option<f64>) -> f64 {
+        fc_perp_reference
+            * self.c_m_fc_perp()
+            * self.c_t()
+            * self.c_i_fc_perp()
+            * bearing_length_in.map_or(1.0, |length| self.c_b(length))
     }
 
     /// Calculate adjusted minimum E (E'min) for stability calculations
     ///
     /// E'min = Emin × C_M × C_t × C_i
     pub fn adjusted_e_min(&self, e_min_reference: f64) -> f64 {
-        e_min_reference * self.c_m_e() * self.c_t() * self.c_i_e()
+        e_min_reference * self.c_m_e() * self.c_t_e() * self.c_i_e()
     }
 
     /// Get a summary of all applied factors for reporting
@@ -937,6 +1211,35 @@ mod tests {
         assert_eq!(Temperature::Elevated.factor(WetService::Wet), 0.7);
     }
 
+    #[test]
+    fn test_custom_temperature_interpolation() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+        // At 100°F no reduction applies
+        let t100 = Temperature::Custom(100.0);
+        assert!(close(t100.factor(WetService::Dry), 1.0));
+        assert!(close(t100.factor(WetService::Wet), 1.0));
+        assert!(close(t100.factor_e(), 1.0));
+
+        // At 150°F the table values: 0.7 dry, 0.5 wet, 0.9 for E
+        let t150 = Temperature::Custom(150.0);
+        assert!(close(t150.factor(WetService::Dry), 0.7));
+        assert!(close(t150.factor(WetService::Wet), 0.5));
+        assert!(close(t150.factor_e(), 0.9));
+
+        // Named conditions keep their tabulated values
+        assert!(close(Temperature::High.factor(WetService::Wet), 0.5));
+        assert!(close(Temperature::Custom(125.0).factor_dry(), Temperature::Elevated.factor_dry()));
+
+        // Between breakpoints: 0.8 + (0.7 - 0.8) × 10/25 at 135°F dry
+        assert!(close(Temperature::Custom(135.0).factor_dry(), 0.76));
+        assert!(close(Temperature::Custom(70.0).factor_dry(), 1.0));
+
+        let factors = AdjustmentFactors::default().with_temperature(Temperature::Custom(150.0));
+        assert!(close(factors.c_t(), 0.7));
+        assert!(close(factors.adjusted_e(1_000_000.0), 900_000.0));
+    }
+
     #[test]
     fn test_size_factor_fb() {
         let sf = SizeFactor::new(9.25, 1.5);
@@ -998,6 +1301,44 @@ mod tests {
         assert!((fv_adj - 207.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_fb_factors_by_material() {
+        use crate::materials::{GlulamLayup, GlulamMaterial, GlulamStressClass, WoodGrade, WoodMaterial, WoodSpecies};
+
+        let factors = AdjustmentFactors::new()
+            .with_incising(Incising::Incised)
+            .with_repetitive(RepetitiveMember::Repetitive);
+
+        let sawn = Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2));
+        let sawn_factors = factors.fb_factors(&sawn, 5.5, 1.5);
+        assert_eq!(sawn_factors.c_f, 1.3);
+        assert_eq!(sawn_factors.c_i, factors.c_i_strength());
+        assert_eq!(sawn_factors.c_r, 1.15);
+
+        // Glulam takes neither C_F, C_i nor C_r
+        let glulam = Material::Glulam(GlulamMaterial::new(GlulamStressClass::F20_E1_5, GlulamLayup::Balanced));
+        assert_eq!(factors.fb_factors(&glulam, 12.0, 5.125).product(), 1.0);
+        assert_eq!(factors.adjusted_fb_with(2000.0, factors.fb_factors(&glulam, 12.0, 5.125), 0.9), 1800.0);
+    }
+
+    #[test]
+    fn test_adjusted_fc_perp_calculation() {
+        let factors = AdjustmentFactors::new();
+        assert_eq!(factors.adjusted_fc_perp(625.0, Some(6.0)), 625.0);
+        assert_eq!(factors.adjusted_fc_perp(625.0, None), 625.0);
+
+        // NDS Table 3.10.4: 1.5" bearing → 1.25, 2" bearing → 1.19
+        assert!((factors.c_b(1.5) - 1.25).abs() < 1e-12);
+        assert!((factors.c_b(2.0) - 1.19).abs() < 0.005);
+        assert!((factors.adjusted_fc_perp(625.0, Some(1.5)) - 781.25).abs() < 1e-9);
+
+        // Incising does not reduce Fc⊥; wet service does
+        let incised = factors.clone().with_incising(Incising::Incised);
+        assert_eq!(incised.adjusted_fc_perp(625.0, None), 625.0);
+        let wet = AdjustmentFactors::new().with_wet_service(WetService::Wet);
+        assert!((wet.adjusted_fc_perp(625.0, None) - 625.0 * 0.67).abs() < 1e-9);
+    }
+
     #[test]
     fn test_adjustment_summary() {
         let factors = AdjustmentFactors::new();
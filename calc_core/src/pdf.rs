@@ -31,14 +31,14 @@
 //! );
 //!
 //! let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
-//! let pdf_bytes = render_beam_pdf(&input, &result, "John Engineer", "25-001").unwrap();
+//! let pdf_bytes = render_beam_pdf(&input, &result, DesignMethod::Asd, "John Engineer", "25-001").unwrap();
 //! std::fs::write("beam_report.pdf", pdf_bytes).unwrap();
 //! ```
 
 use chrono::Utc;
 use typst::diag::{FileError, FileResult};
 use typst::foundations::{Bytes, Datetime};
-use typst::syntax::{FileId, Source};
+use typst::syntax::{FileId, Source, VirtualPath};
 use typst::text::{Font, FontBook};
 use typst::utils::LazyHash;
 use typst::{Library, LibraryExt, World};
@@ -46,10 +46,11 @@ use typst_pdf::PdfOptions;
 
 use crate::calculations::continuous_beam::{calculate_continuous, ContinuousBeamInput, ContinuousBeamResult};
 use crate::calculations::CalculationItem;
-use crate::equations::registry::{beam_calculation_equations, EquationTracker};
+use crate::equations::registry::EquationTracker;
 use crate::errors::{CalcError, CalcResult};
+use crate::loads::DesignMethod;
 use crate::nds_factors::nds_ref;
-use crate::project::Project;
+use crate::project::{FirmLogo, Project, ProjectMetadata};
 
 // ============================================================================
 // Typst World Implementation
@@ -65,8 +66,13 @@ struct PdfWorld {
     fonts: Vec<Font>,
     /// Library (standard functions)
     library: LazyHash<Library>,
+    /// Firm logo served at `LOGO_PATH`
+    logo: Option<Bytes>,
 }
 
+/// Virtual path the firm logo is served from
+const LOGO_PATH: &str = "/logo";
+
 impl PdfWorld {
     fn new(source: String) -> Self {
         let fonts = Self::load_fonts();
@@ -77,9 +83,15 @@ impl PdfWorld {
             book: LazyHash::new(book),
             fonts,
             library: LazyHash::new(Library::default()),
+            logo: None,
         }
     }
 
+    fn with_logo(mut self, logo: Option<&FirmLogo>) -> Self {
+        self.logo = logo.map(|logo| Bytes::new(logo.bytes.clone()));
+        self
+    }
+
     fn load_fonts() -> Vec<Font> {
         let mut fonts = Vec::new();
 
@@ -140,7 +152,12 @@ impl World for PdfWorld {
     }
 
     fn file(&self, id: FileId) -> FileResult<Bytes> {
-        Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        match &self.logo {
+            Some(logo) if id.package().is_none() && *id.vpath() == VirtualPath::new(LOGO_PATH) => {
+                Ok(logo.clone())
+            }
+            _ => Err(FileError::NotFound(id.vpath().as_rootless_path().into())),
+        }
     }
 
     fn font(&self, index: usize) -> Option<Font> {
@@ -212,7 +229,8 @@ const BEAM_TEMPLATE: &str = r##"
   [
     *Code Reference*
     #v(4pt)
-    NDS 2018 (National Design Specification for Wood Construction)
+    NDS 2018 (National Design Specification for Wood Construction) \
+    Load combinations: {{COMBINATIONS}}
   ]
 )
 
@@ -278,8 +296,9 @@ $ M_"max" = (w L^2) / 8 = {{MOMENT_FTLB}} "ft-lb" $
 
 $ V_"max" = (w L) / 2 = {{SHEAR_LB}} "lb" $
 
-$ delta_"max" = (5 w L^4) / (384 E I) = {{DEFLECTION_IN}} "in" $
+$ delta_"max" = (5 w L^4) / (384 E I) = {{DEFLECTION_IN}} "in ({{DEFLECTION_DIR}})" $
 
+{{DIAGRAMS}}
 #v(12pt)
 
 === Stress Checks
@@ -325,6 +344,7 @@ $ delta_"max" = (5 w L^4) / (384 E I) = {{DEFLECTION_IN}} "in" $
   Generated by Stratify Structural Engineering Suite \
   Calculations should be verified by a licensed professional engineer.
 ]
+{{APPENDIX}}
 "##;
 
 // ============================================================================
@@ -337,6 +357,7 @@ $ delta_"max" = (5 w L^4) / (384 E I) = {{DEFLECTION_IN}} "in" $
 ///
 /// * `input` - The beam input parameters
 /// * `result` - The calculation results
+/// * `method` - Design method `result` was calculated with
 /// * `engineer` - Engineer name for the report
 /// * `job_id` - Job/project ID
 ///
@@ -368,14 +389,55 @@ $ delta_"max" = (5 w L^4) / (384 E I) = {{DEFLECTION_IN}} "in" $
 /// );
 ///
 /// let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
-/// let pdf = render_beam_pdf(&input, &result, "John Engineer", "25-001").unwrap();
+/// let pdf = render_beam_pdf(&input, &result, DesignMethod::Asd, "John Engineer", "25-001").unwrap();
 /// ```
 pub fn render_beam_pdf(
     input: &ContinuousBeamInput,
     result: &ContinuousBeamResult,
+    method: DesignMethod,
     engineer: &str,
     job_id: &str,
 ) -> CalcResult<Vec<u8>> {
+    let source = beam_report_source(input, result, method, engineer, job_id)?;
+
+    // Compile the Typst document
+    let world = PdfWorld::new(source);
+
+    let warned = typst::compile(&world);
+
+    let document = warned.output.map_err(|errors| {
+        let error_msgs: Vec<String> = errors
+            .iter()
+            .map(|e| e.message.to_string())
+            .collect();
+        CalcError::Internal {
+            message: format!("Typst compilation failed: {}", error_msgs.join("; ")),
+        }
+    })?;
+
+    // Render to PDF
+    let pdf_bytes = typst_pdf::pdf(&document, &PdfOptions::default()).map_err(|errors| {
+        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
+        CalcError::Internal {
+            message: format!("PDF rendering failed: {}", error_msgs.join("; ")),
+        }
+    })?;
+
+    Ok(pdf_bytes)
+}
+
+/// Build the Typst source for a single-beam report.
+///
+/// Layout: inputs, section and material properties, demands with shear,
+/// moment, and deflection diagrams, stress checks, then an equations
+/// appendix limited to the equations this beam uses.
+fn beam_report_source(
+    input: &ContinuousBeamInput,
+    result: &ContinuousBeamResult,
+    method: DesignMethod,
+    engineer: &str,
+    job_id: &str,
+) -> CalcResult<String> {
     // Use the first span's properties (for single-span beams) or primary span
     let first_span = input.spans.first().ok_or_else(|| CalcError::invalid_input(
         "spans",
@@ -393,22 +455,23 @@ pub fn render_beam_pdf(
 
     // Calculate deflection ratio (L/delta)
     let l_in = input.total_length_ft() * 12.0;
-    let deflection_ratio = if result.max_deflection_in > 0.0 {
-        l_in / result.max_deflection_in
+    let deflection_ratio = if result.max_deflection_in != 0.0 {
+        l_in / result.max_deflection_in.abs()
     } else {
         9999.0
     };
-    let deflection_limit_ratio = 240.0; // L/240 for floor beams
+    let deflection_limit_ratio = input.deflection_criteria.total_ratio;
 
     // Format the template with calculation data
     let source = BEAM_TEMPLATE
         .replace("{{BEAM_LABEL}}", &input.label)
         .replace("{{ENGINEER}}", engineer)
         .replace("{{JOB_ID}}", job_id)
+        .replace("{{COMBINATIONS}}", &escape_typst(&input.combination_set_label(method)))
         .replace("{{DATE}}", &Utc::now().format("%Y-%m-%d").to_string())
         .replace("{{SPAN_FT}}", &format!("{:.1}", input.total_length_ft()))
         .replace("{{LOAD_PLF}}", &format!("{:.0}", design_load_plf))
-        .replace("{{WIDTH_IN}}", &format!("{:.2}", first_span.width_in))
+        .replace("{{WIDTH_IN}}", &format!("{:.2}", first_span.total_width_in()))
         .replace("{{DEPTH_IN}}", &format!("{:.2}", first_span.depth_in))
         .replace(
             "{{MATERIAL}}",
@@ -421,7 +484,8 @@ pub fn render_beam_pdf(
         .replace("{{E_REF}}", &format!("{:.0}", first_span.e_psi()))
         .replace("{{MOMENT_FTLB}}", &format!("{:.0}", result.max_positive_moment_ftlb))
         .replace("{{SHEAR_LB}}", &format!("{:.0}", result.max_shear_lb))
-        .replace("{{DEFLECTION_IN}}", &format!("{:.3}", result.max_deflection_in))
+        .replace("{{DEFLECTION_IN}}", &format!("{:.3}", result.max_deflection_in.abs()))
+        .replace("{{DEFLECTION_DIR}}", result.deflection_direction())
         .replace("{{FB_ACTUAL}}", &format!("{:.0}", span_result.actual_fb_psi))
         .replace("{{FB_ALLOW}}", &format!("{:.0}", span_result.allowable_fb_psi))
         .replace("{{BENDING_UNITY}}", &format!("{:.2}", span_result.bending_unity))
@@ -450,36 +514,19 @@ pub fn render_beam_pdf(
             "{{OVERALL_PASS}}",
             if result.passes() { "PASS" } else { "FAIL" },
         )
-        .replace("{{GOVERNING}}", &result.governing_condition);
-
-    // Compile the Typst document
-    let world = PdfWorld::new(source);
-
-    let warned = typst::compile(&world);
-
-    let document = warned.output.map_err(|errors| {
-        let error_msgs: Vec<String> = errors
-            .iter()
-            .map(|e| e.message.to_string())
-            .collect();
-        CalcError::Internal {
-            message: format!("Typst compilation failed: {}", error_msgs.join("; ")),
-        }
-    })?;
-
-    // Render to PDF
-    let pdf_bytes = typst_pdf::pdf(&document, &PdfOptions::default()).map_err(|errors| {
-        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
-        CalcError::Internal {
-            message: format!("PDF rendering failed: {}", error_msgs.join("; ")),
-        }
-    })?;
+        .replace("{{GOVERNING}}", &result.governing_condition.to_string())
+        .replace("{{DIAGRAMS}}", &diagrams_typst(result))
+        .replace("{{APPENDIX}}", &member_appendix_typst(result));
 
-    Ok(pdf_bytes)
+    Ok(source)
 }
 
 /// Render an entire project (all beams) to a single PDF.
 ///
+/// The package opens with a contents table listing each beam with its
+/// governing unity, pass/fail status, and starting page. Each beam's
+/// detailed calculation follows on its own page.
+///
 /// # Arguments
 ///
 /// * `project` - The project containing all calculation items
@@ -499,13 +546,44 @@ pub fn render_beam_pdf(
 /// let pdf = render_project_pdf(&project).unwrap();
 /// ```
 pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
+    let source = project_package_source(project)?;
+
+    // Compile the Typst document
+    let world = PdfWorld::new(source).with_logo(project.meta.logo.as_ref());
+    let warned = typst::compile(&world);
+
+    let document = warned.output.map_err(|errors| {
+        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
+        CalcError::Internal {
+            message: format!("Typst compilation failed: {}", error_msgs.join("; ")),
+        }
+    })?;
+
+    // Render to PDF
+    let pdf_bytes = typst_pdf::pdf(&document, &PdfOptions::default()).map_err(|errors| {
+        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
+        CalcError::Internal {
+            message: format!("PDF rendering failed: {}", error_msgs.join("; ")),
+        }
+    })?;
+
+    Ok(pdf_bytes)
+}
+
+/// Build the Typst source for a full project calculation package.
+///
+/// Layout: cover page with a table of contents (one row per beam with its
+/// governing unity and starting page), then one section per beam, each
+/// starting on a new page and tagged with a `<beam-N>` label that the
+/// contents table resolves to a page number.
+fn project_package_source(project: &Project) -> CalcResult<String> {
     // Collect all beams and calculate their results
     let mut beams: Vec<(&ContinuousBeamInput, ContinuousBeamResult)> = Vec::new();
     let design_method = project.settings.design_method;
 
     for item in project.items.values() {
         if let CalculationItem::Beam(beam) = item {
-            match calculate_continuous(beam, design_method) {
+            match calculate_continuous(&project.beam_for_calculation(beam), design_method) {
                 Ok(result) => beams.push((beam, result)),
                 Err(e) => {
                     return Err(CalcError::Internal {
@@ -547,7 +625,7 @@ pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
 )
 
 #set text(font: "Berkeley Mono", size: 11pt)
-
+{letterhead}
 // Cover Page / Table of Contents
 #align(center)[
   #block(width: 100%, fill: rgb("#f0f0f0"), inset: 20pt, radius: 4pt)[
@@ -578,20 +656,21 @@ pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
   [
     *Code Reference*
     #v(4pt)
-    NDS 2018 (National Design Specification for Wood Construction)
+    NDS 2018 (National Design Specification for Wood Construction) \
+    Load combinations: {combinations}
   ]
 )
 
 #v(24pt)
 
-== Calculation Summary
+== Contents
 
 #table(
-  columns: (auto, 1fr, auto, auto),
+  columns: (auto, 1fr, auto, auto, auto),
   inset: 8pt,
   stroke: 0.5pt,
-  align: (left, left, right, center),
-  table.header([*No.*], [*Item*], [*Governing Unity*], [*Status*]),
+  align: (left, left, right, center, right),
+  table.header([*No.*], [*Item*], [*Governing Unity*], [*Status*], [*Page*]),
 {summary_rows}
 )
 
@@ -605,6 +684,8 @@ pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
         date = Utc::now().format("%Y-%m-%d"),
         client = escape_typst(&project.meta.client),
         engineer = escape_typst(&project.meta.engineer),
+        combinations = escape_typst(&project.settings.combination_set_label()),
+        letterhead = letterhead_typst(&project.meta),
         summary_rows = build_summary_rows(&beams),
     );
 
@@ -624,8 +705,8 @@ pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
 
         let design_load_plf = input.load_case.total_uniform_plf();
         let l_in = input.total_length_ft() * 12.0;
-        let deflection_ratio = if result.max_deflection_in > 0.0 {
-            l_in / result.max_deflection_in
+        let deflection_ratio = if result.max_deflection_in != 0.0 {
+            l_in / result.max_deflection_in.abs()
         } else {
             9999.0
         };
@@ -635,6 +716,7 @@ pub fn render_project_pdf(project: &Project) -> CalcResult<Vec<u8>> {
 #pagebreak()
 
 // Beam {} of {}
+#metadata(none) <beam-{}>
 #align(center)[
   #block(width: 100%, fill: rgb("#f0f0f0"), inset: 12pt, radius: 4pt)[
     #text(size: 18pt, weight: "bold")[Beam Analysis]
@@ -699,8 +781,8 @@ $ M_"max" = {moment_ftlb} "ft-lb" $
 
 $ V_"max" = {shear_lb} "lb" $
 
-$ delta_"max" = {deflection_in} "in" $
-
+$ delta_"max" = {deflection_in} "in ({deflection_dir})" $
+{diagrams}
 #v(12pt)
 
 === Stress Checks
@@ -733,10 +815,11 @@ $ delta_"max" = {deflection_in} "in" $
 "##,
             i + 1,
             beams.len(),
+            i + 1,
             beam_label = escape_typst(&input.label),
             span_ft = format!("{:.1}", input.total_length_ft()),
             load_plf = format!("{:.0}", design_load_plf),
-            width_in = format!("{:.2}", first_span.width_in),
+            width_in = format!("{:.2}", first_span.total_width_in()),
             depth_in = format!("{:.2}", first_span.depth_in),
             material = first_span.material.display_name(),
             section_modulus = format!("{:.2}", first_span.section_modulus_in3()),
@@ -746,7 +829,9 @@ $ delta_"max" = {deflection_in} "in" $
             e_ref = format!("{:.0}", first_span.e_psi()),
             moment_ftlb = format!("{:.0}", result.max_positive_moment_ftlb),
             shear_lb = format!("{:.0}", result.max_shear_lb),
-            deflection_in = format!("{:.3}", result.max_deflection_in),
+            deflection_in = format!("{:.3}", result.max_deflection_in.abs()),
+            deflection_dir = result.deflection_direction(),
+            diagrams = diagrams_typst(result),
             fb_actual = format!("{:.0}", span_result.actual_fb_psi),
             fb_allow = format!("{:.0}", span_result.allowable_fb_psi),
             bending_unity = format!("{:.2}", span_result.bending_unity),
@@ -756,7 +841,7 @@ $ delta_"max" = {deflection_in} "in" $
             shear_unity = format!("{:.2}", span_result.shear_unity),
             shear_status = if span_result.shear_unity <= 1.0 { "OK" } else { "FAIL" },
             defl_ratio = format!("{:.0}", deflection_ratio),
-            defl_limit = format!("{:.0}", 240.0),
+            defl_limit = format!("{:.0}", input.deflection_criteria.total_ratio),
             defl_unity = format!("{:.2}", span_result.deflection_unity),
             defl_status = if span_result.deflection_unity <= 1.0 { "OK" } else { "FAIL" },
             nds_bending = nds_ref::BENDING,
@@ -776,42 +861,16 @@ $ delta_"max" = {deflection_in} "in" $
         ));
     }
 
-    // Build equation tracker for the appendix
+    // Build the appendix from the equations each analysis recorded
     let mut equation_tracker = EquationTracker::new();
-    for (input, _result) in &beams {
-        // Record the standard beam calculation equations for each beam
-        for equation in beam_calculation_equations() {
-            equation_tracker.record_for_member(
-                equation,
-                "Beam analysis",
-                input.label.clone(),
-            );
-        }
+    for (_input, result) in &beams {
+        equation_tracker.merge(result.equations.clone());
     }
 
     // Add the equations appendix
     source.push_str(&equation_tracker.generate_appendix_typst());
 
-    // Compile the Typst document
-    let world = PdfWorld::new(source);
-    let warned = typst::compile(&world);
-
-    let document = warned.output.map_err(|errors| {
-        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
-        CalcError::Internal {
-            message: format!("Typst compilation failed: {}", error_msgs.join("; ")),
-        }
-    })?;
-
-    // Render to PDF
-    let pdf_bytes = typst_pdf::pdf(&document, &PdfOptions::default()).map_err(|errors| {
-        let error_msgs: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
-        CalcError::Internal {
-            message: format!("PDF rendering failed: {}", error_msgs.join("; ")),
-        }
-    })?;
-
-    Ok(pdf_bytes)
+    Ok(source)
 }
 
 /// Escape special Typst characters in user-provided text
@@ -832,7 +891,59 @@ fn escape_typst(s: &str) -> String {
         .collect()
 }
 
-/// Build summary table rows for the cover page
+/// Firm letterhead for the cover page
+///
+/// The logo is fit within a 2 in × 0.75 in box beside the firm name and
+/// address. Returns an empty string when neither a firm name, address, nor
+/// logo is set, leaving the text-only cover header.
+fn letterhead_typst(meta: &ProjectMetadata) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    if let Some(name) = meta.firm_name.as_deref().filter(|n| !n.trim().is_empty()) {
+        lines.push(format!("#text(size: 14pt, weight: \"bold\")[{}]", escape_typst(name.trim())));
+    }
+    if let Some(address) = &meta.firm_address {
+        lines.extend(
+            address
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(|l| format!("#text(size: 9pt)[{}]", escape_typst(l))),
+        );
+    }
+    if lines.is_empty() && meta.logo.is_none() {
+        return String::new();
+    }
+
+    let logo = match &meta.logo {
+        Some(logo) => format!(
+            "box(width: 2in, height: 0.75in, image(\"{}\", format: \"{}\", width: 100%, height: 100%, fit: \"contain\"))",
+            LOGO_PATH,
+            logo.format.typst_format()
+        ),
+        None => "[]".to_string(),
+    };
+
+    format!(
+        r##"
+// Firm Letterhead
+#grid(
+  columns: (auto, 1fr),
+  column-gutter: 16pt,
+  align: (left + horizon, right + horizon),
+  {logo},
+  [{lines}],
+)
+#v(4pt)
+#line(length: 100%, stroke: 0.5pt + gray)
+#v(12pt)
+"##,
+        lines = lines.join(" \\\n  "),
+    )
+}
+
+/// Build contents table rows for the cover page
+///
+/// The page column is resolved by Typst from each beam section's label.
 fn build_summary_rows(beams: &[(&ContinuousBeamInput, ContinuousBeamResult)]) -> String {
     beams
         .iter()
@@ -841,21 +952,125 @@ fn build_summary_rows(beams: &[(&ContinuousBeamInput, ContinuousBeamResult)]) ->
             let max_unity = result.governing_unity;
             let status = if result.passes() { "OK" } else { "FAIL" };
             format!(
-                "  [{}], [Beam: {}], [{:.2}], [{}],",
+                "  [{}], [Beam: {}], [{:.2}], [{}], [#context locate(<beam-{}>).page()],",
                 i + 1,
                 escape_typst(&input.label),
                 max_unity,
-                status
+                status,
+                i + 1
             )
         })
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Width of a plotted diagram (pt); the full text width of a letter page
+const DIAGRAM_WIDTH_PT: f64 = 468.0;
+
+/// Height of a plotted diagram (pt)
+const DIAGRAM_HEIGHT_PT: f64 = 60.0;
+
+/// Build the shear, moment, and deflection diagrams for a beam report
+///
+/// Each diagram is drawn as a filled polygon about the beam axis, scaled so
+/// the largest ordinate reaches the edge of the plot. Returns an empty string
+/// when the result carries no diagram data.
+fn diagrams_typst(result: &ContinuousBeamResult) -> String {
+    let plots: Vec<String> = [
+        ("Shear", "lb", 0, &result.shear_diagram),
+        ("Moment", "ft-lb", 0, &result.moment_diagram),
+        ("Deflection", "in", 3, &result.deflection_diagram),
+    ]
+    .into_iter()
+    .filter_map(|(title, unit, precision, points)| diagram_typst(title, unit, precision, points))
+    .collect();
+
+    if plots.is_empty() {
+        return String::new();
+    }
+
+    format!("\n=== Diagrams\n\n{}\n", plots.join("\n#v(8pt)\n"))
+}
+
+/// Plot a single diagram of (position_ft, value) points
+///
+/// Draws the vertical axis, the zero line, span-end position labels, and a
+/// marker with a value label at the location of the maximum and minimum.
+fn diagram_typst(title: &str, unit: &str, precision: usize, points: &[(f64, f64)]) -> Option<String> {
+    let (first, last) = (points.first()?.0, points.last()?.0);
+    let length = last - first;
+    let max = points.iter().copied().fold(points[0], |a, b| if b.1 > a.1 { b } else { a });
+    let min = points.iter().copied().fold(points[0], |a, b| if b.1 < a.1 { b } else { a });
+    let peak = max.1.abs().max(min.1.abs());
+    if points.len() < 2 || length <= 0.0 || !peak.is_finite() {
+        return None;
+    }
+
+    // Positive values plot upward from the zero line at mid-height
+    let axis = DIAGRAM_HEIGHT_PT / 2.0;
+    let scale = if peak > 0.0 { axis / peak } else { 0.0 };
+    let to_pt = |x: f64, y: f64| ((x - first) / length * DIAGRAM_WIDTH_PT, axis - y * scale);
+    let vertex = |x: f64, y: f64| {
+        let (px, py) = to_pt(x, y);
+        format!("({:.2}pt, {:.2}pt)", px, py)
+    };
+    let vertices: Vec<String> = std::iter::once(vertex(first, 0.0))
+        .chain(points.iter().map(|&(x, y)| vertex(x, y)))
+        .chain(std::iter::once(vertex(last, 0.0)))
+        .collect();
+
+    // Labels sit beside their marker, on whichever side has more room
+    let annotation = |(x, y): (f64, f64)| {
+        let (px, py) = to_pt(x, y);
+        let dy = if y >= 0.0 { py } else { py - 10.0 };
+        let label = format!("{:.precision$} {} at {:.2} ft", y, unit, x);
+        let placement = if px > DIAGRAM_WIDTH_PT / 2.0 {
+            format!("top + right, dx: {:.2}pt, dy: {:.2}pt", px - 4.0 - DIAGRAM_WIDTH_PT, dy)
+        } else {
+            format!("dx: {:.2}pt, dy: {:.2}pt", px + 4.0, dy)
+        };
+        format!(
+            "  #place(dx: {:.2}pt, dy: {:.2}pt, circle(radius: 1.5pt, fill: rgb(\"#1f4e79\")))\n  #place({}, text(size: 8pt)[{}])\n",
+            px - 1.5,
+            py - 1.5,
+            placement,
+            label
+        )
+    };
+    let annotations: String = [max, min]
+        .into_iter()
+        .filter(|&(_, y)| y != 0.0)
+        .map(annotation)
+        .collect();
+
+    Some(format!(
+        r##"#text(size: 10pt)[*{title}* ({unit})]
+#block(width: {width}pt, height: {labels}pt)[
+  #place(polygon(fill: rgb("#dbe7f5"), stroke: 0.6pt + rgb("#1f4e79"), {vertices}))
+  #place(line(start: (0pt, {axis}pt), length: {width}pt, stroke: 0.5pt + gray))
+  #place(line(start: (0pt, 0pt), end: (0pt, {height}pt), stroke: 0.5pt))
+  #place(dy: {label_dy}pt, text(size: 8pt)[{first:.1} ft])
+  #place(top + right, dy: {label_dy}pt, text(size: 8pt)[{last:.1} ft])
+{annotations}]
+"##,
+        width = DIAGRAM_WIDTH_PT,
+        height = DIAGRAM_HEIGHT_PT,
+        labels = DIAGRAM_HEIGHT_PT + 14.0,
+        label_dy = DIAGRAM_HEIGHT_PT + 2.0,
+        vertices = vertices.join(", "),
+    ))
+}
+
+/// Equations appendix limited to the equations used by one beam
+fn member_appendix_typst(result: &ContinuousBeamResult) -> String {
+    result.equations.generate_appendix_typst()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::loads::{DesignMethod, EnhancedLoadCase, DiscreteLoad, LoadType};
+    use crate::equations::registry::Equation;
+    use crate::loads::{EnhancedLoadCase, DiscreteLoad, LoadType};
     use crate::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
 
     #[test]
@@ -877,7 +1092,7 @@ mod tests {
         );
 
         let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
-        let pdf = render_beam_pdf(&input, &result, "Test Engineer", "TEST-001");
+        let pdf = render_beam_pdf(&input, &result, DesignMethod::Asd, "Test Engineer", "TEST-001");
 
         // Should succeed
         assert!(pdf.is_ok(), "PDF generation failed: {:?}", pdf.err());
@@ -888,4 +1103,156 @@ mod tests {
         // Should be a reasonable size (at least 1KB)
         assert!(pdf_bytes.len() > 1000, "PDF seems too small");
     }
+
+    #[test]
+    fn test_beam_report_has_diagrams_and_member_appendix() {
+        let material = Material::SawnLumber(WoodMaterial::new(
+            WoodSpecies::DouglasFirLarch,
+            WoodGrade::No2,
+        ));
+        let load_case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 30.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 70.0));
+        let input =
+            ContinuousBeamInput::simple_span("B-1", 12.0, 1.5, 9.25, material.clone(), load_case);
+        let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+
+        let source = beam_report_source(&input, &result, DesignMethod::Asd, "Test Engineer", "TEST-003").unwrap();
+        for title in ["*Shear* (lb)", "*Moment* (ft-lb)", "*Deflection* (in)"] {
+            assert!(source.contains(title), "missing {} diagram", title);
+        }
+        assert!(source.contains("#place(polygon("));
+        assert!(source.contains("Appendix: List of Equations"));
+        assert!(source.contains("Maximum Moment for Uniform Load"));
+        assert!(!source.contains("Point Load Reactions"));
+
+        // A cantilever with only a tip load lists the cantilever point load equation
+        let tip_load = EnhancedLoadCase::new("Tip")
+            .with_load(DiscreteLoad::point(LoadType::Dead, 200.0, 4.0))
+            .without_self_weight();
+        let cantilever = ContinuousBeamInput::cantilever("C-1", 4.0, 1.5, 9.25, material, tip_load);
+        let result = calculate_continuous(&cantilever, DesignMethod::Asd).unwrap();
+        let equations = result.equations.unique_equations();
+        assert!(equations.contains(&Equation::CantileverPointReactions));
+        assert!(!equations.contains(&Equation::CantileverUniformReactions));
+        assert!(!equations.contains(&Equation::UniformLoadMaxMoment));
+
+        let pdf = render_beam_pdf(&cantilever, &result, DesignMethod::Asd, "Test Engineer", "TEST-003").unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_diagram_markup_snapshot() {
+        let points = [(0.0, 0.0), (2.5, 300.0), (10.0, -100.0)];
+        let markup = diagram_typst("Moment", "ft-lb", 0, &points).unwrap();
+        let expected = r##"#text(size: 10pt)[*Moment* (ft-lb)]
+#block(width: 468pt, height: 74pt)[
+  #place(polygon(fill: rgb("#dbe7f5"), stroke: 0.6pt + rgb("#1f4e79"), (0.00pt, 30.00pt), (0.00pt, 30.00pt), (117.00pt, 0.00pt), (468.00pt, 40.00pt), (468.00pt, 30.00pt)))
+  #place(line(start: (0pt, 30pt), length: 468pt, stroke: 0.5pt + gray))
+  #place(line(start: (0pt, 0pt), end: (0pt, 60pt), stroke: 0.5pt))
+  #place(dy: 62pt, text(size: 8pt)[0.0 ft])
+  #place(top + right, dy: 62pt, text(size: 8pt)[10.0 ft])
+  #place(dx: 115.50pt, dy: -1.50pt, circle(radius: 1.5pt, fill: rgb("#1f4e79")))
+  #place(dx: 121.00pt, dy: 0.00pt, text(size: 8pt)[300 ft-lb at 2.50 ft])
+  #place(dx: 466.50pt, dy: 38.50pt, circle(radius: 1.5pt, fill: rgb("#1f4e79")))
+  #place(top + right, dx: -4.00pt, dy: 30.00pt, text(size: 8pt)[-100 ft-lb at 10.00 ft])
+]
+"##;
+        assert_eq!(markup, expected);
+
+        // Empty or degenerate vectors omit the figure
+        assert!(diagram_typst("Shear", "lb", 0, &[]).is_none());
+        assert!(diagram_typst("Shear", "lb", 0, &[(0.0, 10.0)]).is_none());
+        assert!(diagrams_typst(&ContinuousBeamResult::default()).is_empty());
+    }
+
+    #[test]
+    fn test_project_letterhead_logo() {
+        use crate::calculations::CalculationItem;
+        use crate::project::LogoFormat;
+
+        let mut project = Project::new("Test Engineer", "TEST-004", "Test Client");
+        let load_case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 30.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 70.0));
+        project.add_item(CalculationItem::Beam(ContinuousBeamInput::simple_span(
+            "B-1",
+            10.0,
+            1.5,
+            9.25,
+            Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2)),
+            load_case,
+        )));
+
+        // No firm data: text-only cover header
+        let source = project_package_source(&project).unwrap();
+        assert!(!source.contains("Firm Letterhead"));
+        assert!(!source.contains("image("));
+
+        project.meta.firm_name = Some("Acme Structural".to_string());
+        project.meta.firm_address = Some("100 Main St\nSpringfield, OR 97477".to_string());
+        let source = project_package_source(&project).unwrap();
+        assert!(source.contains("[Acme Structural]"));
+        assert!(source.contains("[Springfield, OR 97477]"));
+        assert!(!source.contains("image("));
+
+        project.meta.logo = Some(FirmLogo {
+            format: LogoFormat::Svg,
+            bytes: br#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="10"><rect width="40" height="10"/></svg>"#
+                .to_vec(),
+        });
+        let source = project_package_source(&project).unwrap();
+        assert!(source.contains(r#"image("/logo", format: "svg""#));
+        assert!(source.contains("box(width: 2in, height: 0.75in"));
+
+        let pdf = render_project_pdf(&project).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_project_package_has_contents_and_page_breaks() {
+        use crate::calculations::CalculationItem;
+
+        let mut project = Project::new("Test Engineer", "TEST-002", "Test Client");
+        for (label, span) in [("B-1", 10.0), ("B-2", 12.0), ("B-3", 14.0)] {
+            let load_case = EnhancedLoadCase::new("Floor")
+                .with_load(DiscreteLoad::uniform(LoadType::Dead, 30.0))
+                .with_load(DiscreteLoad::uniform(LoadType::Live, 70.0));
+            let beam = ContinuousBeamInput::simple_span(
+                label,
+                span,
+                1.5,
+                9.25,
+                Material::SawnLumber(WoodMaterial::new(
+                    WoodSpecies::DouglasFirLarch,
+                    WoodGrade::No2,
+                )),
+                load_case,
+            );
+            project.add_item(CalculationItem::Beam(beam));
+        }
+
+        let source = project_package_source(&project).unwrap();
+        assert!(source.contains("Load combinations: ASCE 7-22"));
+        assert_eq!(source.matches("=== Diagrams").count(), 3);
+
+        for (n, label) in ["B-1", "B-2", "B-3"].iter().enumerate() {
+            assert!(source.contains(&format!("[Beam: {}]", label)));
+            assert!(source.contains(&format!("locate(<beam-{}>).page()", n + 1)));
+            assert!(source.contains(&format!("<beam-{}>\n", n + 1)));
+        }
+        // Contents table precedes the first beam section
+        assert!(source.find("== Contents").unwrap() < source.find("#pagebreak()").unwrap());
+
+        // Each beam section is separated from the previous one by a page break
+        let starts: Vec<usize> = (1..=3)
+            .map(|n| source.find(&format!("#metadata(none) <beam-{}>", n)).unwrap())
+            .collect();
+        for pair in starts.windows(2) {
+            assert!(source[pair[0]..pair[1]].contains("#pagebreak()"));
+        }
+
+        let pdf = render_project_pdf(&project).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
 }
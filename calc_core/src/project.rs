@@ -7,7 +7,7 @@
 //!
 //! ```text
 //! Project
-//! ├── meta: ProjectMetadata (version, engineer, job info, timestamps)
+//! ├── meta: ProjectMetadata (version, engineer, job info, firm letterhead, timestamps)
 //! ├── settings: GlobalSettings (code year, defaults)
 //! └── items: HashMap<Uuid, CalculationItem> (all calculations)
 //! ```
@@ -23,7 +23,7 @@
 //! let json = serde_json::to_string_pretty(&project).unwrap();
 //!
 //! // Save to file (see file_io module for atomic saves)
-//! std::fs::write("project.stf", &json).unwrap();
+//! std::fs::write(std::env::temp_dir().join("project.stf"), &json).unwrap();
 //! ```
 
 use std::collections::HashMap;
@@ -32,18 +32,32 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::calculations::CalculationItem;
-use crate::loads::DesignMethod;
+use crate::calculations::{
+    calculate_continuous, CalculationItem, ContinuousBeamInput, ContinuousBeamResult,
+};
+use crate::errors::CalcResult;
+use crate::loads::{combination_set_label, CodeEdition, DesignMethod, LoadCombination, RiskCategory};
 
 /// Current schema version for .stf files
 pub const SCHEMA_VERSION: &str = "0.1.0";
 
+/// Current layout version of the serialized project, stored as the
+/// top-level `schema_version`
+///
+/// Files written before the field existed are version 1. Older documents
+/// are upgraded on load by [`crate::file_io::migrate`].
+pub const FILE_SCHEMA_VERSION: u32 = 3;
+
 /// Root project container.
 ///
 /// This is the top-level struct that gets serialized to `.stf` files.
 /// Items are stored in a flat UUID-keyed map for O(1) lookups.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
+    /// Layout version of the serialized document ([`FILE_SCHEMA_VERSION`])
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+
     /// Project metadata (version, engineer, job info)
     pub meta: ProjectMetadata,
 
@@ -79,6 +93,7 @@ impl Project {
     pub fn new(engineer: impl Into<String>, job_id: impl Into<String>, client: impl Into<String>) -> Self {
         let now = Utc::now();
         Project {
+            schema_version: FILE_SCHEMA_VERSION,
             meta: ProjectMetadata {
                 version: SCHEMA_VERSION.to_string(),
                 engineer: engineer.into(),
@@ -86,6 +101,9 @@ impl Project {
                 client: client.into(),
                 created: now,
                 modified: now,
+                firm_name: None,
+                firm_address: None,
+                logo: None,
             },
             settings: GlobalSettings::default(),
             items: HashMap::new(),
@@ -129,6 +147,39 @@ impl Project {
         id
     }
 
+    /// Duplicate a calculation item under a new UUID.
+    ///
+    /// The copy's label gets a " (copy)" suffix, and its spans and the loads
+    /// in every load case get new UUIDs so it can be edited independently of
+    /// the original. Returns `None` if no item has the given UUID.
+    pub fn duplicate_item(&mut self, id: &Uuid) -> Option<Uuid> {
+        let mut item = self.items.get(id)?.clone();
+        match &mut item {
+            CalculationItem::Beam(beam) => {
+                beam.label.push_str(" (copy)");
+                for span in &mut beam.spans {
+                    span.id = Uuid::new_v4();
+                }
+                let load_cases = std::iter::once(&mut beam.load_case)
+                    .chain(&mut beam.additional_load_cases)
+                    .chain(&mut beam.weak_axis_load_case);
+                for load in load_cases.flat_map(|case| &mut case.loads) {
+                    load.id = Uuid::new_v4();
+                }
+            }
+            CalculationItem::Column(column) => column.label.push_str(" (copy)"),
+            CalculationItem::SpreadFooting(footing) => footing.label.push_str(" (copy)"),
+            CalculationItem::RetainingWall(wall) => wall.label.push_str(" (copy)"),
+            CalculationItem::SteelBeam(beam) => {
+                beam.label.push_str(" (copy)");
+                for load in &mut beam.load_case.loads {
+                    load.id = Uuid::new_v4();
+                }
+            }
+        }
+        Some(self.add_item(item))
+    }
+
     /// Remove a calculation item by UUID.
     ///
     /// Returns the removed item if it existed.
@@ -183,6 +234,65 @@ impl Project {
     pub fn item_count(&self) -> usize {
         self.items.len()
     }
+
+    /// Copy of a beam input with project-wide settings applied.
+    ///
+    /// Applies `settings.code_edition` and, when set, `settings.custom_combinations`
+    /// and `settings.self_weight_density_pcf` (to every span).
+    pub fn beam_for_calculation(&self, beam: &ContinuousBeamInput) -> ContinuousBeamInput {
+        let mut beam = beam.clone().with_code_edition(self.settings.code_edition);
+        if let Some(combos) = &self.settings.custom_combinations {
+            beam = beam.with_custom_combinations(combos.clone());
+        }
+        match self.settings.self_weight_density_pcf {
+            Some(density) => beam.with_self_weight_density(density),
+            None => beam,
+        }
+    }
+
+    /// Copy of any item with project-wide settings applied.
+    ///
+    /// Wood beams go through [`Self::beam_for_calculation`]; steel beams take
+    /// `settings.code_edition` and `settings.custom_combinations`. Columns,
+    /// footings and retaining walls take their design loads directly rather
+    /// than through load combinations, so they are returned unchanged.
+    pub fn item_for_calculation(&self, item: &CalculationItem) -> CalculationItem {
+        match item {
+            CalculationItem::Beam(beam) => CalculationItem::Beam(self.beam_for_calculation(beam)),
+            CalculationItem::SteelBeam(beam) => {
+                let mut beam = beam.clone().with_code_edition(self.settings.code_edition);
+                if let Some(combos) = &self.settings.custom_combinations {
+                    beam = beam.with_custom_combinations(combos.clone());
+                }
+                CalculationItem::SteelBeam(beam)
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Calculate every beam in the project with the project's design method.
+    ///
+    /// Results are keyed by item UUID. Items other than wood beams are skipped.
+    pub fn calculate_all(&self) -> HashMap<Uuid, CalcResult<ContinuousBeamResult>> {
+        self.items
+            .iter()
+            .filter_map(|(id, item)| match item {
+                CalculationItem::Beam(beam) => {
+                    let beam = self.beam_for_calculation(beam);
+                    Some((*id, calculate_continuous(&beam, self.settings.design_method)))
+                }
+                CalculationItem::Column(_)
+                | CalculationItem::SteelBeam(_)
+                | CalculationItem::SpreadFooting(_)
+                | CalculationItem::RetainingWall(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Documents without a `schema_version` predate it
+fn legacy_schema_version() -> u32 {
+    1
 }
 
 impl Default for Project {
@@ -211,6 +321,47 @@ pub struct ProjectMetadata {
 
     /// When the project was last modified
     pub modified: DateTime<Utc>,
+
+    /// Firm name for the report letterhead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firm_name: Option<String>,
+
+    /// Firm mailing address for the report letterhead (may span several lines)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firm_address: Option<String>,
+
+    /// Firm logo for the report letterhead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<FirmLogo>,
+}
+
+/// Image format of a firm logo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogoFormat {
+    Png,
+    Jpg,
+    Svg,
+}
+
+impl LogoFormat {
+    /// Typst image format name
+    pub fn typst_format(&self) -> &'static str {
+        match self {
+            LogoFormat::Png => "png",
+            LogoFormat::Jpg => "jpg",
+            LogoFormat::Svg => "svg",
+        }
+    }
+}
+
+/// Firm logo image embedded in the project file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FirmLogo {
+    /// Image encoding of `bytes`
+    pub format: LogoFormat,
+    /// Raw image file contents
+    pub bytes: Vec<u8>,
 }
 
 /// Global project settings.
@@ -230,6 +381,25 @@ pub struct GlobalSettings {
 
     /// Design method (ASD or LRFD) for load combinations
     pub design_method: DesignMethod,
+
+    /// ASCE 7 edition for load combinations
+    #[serde(default)]
+    pub code_edition: CodeEdition,
+
+    /// Project-specific load combinations replacing the built-in set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_combinations: Option<Vec<LoadCombination>>,
+
+    /// Project-wide self-weight density (pcf), overriding each span's density
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_weight_density_pcf: Option<f64>,
+}
+
+impl GlobalSettings {
+    /// Name of the combination set in use under `design_method`, for reports
+    pub fn combination_set_label(&self) -> String {
+        combination_set_label(self.code_edition, self.custom_combinations.as_deref(), self.design_method)
+    }
 }
 
 impl Default for GlobalSettings {
@@ -240,25 +410,13 @@ impl Default for GlobalSettings {
             risk_category: RiskCategory::II,
             default_materials: DefaultMaterials::default(),
             design_method: DesignMethod::Asd,
+            code_edition: CodeEdition::default(),
+            custom_combinations: None,
+            self_weight_density_pcf: None,
         }
     }
 }
 
-/// Risk category per ASCE 7
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum RiskCategory {
-    I,
-    II,
-    III,
-    IV,
-}
-
-impl Default for RiskCategory {
-    fn default() -> Self {
-        RiskCategory::II
-    }
-}
-
 /// Default materials for new calculations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DefaultMaterials {
@@ -282,6 +440,116 @@ impl Default for DefaultMaterials {
     }
 }
 
+/// Pre-populated starting points for common member types.
+///
+/// Each template returns a [`ContinuousBeamInput`](crate::calculations::ContinuousBeamInput)
+/// with a typical span, area loads (psf over a tributary width), and a
+/// material suited to that use. Callers are expected to edit spans and
+/// loads to suit the actual project before running the calculation.
+pub mod templates {
+    use serde::{Deserialize, Serialize};
+
+    use crate::calculations::ContinuousBeamInput;
+    use crate::loads::{DiscreteLoad, EnhancedLoadCase, LoadType};
+    use crate::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
+    use crate::nds_factors::{RepetitiveMember, WetService};
+
+    /// Member types with a built-in template
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum TemplateKind {
+        FloorBeam,
+        RoofBeam,
+        Header,
+        DeckJoist,
+    }
+
+    impl TemplateKind {
+        /// All template kinds, in display order
+        pub const ALL: [TemplateKind; 4] = [
+            TemplateKind::FloorBeam,
+            TemplateKind::RoofBeam,
+            TemplateKind::Header,
+            TemplateKind::DeckJoist,
+        ];
+
+        /// Human-readable name
+        pub fn display_name(&self) -> &'static str {
+            match self {
+                TemplateKind::FloorBeam => "Floor Beam",
+                TemplateKind::RoofBeam => "Roof Beam",
+                TemplateKind::Header => "Header",
+                TemplateKind::DeckJoist => "Deck Joist",
+            }
+        }
+
+        /// Build the pre-populated beam input for this template
+        pub fn input(&self) -> ContinuousBeamInput {
+            match self {
+                TemplateKind::FloorBeam => floor_beam(),
+                TemplateKind::RoofBeam => roof_beam(),
+                TemplateKind::Header => header(),
+                TemplateKind::DeckJoist => deck_joist(),
+            }
+        }
+
+        /// Live load deflection limit denominator (L/n) per IBC Table 1604.3
+        ///
+        /// Floor members use L/360. Roof members without a plaster or
+        /// stucco ceiling use L/240.
+        pub fn live_deflection_limit(&self) -> f64 {
+            match self {
+                TemplateKind::FloorBeam | TemplateKind::Header | TemplateKind::DeckJoist => 360.0,
+                TemplateKind::RoofBeam => 240.0,
+            }
+        }
+    }
+
+    fn df_l(grade: WoodGrade) -> Material {
+        Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, grade))
+    }
+
+    /// Floor beam: 14 ft 4x12 DF-L No.1 carrying 4 ft of floor (15 psf D, 40 psf L)
+    pub fn floor_beam() -> ContinuousBeamInput {
+        let load_case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0).with_tributary_width(4.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 40.0).with_tributary_width(4.0));
+
+        ContinuousBeamInput::simple_span("FB-1", 14.0, 3.5, 11.25, df_l(WoodGrade::No1), load_case)
+    }
+
+    /// Roof beam: 16 ft 4x12 DF-L No.2 carrying 4 ft of roof (15 psf D, 20 psf Lr)
+    pub fn roof_beam() -> ContinuousBeamInput {
+        let load_case = EnhancedLoadCase::new("Roof")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0).with_tributary_width(4.0))
+            .with_load(DiscreteLoad::uniform(LoadType::LiveRoof, 20.0).with_tributary_width(4.0));
+
+        ContinuousBeamInput::simple_span("RB-1", 16.0, 3.5, 11.25, df_l(WoodGrade::No2), load_case)
+    }
+
+    /// Header: 6 ft 4x10 DF-L No.2 over an opening carrying 8 ft of floor
+    pub fn header() -> ContinuousBeamInput {
+        let load_case = EnhancedLoadCase::new("Header")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0).with_tributary_width(8.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 40.0).with_tributary_width(8.0));
+
+        ContinuousBeamInput::simple_span("H-1", 6.0, 3.5, 9.25, df_l(WoodGrade::No2), load_case)
+    }
+
+    /// Deck joist: 12 ft 2x12 DF-L No.2 at 16" o.c. (10 psf D, 60 psf L), wet service
+    pub fn deck_joist() -> ContinuousBeamInput {
+        let spacing_ft = 16.0 / 12.0;
+        let load_case = EnhancedLoadCase::new("Deck")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 10.0).with_tributary_width(spacing_ft))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 60.0).with_tributary_width(spacing_ft));
+
+        let mut input =
+            ContinuousBeamInput::simple_span("DJ-1", 12.0, 1.5, 11.25, df_l(WoodGrade::No2), load_case);
+        input.adjustment_factors.wet_service = WetService::Wet;
+        input.adjustment_factors.repetitive_member = RepetitiveMember::Repetitive;
+        input
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,6 +611,173 @@ mod tests {
         assert_eq!(project.item_count(), 0);
     }
 
+    #[test]
+    fn test_calculate_all() {
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let light = project.add_item(CalculationItem::Beam(templates::header()));
+        let mut heavy_beam = templates::floor_beam();
+        heavy_beam.spans[0].length_ft = 24.0;
+        let heavy = project.add_item(CalculationItem::Beam(heavy_beam));
+
+        let results = project.calculate_all();
+        assert_eq!(results.len(), 2);
+        let light_unity = results[&light].as_ref().unwrap().governing_unity;
+        let heavy_unity = results[&heavy].as_ref().unwrap().governing_unity;
+        assert!(heavy_unity > light_unity);
+    }
+
+    #[test]
+    fn test_duplicate_item() {
+        use crate::loads::{DiscreteLoad, EnhancedLoadCase, LoadType};
+
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let beam = templates::floor_beam().with_additional_load_case(
+            EnhancedLoadCase::new("Snow drift").with_load(DiscreteLoad::uniform(LoadType::Snow, 30.0)),
+        );
+        let id = project.add_item(CalculationItem::Beam(beam));
+        let copy_id = project.duplicate_item(&id).unwrap();
+        assert_ne!(copy_id, id);
+        assert_eq!(project.item_count(), 2);
+        assert!(project.duplicate_item(&Uuid::new_v4()).is_none());
+
+        let (CalculationItem::Beam(original), CalculationItem::Beam(copy)) =
+            (&project.items[&id], &project.items[&copy_id])
+        else {
+            panic!("expected beams");
+        };
+        assert_eq!(copy.label, format!("{} (copy)", original.label));
+        for (a, b) in original.spans.iter().zip(&copy.spans) {
+            assert_ne!(a.id, b.id);
+        }
+        for (a, b) in original.load_cases().zip(copy.load_cases()) {
+            for (a, b) in a.loads.iter().zip(&b.loads) {
+                assert_ne!(a.id, b.id);
+            }
+        }
+        assert_eq!(copy.additional_load_cases[0].loads.len(), 1);
+
+        let results = project.calculate_all();
+        let (original, copy) = (
+            results[&id].as_ref().unwrap(),
+            results[&copy_id].as_ref().unwrap(),
+        );
+        assert_eq!(copy.governing_unity, original.governing_unity);
+        assert_eq!(copy.max_positive_moment_ftlb, original.max_positive_moment_ftlb);
+        assert_eq!(copy.max_shear_lb, original.max_shear_lb);
+        assert_eq!(copy.max_deflection_in, original.max_deflection_in);
+        assert_eq!(copy.reactions, original.reactions);
+    }
+
+    #[test]
+    fn test_project_custom_combination_governs() {
+        use crate::calculations::calculate_continuous_detailed;
+        use crate::loads::LoadType;
+
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let id = project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        let baseline = project.calculate_all()[&id].as_ref().unwrap().max_positive_moment_ftlb;
+
+        // A single firm-specific combination heavier than D + L
+        project.settings.custom_combinations = Some(vec![LoadCombination::new("FIRM-1", "D + 2.0L")
+            .with_factor(LoadType::Dead, 1.0)
+            .with_factor(LoadType::Live, 2.0)]);
+        assert_eq!(project.settings.combination_set_label(), "Custom (FIRM-1)");
+
+        let CalculationItem::Beam(beam) = &project.items[&id] else {
+            panic!("expected beam");
+        };
+        let beam = project.beam_for_calculation(beam);
+        let result = calculate_continuous_detailed(&beam, project.settings.design_method).unwrap();
+        assert_eq!(result.governing_combination, "FIRM-1");
+        assert!(result.max_positive_moment_ftlb > baseline);
+
+        let by_type = |load_type| {
+            result
+                .reactions_by_type
+                .iter()
+                .find(|r| r.load_type == load_type)
+                .map_or(0.0, |r| r.reactions.iter().sum::<f64>())
+        };
+        let total: f64 = result.reactions.iter().sum();
+        assert!((total - (by_type(LoadType::Dead) + 2.0 * by_type(LoadType::Live))).abs() < 1e-6);
+
+        // FIRM-1 is an ASD combination, so LRFD keeps the built-in set until
+        // a combination is tagged for it
+        let lrfd = beam.load_combinations(DesignMethod::Lrfd);
+        assert!(lrfd.iter().all(|c| c.name.starts_with("LRFD")));
+        assert_eq!(beam.combination_set_label(DesignMethod::Lrfd), "ASCE 7-22");
+        let firm_lrfd = LoadCombination::new("FIRM-U", "1.4D + 1.7L")
+            .with_factor(LoadType::Dead, 1.4)
+            .with_factor(LoadType::Live, 1.7)
+            .for_method(DesignMethod::Lrfd);
+        project.settings.custom_combinations.as_mut().unwrap().push(firm_lrfd);
+        let beam = project.beam_for_calculation(&beam);
+        let names = |method| beam.load_combinations(method).into_iter().map(|c| c.name).collect::<Vec<_>>();
+        assert_eq!(names(DesignMethod::Asd), ["FIRM-1"]);
+        assert_eq!(names(DesignMethod::Lrfd), ["FIRM-U"]);
+        project.settings.design_method = DesignMethod::Lrfd;
+        assert_eq!(project.settings.combination_set_label(), "Custom (FIRM-U)");
+        project.settings.design_method = DesignMethod::Asd;
+
+        // An empty set or a non-finite factor is rejected
+        project.settings.custom_combinations = Some(Vec::new());
+        assert!(project.calculate_all()[&id].is_err());
+        project.settings.custom_combinations =
+            Some(vec![LoadCombination::new("BAD", "D").with_factor(LoadType::Dead, f64::NAN)]);
+        assert!(project.calculate_all()[&id].is_err());
+    }
+
+    #[test]
+    fn test_project_custom_combinations_reach_steel_beams() {
+        use crate::calculations::steel_beam::{self, SteelBeamInput};
+        use crate::loads::{DiscreteLoad, EnhancedLoadCase, LoadType};
+
+        let load_case = EnhancedLoadCase::new("Floor")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 300.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 800.0));
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let id = project.add_item(CalculationItem::SteelBeam(SteelBeamInput::new("SB-1", 20.0, "W18X35", load_case)));
+        project.settings.custom_combinations = Some(vec![LoadCombination::new("FIRM-1", "D + 2.0L")
+            .with_factor(LoadType::Dead, 1.0)
+            .with_factor(LoadType::Live, 2.0)]);
+
+        let CalculationItem::SteelBeam(beam) = project.item_for_calculation(&project.items[&id]) else {
+            panic!("expected steel beam");
+        };
+        let result = steel_beam::calculate(&beam, DesignMethod::Asd).unwrap();
+        assert_eq!(result.governing_combination, "FIRM-1");
+        let lrfd = steel_beam::calculate(&beam, DesignMethod::Lrfd).unwrap();
+        assert!(lrfd.governing_combination.starts_with("LRFD"));
+    }
+
+    #[test]
+    fn test_project_self_weight_density() {
+        use crate::materials::{LvlMaterial, Material};
+
+        let mut project = Project::new("Engineer", "25-001", "Client");
+        let mut lvl_beam = templates::floor_beam();
+        lvl_beam.spans[0].material = Material::Lvl(LvlMaterial::default());
+        let sawn = project.add_item(CalculationItem::Beam(templates::floor_beam()));
+        let lvl = project.add_item(CalculationItem::Beam(lvl_beam));
+        let baseline = project.calculate_all();
+
+        project.settings.self_weight_density_pcf = Some(40.0);
+        let heavier = project.calculate_all();
+
+        for id in [sawn, lvl] {
+            let CalculationItem::Beam(beam) = &project.items[&id] else {
+                panic!("expected beam");
+            };
+            let span = &project.beam_for_calculation(beam).spans[0];
+            let expected = span.area_in2() * 40.0 / 144.0;
+            assert!((span.self_weight_plf() - expected).abs() < 1e-9);
+
+            let before = baseline[&id].as_ref().unwrap().reactions[0];
+            let after = heavier[&id].as_ref().unwrap().reactions[0];
+            assert!(after > before);
+        }
+    }
+
     #[test]
     fn test_risk_category_serialization() {
         let cat = RiskCategory::III;
@@ -352,4 +787,31 @@ mod tests {
         let roundtrip: RiskCategory = serde_json::from_str(&json).unwrap();
         assert_eq!(roundtrip, RiskCategory::III);
     }
+
+    #[test]
+    fn test_floor_beam_template() {
+        use crate::calculations::calculate_continuous;
+        use crate::loads::LoadType;
+
+        let input = templates::floor_beam();
+        let loads = &input.load_case.loads;
+
+        let dead = loads.iter().find(|l| l.load_type == LoadType::Dead).unwrap();
+        let live = loads.iter().find(|l| l.load_type == LoadType::Live).unwrap();
+        assert!((10.0..=20.0).contains(&dead.magnitude));
+        assert!((30.0..=50.0).contains(&live.magnitude));
+        assert!(dead.tributary_width_ft.is_some());
+        assert!(live.effective_magnitude() > live.magnitude);
+
+        assert_eq!(templates::TemplateKind::FloorBeam.live_deflection_limit(), 360.0);
+
+        // Template should pass a default ASD check out of the box
+        let result = calculate_continuous(&input, DesignMethod::Asd).unwrap();
+        assert!(result.passes());
+        assert!(result.max_deflection_in.abs() < input.total_length_ft() * 12.0 / 360.0);
+
+        for kind in templates::TemplateKind::ALL {
+            assert!(kind.input().validate().is_ok(), "{} template invalid", kind.display_name());
+        }
+    }
 }
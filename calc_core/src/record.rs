@@ -0,0 +1,117 @@
+//! # Calculation Records
+//!
+//! A [`CalcRecord`] bundles a calculation input with its computed result and
+//! the context needed to reproduce it (design method, engine version, time).
+//! It is the natural unit to archive or hand to another tool: a consumer gets
+//! the complete calc without re-running anything.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use calc_core::calculations::{CalculationItem, ContinuousBeamInput};
+//! use calc_core::loads::{DesignMethod, DiscreteLoad, EnhancedLoadCase, LoadType};
+//! use calc_core::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
+//! use calc_core::record::CalcRecord;
+//!
+//! let loads = EnhancedLoadCase::new("Floor")
+//!     .with_load(DiscreteLoad::uniform(LoadType::Dead, 15.0).with_tributary_width(1.33))
+//!     .with_load(DiscreteLoad::uniform(LoadType::Live, 40.0).with_tributary_width(1.33));
+//! let beam = ContinuousBeamInput::simple_span(
+//!     "J-1",
+//!     12.0,
+//!     1.5,
+//!     9.25,
+//!     Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2)),
+//!     loads,
+//! );
+//!
+//! let record = CalcRecord::compute(CalculationItem::Beam(beam), DesignMethod::Asd).unwrap();
+//! let json = serde_json::to_string_pretty(&record).unwrap();
+//! assert!(json.contains("\"engine_version\""));
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::{CalculationItem, CalculationResult};
+use crate::errors::CalcResult;
+use crate::loads::DesignMethod;
+
+/// Version of calc_core that produced a record
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A calculation input together with its computed result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalcRecord {
+    /// The exact input that was calculated
+    pub input: CalculationItem,
+
+    /// Result of calculating `input`
+    pub result: CalculationResult,
+
+    /// Design method used (ASD or LRFD)
+    pub method: DesignMethod,
+
+    /// calc_core version that produced the result
+    pub engine_version: String,
+
+    /// When the calculation was run
+    pub computed_at: DateTime<Utc>,
+}
+
+impl CalcRecord {
+    /// Calculate `item` and wrap the input and result in a record
+    pub fn compute(item: CalculationItem, method: DesignMethod) -> CalcResult<Self> {
+        let result = item.calculate(method)?;
+        Ok(Self {
+            input: item,
+            result,
+            method,
+            engine_version: ENGINE_VERSION.to_string(),
+            computed_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::ContinuousBeamInput;
+    use crate::loads::{DiscreteLoad, EnhancedLoadCase, LoadType};
+    use crate::materials::{Material, WoodGrade, WoodMaterial, WoodSpecies};
+
+    #[test]
+    fn test_record_roundtrip() {
+        let loads = EnhancedLoadCase::new("Test")
+            .with_load(DiscreteLoad::uniform(LoadType::Dead, 50.0))
+            .with_load(DiscreteLoad::uniform(LoadType::Live, 100.0));
+        let beam = ContinuousBeamInput::simple_span(
+            "B-1",
+            12.0,
+            1.5,
+            9.25,
+            Material::SawnLumber(WoodMaterial::new(WoodSpecies::DouglasFirLarch, WoodGrade::No2)),
+            loads,
+        );
+
+        let record = CalcRecord::compute(CalculationItem::Beam(beam), DesignMethod::Asd).unwrap();
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains("\"input\":{\"type\":\"Beam\""));
+        assert!(json.contains("\"result\":{\"type\":\"Beam\""));
+        assert!(json.contains("\"span_results\""));
+
+        let roundtrip: CalcRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtrip.engine_version, ENGINE_VERSION);
+        assert_eq!(roundtrip.method, DesignMethod::Asd);
+        assert_eq!(roundtrip.computed_at, record.computed_at);
+        match (&roundtrip.input, &roundtrip.result) {
+            (CalculationItem::Beam(input), CalculationResult::Beam(result)) => {
+                assert_eq!(input.label, "B-1");
+                let CalculationResult::Beam(original) = &record.result else { unreachable!() };
+                assert_eq!(result.governing_unity, original.governing_unity);
+                assert_eq!(result.reactions, original.reactions);
+            }
+            _ => panic!("record did not round-trip as a beam"),
+        }
+    }
+}
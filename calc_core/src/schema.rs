@@ -0,0 +1,79 @@
+//! JSON Schema export for calculation inputs
+//!
+//! Enabled with the `json-schema` feature. The schemas are derived from the
+//! same serde attributes used for (de)serialization, so tagged enums such as
+//! [`Material`] carry their `type` discriminator and optional fields are not
+//! marked required. MCP clients and form generators can use them to validate
+//! a payload before calling the solver.
+//!
+//! ```rust
+//! let schemas = calc_core::schema::input_schemas();
+//! assert!(schemas.contains_key("ContinuousBeamInput"));
+//! ```
+
+use std::collections::BTreeMap;
+
+use schemars::schema_for;
+use serde_json::Value;
+
+use crate::calculations::column::ColumnInput;
+use crate::calculations::continuous_beam::ContinuousBeamInput;
+use crate::loads::EnhancedLoadCase;
+use crate::materials::Material;
+use crate::nds_factors::AdjustmentFactors;
+
+/// JSON Schema for each public input type, keyed by type name
+///
+/// Each value is a standalone schema document with its own `definitions`.
+pub fn input_schemas() -> BTreeMap<&'static str, Value> {
+    let schemas = [
+        ("ContinuousBeamInput", schema_for!(ContinuousBeamInput)),
+        ("ColumnInput", schema_for!(ColumnInput)),
+        ("EnhancedLoadCase", schema_for!(EnhancedLoadCase)),
+        ("Material", schema_for!(Material)),
+        ("AdjustmentFactors", schema_for!(AdjustmentFactors)),
+    ];
+
+    schemas
+        .into_iter()
+        .map(|(name, schema)| (name, serde_json::to_value(schema).unwrap_or(Value::Null)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_schema_lists_all_variants() {
+        let schemas = input_schemas();
+        let variants: Vec<&str> = schemas["Material"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["properties"]["type"]["enum"][0].as_str().unwrap())
+            .collect();
+        assert_eq!(variants, ["SawnLumber", "Glulam", "Lvl", "Psl"]);
+
+        for variant in schemas["Material"]["oneOf"].as_array().unwrap() {
+            let required = variant["required"].as_array().unwrap();
+            assert!(required.iter().any(|r| r == "type"));
+        }
+    }
+
+    #[test]
+    fn test_optional_fields_not_required() {
+        let schemas = input_schemas();
+        assert_eq!(schemas.len(), 5);
+
+        let required: Vec<&str> = schemas["ContinuousBeamInput"]["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r.as_str().unwrap())
+            .collect();
+        assert!(required.contains(&"spans"));
+        assert!(!required.contains(&"adjustment_factors"));
+        assert!(!required.contains(&"bearing_lengths_in"));
+    }
+}
@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 
 /// Notch location along the beam
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum NotchLocation {
     /// No notch
     #[default]
@@ -69,6 +70,7 @@ impl std::fmt::Display for NotchLocation {
 
 /// Section deductions for holes, notches, etc.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SectionDeductions {
     /// Notch locations
     pub notch_location: NotchLocation,
@@ -90,6 +92,12 @@ pub struct SectionDeductions {
     /// Number of holes in beam
     #[serde(default)]
     pub hole_count: u8,
+
+    /// Position of the largest hole from the left end of the beam (ft)
+    ///
+    /// When set, bending and shear are checked on the net section there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hole_position_ft: Option<f64>,
 }
 
 impl SectionDeductions {
@@ -127,9 +135,20 @@ impl SectionDeductions {
         self
     }
 
+    /// Locate the largest hole along the beam (builder pattern)
+    pub fn with_hole_position(mut self, position_ft: f64) -> Self {
+        self.hole_position_ft = Some(position_ft);
+        self
+    }
+
     /// Check if there are any deductions
     pub fn has_deductions(&self) -> bool {
-        self.notch_location.has_any_notch() || (self.hole_diameter_in > 0.0 && self.hole_count > 0)
+        self.notch_location.has_any_notch() || self.has_holes()
+    }
+
+    /// Check if the beam has any holes
+    pub fn has_holes(&self) -> bool {
+        self.hole_diameter_in > 0.0 && self.hole_count > 0
     }
 
     /// Calculate effective depth at left support (after notch)
@@ -176,6 +195,25 @@ impl SectionDeductions {
         }
     }
 
+    /// Allowable shear at a tension-side end notch per NDS 3.4.3.2(a)
+    ///
+    /// V_r' = (2/3)·Fv'·b·d_n·(d_n/d)², where d_n is the depth remaining
+    /// at the notch. Returns `None` when that end is not notched.
+    pub fn notched_shear_capacity_lb(
+        &self,
+        at_left: bool,
+        fv_prime_psi: f64,
+        width_in: f64,
+        full_depth_in: f64,
+    ) -> Option<f64> {
+        let d_n = if at_left {
+            self.effective_depth_left_in(full_depth_in)
+        } else {
+            self.effective_depth_right_in(full_depth_in)
+        };
+        (d_n < full_depth_in).then(|| 2.0 / 3.0 * fv_prime_psi * width_in * d_n * (d_n / full_depth_in).powi(2))
+    }
+
     /// Check if notch exceeds NDS limits (d/4 for sawn lumber)
     pub fn notch_exceeds_limit(&self, full_depth_in: f64) -> bool {
         let max_notch = full_depth_in / 4.0;
@@ -187,6 +225,15 @@ impl SectionDeductions {
         self.hole_diameter_in > full_depth_in / 3.0
     }
 
+    /// Net depth through a hole for the shear check (inches)
+    pub fn net_shear_depth_in(&self, full_depth_in: f64) -> f64 {
+        if self.has_holes() {
+            (full_depth_in - self.hole_diameter_in).max(0.0)
+        } else {
+            full_depth_in
+        }
+    }
+
     /// Calculate net width reduction from holes (for bending check)
     ///
     /// Returns the reduction in effective width due to holes.
@@ -258,6 +305,16 @@ mod tests {
         assert!((factor - 1.78).abs() < 0.01);
     }
 
+    #[test]
+    fn test_notched_shear_capacity() {
+        let ded = SectionDeductions::none().with_right_notch(2.0);
+        assert!(ded.notched_shear_capacity_lb(true, 180.0, 1.5, 10.0).is_none());
+
+        // 2/3 · 180 · 1.5 · 8 · (8/10)² = 921.6 lb
+        let capacity = ded.notched_shear_capacity_lb(false, 180.0, 1.5, 10.0).unwrap();
+        assert!((capacity - 921.6).abs() < 1e-9);
+    }
+
     #[test]
     fn test_notch_exceeds_limit() {
         let ded = SectionDeductions::none().with_left_notch(3.0);
@@ -287,6 +344,14 @@ mod tests {
         assert!((factor - 0.992).abs() < 0.01);
     }
 
+    #[test]
+    fn test_net_shear_depth() {
+        let ded = SectionDeductions::none().with_holes(2.0, 1).with_hole_position(4.0);
+        assert_eq!(ded.hole_position_ft, Some(4.0));
+        assert_eq!(ded.net_shear_depth_in(10.0), 8.0);
+        assert_eq!(SectionDeductions::none().net_shear_depth_in(10.0), 10.0);
+    }
+
     #[test]
     fn test_serialization() {
         let ded = SectionDeductions::none()
@@ -19,6 +19,16 @@
 //! - Moment: foot-pounds (ft-lb), kip-feet (k-ft), inch-pounds (in-lb), kip-inches (k-in)
 //! - Distributed load: pounds per linear foot (plf), kips per linear foot (klf)
 //!
+//! ## SI Output
+//!
+//! Results can be reported in SI through [`UnitSystem`], which converts an
+//! internal US customary value of a given [`Quantity`] for display:
+//! - Length: meters (m), with deflections in millimeters (mm)
+//! - Force: kilonewtons (kN)
+//! - Stress: megapascals (MPa)
+//! - Moment: kilonewton-meters (kN·m)
+//! - Distributed load: kilonewtons per meter (kN/m)
+//!
 //! ## Example
 //!
 //! ```rust
@@ -238,6 +248,130 @@ pub struct In4(pub f64);
 #[serde(transparent)]
 pub struct In3(pub f64);
 
+// ============================================================================
+// Unit Systems
+// ============================================================================
+
+/// Meters per foot (exact)
+const M_PER_FT: f64 = 0.3048;
+
+/// Millimeters per inch (exact)
+const MM_PER_IN: f64 = 25.4;
+
+/// Kilonewtons per pound-force
+const KN_PER_LB: f64 = 4.448_221_615_260_5e-3;
+
+/// Physical quantity of a reported value, which sets its unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Quantity {
+    /// Positions and spans: ft or m
+    Length,
+    /// Deflections: in or mm
+    Deflection,
+    /// Forces and reactions: lb or kN
+    Force,
+    /// Bending moments: ft-lb or kN·m
+    Moment,
+    /// Stresses: psi or MPa
+    Stress,
+    /// Line loads: plf or kN/m
+    DistributedLoad,
+}
+
+impl Quantity {
+    /// SI units per US customary unit
+    fn si_factor(self) -> f64 {
+        match self {
+            Quantity::Length => M_PER_FT,
+            Quantity::Deflection => MM_PER_IN,
+            Quantity::Force => KN_PER_LB,
+            Quantity::Moment => KN_PER_LB * M_PER_FT,
+            Quantity::Stress => KN_PER_LB * 1000.0 / (MM_PER_IN * MM_PER_IN),
+            Quantity::DistributedLoad => KN_PER_LB / M_PER_FT,
+        }
+    }
+}
+
+/// System of units used to report results
+///
+/// Values are always stored in US customary units; a unit system only
+/// converts them for display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum UnitSystem {
+    /// US customary: ft, in, lb, ft-lb, psi, plf
+    #[default]
+    Imperial,
+    /// SI: m, mm, kN, kN·m, MPa, kN/m
+    Si,
+}
+
+impl UnitSystem {
+    /// All unit systems for UI selection
+    pub const ALL: [UnitSystem; 2] = [UnitSystem::Imperial, UnitSystem::Si];
+
+    /// Convert a US customary value of `quantity` into this system
+    pub fn from_imperial(self, quantity: Quantity, value: f64) -> f64 {
+        match self {
+            UnitSystem::Imperial => value,
+            UnitSystem::Si => value * quantity.si_factor(),
+        }
+    }
+
+    /// Convert a value of `quantity` in this system back to US customary
+    pub fn to_imperial(self, quantity: Quantity, value: f64) -> f64 {
+        match self {
+            UnitSystem::Imperial => value,
+            UnitSystem::Si => value / quantity.si_factor(),
+        }
+    }
+
+    /// Unit label for `quantity` in this system
+    pub fn label(self, quantity: Quantity) -> &'static str {
+        match (self, quantity) {
+            (UnitSystem::Imperial, Quantity::Length) => "ft",
+            (UnitSystem::Imperial, Quantity::Deflection) => "in",
+            (UnitSystem::Imperial, Quantity::Force) => "lb",
+            (UnitSystem::Imperial, Quantity::Moment) => "ft-lb",
+            (UnitSystem::Imperial, Quantity::Stress) => "psi",
+            (UnitSystem::Imperial, Quantity::DistributedLoad) => "plf",
+            (UnitSystem::Si, Quantity::Length) => "m",
+            (UnitSystem::Si, Quantity::Deflection) => "mm",
+            (UnitSystem::Si, Quantity::Force) => "kN",
+            (UnitSystem::Si, Quantity::Moment) => "kN·m",
+            (UnitSystem::Si, Quantity::Stress) => "MPa",
+            (UnitSystem::Si, Quantity::DistributedLoad) => "kN/m",
+        }
+    }
+
+    /// Format a value already in this system with engineering precision
+    /// and its unit label (e.g., "12.34 kN·m")
+    pub fn format(self, quantity: Quantity, value: f64) -> String {
+        let decimals = match (self, quantity) {
+            (UnitSystem::Imperial, Quantity::Length) => 2,
+            (UnitSystem::Imperial, Quantity::Deflection) => 3,
+            (UnitSystem::Imperial, _) => 0,
+            (UnitSystem::Si, Quantity::Length) => 3,
+            (UnitSystem::Si, Quantity::Deflection) => 1,
+            (UnitSystem::Si, _) => 2,
+        };
+        format!("{:.*} {}", decimals, value, self.label(quantity))
+    }
+
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => "Imperial (US)",
+            UnitSystem::Si => "SI (Metric)",
+        }
+    }
+}
+
+impl std::fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
 // ============================================================================
 // Arithmetic Implementations (macro to reduce boilerplate)
 // ============================================================================
@@ -331,6 +465,18 @@ mod tests {
         assert_eq!((a / 2.0).0, 5.0);
     }
 
+    #[test]
+    fn test_si_conversion() {
+        let si = UnitSystem::Si;
+        assert!((si.from_imperial(Quantity::Length, 10.0) - 3.048).abs() < 1e-12);
+        assert!((si.from_imperial(Quantity::Force, 1000.0) - 4.448).abs() < 1e-3);
+        assert!((si.from_imperial(Quantity::Moment, 1000.0) - 1.356).abs() < 1e-3);
+        assert!((si.from_imperial(Quantity::Stress, 1000.0) - 6.895).abs() < 1e-3);
+        assert!((si.from_imperial(Quantity::DistributedLoad, 100.0) - 1.459).abs() < 1e-3);
+        assert_eq!(si.format(Quantity::Deflection, si.from_imperial(Quantity::Deflection, 0.5)), "12.7 mm");
+        assert_eq!(UnitSystem::Imperial.format(Quantity::Moment, 1234.4), "1234 ft-lb");
+    }
+
     #[test]
     fn test_serialization() {
         let ft = Feet(12.5);
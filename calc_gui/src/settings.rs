@@ -0,0 +1,120 @@
+//! Persisted application settings (recent files, theme, panel layout) for native builds.
+//!
+//! Stored as JSON in the OS config directory (e.g. `~/.config/stratify/settings.json`
+//! on Linux). Only compiled for native targets (not WASM).
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+/// Number of recently opened projects to remember
+pub const MAX_RECENT_FILES: usize = 8;
+
+/// Settings that persist across sessions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppSettings {
+    /// Recently opened or saved projects, most recent first
+    #[serde(default)]
+    pub recent_files: Vec<PathBuf>,
+
+    /// Theme and panel layout
+    #[serde(default)]
+    pub preferences: Preferences,
+}
+
+/// Theme and panel layout restored at launch
+///
+/// Missing fields take the same defaults as a fresh `App`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub dark_mode: bool,
+    /// Items panel width (px)
+    pub items_panel_width: f32,
+    /// Input panel share of the input + results width
+    pub input_panel_ratio: f32,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Preferences {
+            dark_mode: false,
+            items_panel_width: 170.0,
+            input_panel_ratio: 0.5,
+        }
+    }
+}
+
+impl AppSettings {
+    /// Location of the settings file, if the OS provides a config directory
+    fn file_path() -> Option<PathBuf> {
+        ProjectDirs::from("", "Hotschmoe", "Stratify")
+            .map(|dirs| dirs.config_dir().join("settings.json"))
+    }
+
+    /// Load settings, falling back to defaults if the file is missing or unreadable
+    pub fn load() -> Self {
+        Self::file_path()
+            .and_then(|path| std::fs::read(path).ok())
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write settings to the config directory
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::file_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No config directory"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Move `path` to the front of the recent files list
+    pub fn add_recent_file(&mut self, path: PathBuf) {
+        self.recent_files.retain(|p| *p != path);
+        self.recent_files.insert(0, path);
+        self.recent_files.truncate(MAX_RECENT_FILES);
+    }
+
+    /// Drop `path` from the recent files list
+    pub fn remove_recent_file(&mut self, path: &Path) {
+        self.recent_files.retain(|p| p != path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preferences_roundtrip() {
+        let mut settings = AppSettings {
+            preferences: Preferences {
+                dark_mode: true,
+                items_panel_width: 240.0,
+                input_panel_ratio: 0.65,
+            },
+            ..Default::default()
+        };
+        settings.add_recent_file(PathBuf::from("/jobs/25-001.stf"));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let roundtrip: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtrip.preferences, settings.preferences);
+        assert_eq!(roundtrip.recent_files, settings.recent_files);
+
+        // Files from older versions, or with missing fields, keep the defaults
+        let partial: AppSettings =
+            serde_json::from_str(r#"{"preferences": {"dark_mode": true}}"#).unwrap();
+        assert!(partial.preferences.dark_mode);
+        assert_eq!(partial.preferences.items_panel_width, 170.0);
+        assert_eq!(partial.preferences.input_panel_ratio, 0.5);
+        assert!(serde_json::from_str::<AppSettings>("{}").unwrap().recent_files.is_empty());
+
+        // Corrupt files are rejected; load() then falls back to defaults
+        assert!(serde_json::from_str::<AppSettings>("{\"preferences\": 3").is_err());
+    }
+}
@@ -40,9 +40,15 @@ pub fn view(app: &App) -> Column<'_, Message> {
         InputTab::Loads => view_loads_tab(app),
     };
 
-    // Only show Delete button for existing beams (always visible regardless of tab)
-    let action_buttons = if app.selected_beam_id().is_some() {
+    // Only show beam actions for existing beams (always visible regardless of tab)
+    let action_buttons = if let Some(id) = app.selected_beam_id() {
         row![
+            button("Export PDF")
+                .on_press(Message::ExportBeamPdf(id))
+                .padding(Padding::from([6, 12])),
+            button("Duplicate")
+                .on_press(Message::DuplicateSelectedBeam)
+                .padding(Padding::from([6, 12])),
             button("Delete Beam")
                 .on_press(Message::DeleteSelectedBeam)
                 .padding(Padding::from([6, 12])),
@@ -159,6 +165,10 @@ fn view_sawn_lumber_section(app: &App) -> Element<'_, Message> {
     } else {
         format!("{}{}", app.selected_ply_count.prefix(), app.selected_lumber_size.display_name())
     };
+    let total_width = match app.width_in.parse::<f64>() {
+        Ok(w) => format!("{:.2}", w * app.selected_ply_count.count() as f64),
+        Err(_) => app.width_in.clone(),
+    };
 
     column![
         row![
@@ -184,7 +194,7 @@ fn view_sawn_lumber_section(app: &App) -> Element<'_, Message> {
         .align_y(Alignment::Center),
         row![
             text("Actual:").size(10).width(Length::Fixed(80.0)).color([0.5, 0.5, 0.5]),
-            text(format!("{} = {}\" x {}\"", designation_text, app.width_in, app.depth_in))
+            text(format!("{} = {}\" x {}\"", designation_text, total_width, app.depth_in))
                 .size(10)
                 .color([0.5, 0.5, 0.5]),
         ]
@@ -192,7 +202,7 @@ fn view_sawn_lumber_section(app: &App) -> Element<'_, Message> {
         // Custom size inputs (only shown if custom is selected)
         if app.selected_lumber_size.is_custom() {
             column![
-                labeled_input("Width (in):", &app.width_in, Message::WidthChanged),
+                labeled_input("Ply width (in):", &app.width_in, Message::WidthChanged),
                 labeled_input("Depth (in):", &app.depth_in, Message::DepthChanged),
             ]
             .spacing(6)
@@ -211,6 +221,7 @@ fn view_span_table(app: &App) -> Element<'_, Message> {
         text("#").size(10).width(Length::Fixed(20.0)),
         text("Length (ft)").size(10).width(Length::Fixed(80.0)),
         text("Left Support").size(10).width(Length::Fixed(90.0)),
+        text("k (lb-in/rad)").size(10).width(Length::Fixed(80.0)),
         text("").size(10).width(Length::Fixed(30.0)),
     ]
     .spacing(4)
@@ -239,6 +250,12 @@ fn view_span_table(app: &App) -> Element<'_, Message> {
         .width(Length::Fixed(90.0))
         .text_size(10);
 
+        let stiffness_input = spring_stiffness_input(
+            span_row.left_support,
+            &span_row.spring_stiffness,
+            move |s| Message::SpanSpringStiffnessChanged(row_id, s),
+        );
+
         // Only show delete button if we have more than 1 span
         let delete_btn: Element<'_, Message> = if app.span_table.len() > 1 {
             button(text("X").size(10))
@@ -253,6 +270,7 @@ fn view_span_table(app: &App) -> Element<'_, Message> {
             num_label,
             length_input,
             support_picker,
+            stiffness_input,
             delete_btn,
         ]
         .spacing(4)
@@ -272,6 +290,11 @@ fn view_span_table(app: &App) -> Element<'_, Message> {
         )
         .width(Length::Fixed(90.0))
         .text_size(10),
+        spring_stiffness_input(
+            app.right_end_support,
+            &app.right_end_spring_stiffness,
+            Message::RightEndSpringStiffnessChanged,
+        ),
     ]
     .spacing(4)
     .align_y(Alignment::Center);
@@ -304,6 +327,24 @@ fn view_span_table(app: &App) -> Element<'_, Message> {
     .into()
 }
 
+/// Rotational stiffness input, shown only for a spring support
+fn spring_stiffness_input<'a>(
+    support: SupportType,
+    value: &'a str,
+    on_change: impl Fn(String) -> Message + 'a,
+) -> Element<'a, Message> {
+    if matches!(support, SupportType::Spring { .. }) {
+        text_input("100000", value)
+            .on_input(on_change)
+            .width(Length::Fixed(80.0))
+            .padding(2)
+            .size(10)
+            .into()
+    } else {
+        Space::new().width(80).into()
+    }
+}
+
 /// Render the load table
 fn view_load_table(app: &App) -> Element<'_, Message> {
     let self_weight_checkbox = checkbox(app.include_self_weight)
@@ -705,6 +746,13 @@ fn view_section_deductions(app: &App) -> Element<'_, Message> {
             .width(Length::Fixed(40.0))
             .padding(2)
             .size(10),
+        Space::new().width(8),
+        text("At (ft):").size(10),
+        text_input("", &app.hole_position)
+            .on_input(Message::HolePositionChanged)
+            .width(Length::Fixed(50.0))
+            .padding(2)
+            .size(10),
     ]
     .spacing(4)
     .align_y(Alignment::Center);
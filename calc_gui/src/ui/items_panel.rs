@@ -4,7 +4,7 @@
 //! - Project Info section (clickable to edit)
 //! - Enabled category sections (added via category picker)
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use iced::widget::{button, column, container, row, rule, scrollable, text, Column, Space};
 use iced::{Alignment, Element, Length, Padding};
@@ -22,6 +22,7 @@ pub fn view_items_panel<'a>(
     enabled_categories: &'a HashSet<ItemCategory>,
     selection: &'a EditorSelection,
     selected_beam_id: Option<Uuid>,
+    item_unities: &'a HashMap<Uuid, f64>,
     width: f32,
 ) -> Element<'a, Message> {
     let mut panel_content: Column<'_, Message> = column![].spacing(2);
@@ -121,23 +122,43 @@ pub fn view_items_panel<'a>(
         if beams_expanded {
             let mut beams_list: Column<'_, Message> = column![].spacing(2).padding(Padding::from([4, 8]));
 
+            // The single worst member across the project gets a marker
+            let worst_id = item_unities
+                .iter()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(id, _)| *id);
+
             // List beams
             for (id, item) in &project.items {
                 if let CalculationItem::Beam(beam) = item {
                     let is_selected = selected_beam_id == Some(*id);
-                    let btn = if is_selected {
-                        button(text(&beam.label).size(10))
-                            .on_press(Message::SelectBeam(*id))
-                            .padding(Padding::from([3, 6]))
-                            .style(button::primary)
-                            .width(Length::Fill)
+                    let is_worst = worst_id == Some(*id) && item_unities.len() > 1;
+
+                    let label = if is_worst {
+                        format!("\u{25B2} {}", beam.label)
+                    } else {
+                        beam.label.clone()
+                    };
+                    let mut content = row![text(label).size(10), Space::new().width(Length::Fill)]
+                        .align_y(Alignment::Center);
+                    if let Some(unity) = item_unities.get(id) {
+                        content = content.push(
+                            text(format!("{:.2}", unity)).size(10).color(unity_color(*unity)),
+                        );
+                    }
+
+                    let style = if is_selected {
+                        button::primary
+                    } else if is_worst {
+                        button::danger
                     } else {
-                        button(text(&beam.label).size(10))
-                            .on_press(Message::SelectBeam(*id))
-                            .padding(Padding::from([3, 6]))
-                            .style(button::secondary)
-                            .width(Length::Fill)
+                        button::secondary
                     };
+                    let btn = button(content)
+                        .on_press(Message::SelectBeam(*id))
+                        .padding(Padding::from([3, 6]))
+                        .style(style)
+                        .width(Length::Fill);
                     beams_list = beams_list.push(btn);
                 }
             }
@@ -172,3 +193,14 @@ pub fn view_items_panel<'a>(
 
     panel.into()
 }
+
+/// Badge color for a governing unity ratio
+fn unity_color(unity: f64) -> [f32; 3] {
+    if unity > 1.0 {
+        [0.8, 0.2, 0.2]
+    } else if unity >= 0.8 {
+        [0.9, 0.7, 0.0]
+    } else {
+        [0.2, 0.6, 0.2]
+    }
+}
@@ -21,12 +21,15 @@ pub enum ModalType {
 }
 
 /// Actions that can be pending while a modal is shown
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PendingAction {
     /// User wants to create a new project
     NewProject,
     /// User wants to open an existing project
     OpenProject,
+    /// User wants to open a project from the recent files list
+    #[cfg(not(target_arch = "wasm32"))]
+    OpenRecent(std::path::PathBuf),
 }
 
 impl std::fmt::Display for PendingAction {
@@ -34,6 +37,8 @@ impl std::fmt::Display for PendingAction {
         match self {
             PendingAction::NewProject => write!(f, "create a new project"),
             PendingAction::OpenProject => write!(f, "open another project"),
+            #[cfg(not(target_arch = "wasm32"))]
+            PendingAction::OpenRecent(_) => write!(f, "open another project"),
         }
     }
 }
@@ -54,7 +59,7 @@ pub fn view_backdrop() -> Element<'static, Message> {
 /// Render a modal dialog based on its type
 pub fn view_modal(modal_type: &ModalType) -> Element<'_, Message> {
     match modal_type {
-        ModalType::UnsavedChanges { action } => view_unsaved_changes_modal(*action),
+        ModalType::UnsavedChanges { action } => view_unsaved_changes_modal(action.clone()),
         ModalType::CategoryPicker => view_category_picker_modal(),
     }
 }
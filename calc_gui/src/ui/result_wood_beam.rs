@@ -7,7 +7,7 @@
 use iced::widget::{button, column, rule, text, Canvas, Column, Row, Space};
 use iced::{Element, Length, Padding};
 
-use calc_core::calculations::continuous_beam::{ContinuousBeamInput, ContinuousBeamResult};
+use calc_core::calculations::continuous_beam::{ContinuousBeamInput, ContinuousBeamResult, SpanResult};
 use calc_core::nds_factors::nds_ref;
 
 use crate::{Message, ResultsTab};
@@ -101,24 +101,29 @@ fn view_calculation_results<'a>(input: &'a ContinuousBeamInput, result: &'a Cont
 
     let (bending_status, bending_unity, actual_fb, allowable_fb) = span_result
         .map(|sr| {
-            let status = if sr.bending_unity <= 1.0 { "OK" } else { "FAIL" };
-            (status, sr.bending_unity, sr.actual_fb_psi, sr.allowable_fb_psi)
+            // Gross-section check; a hole is shown on its own line
+            let unity = sr.actual_fb_psi / sr.allowable_fb_psi;
+            let status = if unity <= 1.0 { "OK" } else { "FAIL" };
+            (status, unity, sr.actual_fb_psi, sr.allowable_fb_psi)
         })
         .unwrap_or(("N/A", 0.0, 0.0, 0.0));
 
     let (shear_status, shear_unity, actual_fv, allowable_fv) = span_result
         .map(|sr| {
-            let status = if sr.shear_unity <= 1.0 { "OK" } else { "FAIL" };
-            (status, sr.shear_unity, sr.actual_fv_psi, sr.allowable_fv_psi)
+            // Gross-section check; a notched end is shown on its own line
+            let unity = sr.actual_fv_psi / sr.allowable_fv_psi;
+            let status = if unity <= 1.0 { "OK" } else { "FAIL" };
+            (status, unity, sr.actual_fv_psi, sr.allowable_fv_psi)
         })
         .unwrap_or(("N/A", 0.0, 0.0, 0.0));
 
-    let (defl_status, defl_unity) = span_result
+    let (defl_status, defl_unity, defl_ratio, span_depth_ratio) = span_result
         .map(|sr| {
             let status = if sr.deflection_unity <= 1.0 { "OK" } else { "FAIL" };
-            (status, sr.deflection_unity)
+            let ratio = sr.deflection_ratio.map_or_else(|| "∞".to_string(), |r| format!("{:.0}", r));
+            (status, sr.deflection_unity, ratio, sr.span_to_depth_ratio)
         })
-        .unwrap_or(("N/A", 0.0));
+        .unwrap_or(("N/A", 0.0, "0".to_string(), 0.0));
 
     // Build reactions display string (R_1, R_2, R_3, etc.)
     let reactions_str = result.reactions
@@ -140,12 +145,16 @@ fn view_calculation_results<'a>(input: &'a ContinuousBeamInput, result: &'a Cont
         governing,
         Space::new().height(12),
         text("Load Summary").size(12),
-        text(format!("Governing Combo: {}", result.governing_combination)).size(11),
+        text(format!("Governing Combo: {}", result.governing_label())).size(11),
         Space::new().height(12),
         text("Demand").size(12),
         text(format!("Max Moment: {:.0} ft-lb", result.max_positive_moment_ftlb)).size(11),
         text(format!("Max Shear: {:.0} lb", result.max_shear_lb)).size(11),
-        text(format!("Max Deflection: {:.3} in", result.max_deflection_in)).size(11),
+        text(format!(
+            "Max Deflection: {:.3} in {}",
+            result.max_deflection_in.abs(),
+            result.deflection_direction()
+        )).size(11),
         Space::new().height(12),
         text("Capacity Checks").size(12),
         text(format!(
@@ -156,22 +165,71 @@ fn view_calculation_results<'a>(input: &'a ContinuousBeamInput, result: &'a Cont
             "Shear: {:.0}/{:.0} psi = {:.2} [{}] ({})",
             actual_fv, allowable_fv, shear_unity, shear_status, nds_ref::SHEAR
         )).size(11),
+        view_notched_shear(span_result),
+        view_net_section(span_result),
+        view_biaxial(span_result),
         text(format!(
-            "Deflection: {:.2} [{}] ({})",
-            defl_unity, defl_status, nds_ref::DEFLECTION
+            "Deflection: L/{} = {:.2} [{}] ({})",
+            defl_ratio, defl_unity, defl_status, nds_ref::DEFLECTION
         )).size(11),
         Space::new().height(12),
         text("Section Properties").size(12),
         text(format!("Section Modulus (S): {:.2} in³", section_modulus)).size(11),
         text(format!("Moment of Inertia (I): {:.2} in⁴", moment_inertia)).size(11),
+        text(format!("Span/Depth (L/d): {:.1}", span_depth_ratio)).size(11),
         Space::new().height(12),
         text("Support Reactions").size(12),
         text(format!("Max: {}", reactions_str)).size(11),
-        text(format!("  ({})", result.governing_combination)).size(10),
+        text(format!("  ({})", result.governing_label())).size(10),
         view_min_reactions(result),
     ]
 }
 
+/// Render the notched shear check when an end support is notched
+fn view_notched_shear<'a>(span_result: Option<&SpanResult>) -> Element<'a, Message> {
+    match span_result.and_then(|sr| sr.notched_shear_capacity_lb.zip(sr.notched_shear_unity)) {
+        Some((capacity, unity)) => {
+            let status = if unity <= 1.0 { "OK" } else { "FAIL" };
+            text(format!(
+                "Notched Shear: V_r' = {:.0} lb, {:.2} [{}] (NDS 3.4.3.2)",
+                capacity, unity, status
+            ))
+            .size(11)
+            .into()
+        }
+        None => Space::new().into(),
+    }
+}
+
+/// Render the net section checks when a hole is located in the span
+fn view_net_section<'a>(span_result: Option<&SpanResult>) -> Element<'a, Message> {
+    match span_result.and_then(|sr| sr.net_section_bending_unity.zip(sr.net_section_shear_unity)) {
+        Some((bending, shear)) => {
+            let status = if bending.max(shear) <= 1.0 { "OK" } else { "FAIL" };
+            text(format!(
+                "Net Section at Hole: bending {:.2}, shear {:.2} [{}]",
+                bending, shear, status
+            ))
+            .size(11)
+            .into()
+        }
+        None => Space::new().into(),
+    }
+}
+
+/// Render the biaxial bending interaction when the beam has weak-axis loads
+fn view_biaxial<'a>(span_result: Option<&SpanResult>) -> Element<'a, Message> {
+    match span_result.and_then(|sr| sr.biaxial_unity) {
+        Some(unity) => {
+            let status = if unity <= 1.0 { "OK" } else { "FAIL" };
+            text(format!("Biaxial Bending: {:.2} [{}] (NDS 3.9.2)", unity, status))
+                .size(11)
+                .into()
+        }
+        None => Space::new().into(),
+    }
+}
+
 /// Render minimum reactions section with uplift warning
 fn view_min_reactions<'a>(result: &'a ContinuousBeamResult) -> Element<'a, Message> {
     // Build min reactions display string
@@ -345,6 +345,19 @@ impl BeamDiagram {
                     frame.stroke(&hatch, Stroke::default().with_color(color).with_width(1.0));
                 }
             }
+            SupportType::Spring { .. } => {
+                // Triangle (outline) with a rotational spring circle at the apex
+                let triangle = Path::new(|builder| {
+                    builder.move_to(Point::new(x, y));
+                    builder.line_to(Point::new(x - size / 2.0, y + size));
+                    builder.line_to(Point::new(x + size / 2.0, y + size));
+                    builder.close();
+                });
+                frame.stroke(&triangle, Stroke::default().with_color(color).with_width(2.0));
+
+                let coil = Path::circle(Point::new(x, y), size * 0.25);
+                frame.stroke(&coil, Stroke::default().with_color(color).with_width(1.5));
+            }
             SupportType::Free => {
                 // No support symbol - maybe a small dot to show the end
                 let dot = Path::circle(Point::new(x, y + 2.0), 2.0);
@@ -524,6 +537,9 @@ impl BeamDiagram {
                         load_color,
                     );
                 }
+                LoadDistribution::HorizontalPoint { .. } => {
+                    // Axial load - not drawn on the gravity load diagram
+                }
             }
         }
     }
@@ -1,6 +1,10 @@
 //! Toolbar component
 //!
-//! Contains file operations (New, Open, Save, Save As, Export PDF) and settings dropdown.
+//! Contains file operations (New, Open, Save, Save As, Undo, Redo, Export PDF) and settings
+//! dropdown (with recent files on native).
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
 
 use iced::widget::{button, column, container, row, text, Space};
 use iced::{Alignment, Element, Length, Padding};
@@ -51,6 +55,14 @@ pub fn view_toolbar(settings_menu_open: bool) -> Element<'static, Message> {
             .on_press(Message::SaveProjectAs)
             .padding(Padding::from([4, 8]))
             .style(button::secondary),
+        button(text("Undo").size(11))
+            .on_press(Message::Undo)
+            .padding(Padding::from([4, 8]))
+            .style(button::secondary),
+        button(text("Redo").size(11))
+            .on_press(Message::Redo)
+            .padding(Padding::from([4, 8]))
+            .style(button::secondary),
         button(text("Export PDF").size(11))
             .on_press(Message::ExportPdf)
             .padding(Padding::from([4, 8]))
@@ -75,9 +87,13 @@ pub fn view_toolbar(settings_menu_open: bool) -> Element<'static, Message> {
     .into()
 }
 
-/// Render the settings dropdown menu (native version with update checking)
+/// Render the settings dropdown menu (native version with update checking and recent files)
 #[cfg(not(target_arch = "wasm32"))]
-pub fn view_settings_menu(dark_mode: bool, update_status: &UpdateStatus) -> Element<'static, Message> {
+pub fn view_settings_menu(
+    dark_mode: bool,
+    update_status: &UpdateStatus,
+    recent_files: &[PathBuf],
+) -> Element<'static, Message> {
     let theme_label = if dark_mode { "Light Mode" } else { "Dark Mode" };
 
     // Build update button based on status
@@ -120,9 +136,12 @@ pub fn view_settings_menu(dark_mode: bool, update_status: &UpdateStatus) -> Elem
             .style(button::secondary),
         // Update button
         update_button,
+        Space::new().height(4),
+        text("Recent Files").size(10),
+        view_recent_files(recent_files),
     ]
     .spacing(2)
-    .width(Length::Fixed(140.0));
+    .width(Length::Fixed(180.0));
 
     container(dropdown_content)
         .padding(4)
@@ -130,6 +149,31 @@ pub fn view_settings_menu(dark_mode: bool, update_status: &UpdateStatus) -> Elem
         .into()
 }
 
+/// Render the recent files submenu, one button per file labelled with its name
+#[cfg(not(target_arch = "wasm32"))]
+fn view_recent_files(recent_files: &[PathBuf]) -> Element<'static, Message> {
+    if recent_files.is_empty() {
+        return text("No recent files").size(10).color([0.5, 0.5, 0.5]).into();
+    }
+
+    recent_files
+        .iter()
+        .fold(column![].spacing(2), |menu, path| {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string());
+            menu.push(
+                button(text(name).size(10))
+                    .on_press(Message::OpenRecent(path.clone()))
+                    .padding(Padding::from([4, 12]))
+                    .width(Length::Fill)
+                    .style(button::secondary),
+            )
+        })
+        .into()
+}
+
 /// Render the settings dropdown menu (WASM version - no update checking)
 #[cfg(target_arch = "wasm32")]
 pub fn view_settings_menu(dark_mode: bool) -> Element<'static, Message> {